@@ -25,6 +25,9 @@ THE SOFTWARE.
 use angle;
 use time;
 use coords;
+use ecliptic;
+use nutation;
+use sun;
 
 /**
 Computes solar aberration in ecliptic longitude
@@ -376,3 +379,62 @@ pub fn stell_aberr_in_eq_coords(stell_eq_point: &coords::EqPoint, JD: f64) -> (f
 
     (delta_asc, delta_dec)
 }
+
+/**
+Computes the annual aberration of a star in equatorial coordinates
+
+This uses the classical expressions in terms of the Sun's true
+longitude, and the eccentricity and longitude of the perihelion of
+the Earth's orbit, which include the E-terms of aberration. For the
+more accurate method of Ron and Vondrák, see
+[stell_aberr_in_eq_coords()](./fn.stell_aberr_in_eq_coords.html).
+
+The aberration in right ascension grows as `1 / cos(dec)` towards the
+celestial poles, while the displacement on the sky,
+`abrr_in_asc * cos(dec)`, stays under about 20.5 arcseconds. Within
+`1e-9` radians of a pole, where right ascension is undefined, the
+aberration in right ascension is taken to be zero.
+
+# Returns
+
+`(abrr_in_asc, abrr_in_dec)`
+
+* `abrr_in_asc`: Aberration in right ascension *| in radians*
+* `abrr_in_dec`: Aberration in declination *| in radians*
+
+# Arguments
+
+* `eq_point`: Equatorial coordinates of the star *| in radians*
+* `JD`      : Julian (Ephemeris) day
+**/
+pub fn annual_aberr(eq_point: &coords::EqPoint, JD: f64) -> (f64, f64) {
+    let JC = time::julian_cent(JD);
+    let k = angle::deg_frm_dms(0, 0, 20.49552).to_radians();
+    let e = 0.016708634 - JC * (0.000042037 + JC * 0.0000001267);
+    let perih = (102.93735 + JC * (1.71946 + JC * 0.00046)).to_radians();
+
+    let (sun_ecl_point, _) = sun::geocent_ecl_pos(JD);
+    let sun_long = sun_ecl_point.long;
+    let (_, nut_in_oblq) = nutation::nutation(JD);
+    let oblq = ecliptic::mn_oblq_laskar(JD) + nut_in_oblq;
+
+    let (sin_asc, cos_asc) = eq_point.asc.sin_cos();
+    let (sin_dec, cos_dec) = eq_point.dec.sin_cos();
+    let (sin_long, cos_long) = sun_long.sin_cos();
+    let (sin_perih, cos_perih) = perih.sin_cos();
+    let cos_oblq = oblq.cos();
+    let a = oblq.tan() * cos_dec - sin_asc * sin_dec;
+
+    let abrr_in_asc = if cos_dec < 1e-9 {
+        0.0
+    } else {
+        (-k * (cos_asc * cos_long * cos_oblq + sin_asc * sin_long)
+            + e * k * (cos_asc * cos_perih * cos_oblq + sin_asc * sin_perih))
+            / cos_dec
+    };
+
+    let abrr_in_dec = -k * (cos_long * cos_oblq * a + cos_asc * sin_dec * sin_long)
+        + e * k * (cos_perih * cos_oblq * a + cos_asc * sin_dec * sin_perih);
+
+    (abrr_in_asc, abrr_in_dec)
+}
@@ -23,6 +23,8 @@ THE SOFTWARE.
 //! Angles for astronomy
 
 use std;
+use std::error;
+use std::fmt;
 
 pub const TWO_PI: f64 = 2.0 * std::f64::consts::PI;
 
@@ -178,3 +180,298 @@ pub fn limit_to_two_PI(angl: f64) -> f64 {
         limited_angl
     }
 }
+
+/**
+Computes the equivalent angle in (-180, 180] degree range
+
+# Arguments
+
+* `angl`: Angle *| in degrees*
+
+Both -180 and 180 degrees map to 180 degrees.
+**/
+#[inline]
+pub fn limit_to_pm_180(angl: f64) -> f64 {
+    let limited_angl = limit_to_360(angl);
+
+    if limited_angl > 180.0 {
+        limited_angl - 360.0
+    } else {
+        limited_angl
+    }
+}
+
+/**
+Computes the equivalent angle in (-π, π] radian range
+
+# Arguments
+
+* `angl`: Angle *| in radians*
+
+Both -π and π radians map to π radians.
+**/
+#[inline]
+pub fn limit_to_pm_PI(angl: f64) -> f64 {
+    let limited_angl = limit_to_two_PI(angl);
+
+    if limited_angl > std::f64::consts::PI {
+        limited_angl - TWO_PI
+    } else {
+        limited_angl
+    }
+}
+
+/**
+Computes an angle expressed in degrees, arcminutes and
+arcseconds, from an angle in radians
+
+# Returns
+
+`(deg, min, sec)`
+
+* `deg`: Degrees, carrying the sign of the angle
+* `min`: Arcminutes
+* `sec`: Arcseconds
+
+For an angle between -1 and 0 degrees, `deg` is zero and can't
+carry the sign of the angle, which then has to be taken from the
+angle itself, as [`fmt_dms()`](./fn.fmt_dms.html) does.
+
+# Arguments
+
+* `angl`: Angle *| in radians*
+**/
+pub fn to_dms(angl: f64) -> (i32, u32, f64) {
+    let (deg, min, sec) = sexagesimal(angl.to_degrees().abs());
+
+    if angl < 0.0 {
+        (-(deg as i32), min, sec)
+    } else {
+        (deg as i32, min, sec)
+    }
+}
+
+/**
+Computes an angle expressed in hours, minutes and seconds,
+from an angle in radians
+
+# Returns
+
+`(hour, min, sec)`
+
+* `hour`: Hours, in the range [0, 24)
+* `min`: Minutes
+* `sec`: Seconds
+
+# Arguments
+
+* `angl`: Angle, such as a right ascension *| in radians*
+**/
+pub fn to_hms(angl: f64) -> (i32, u32, f64) {
+    let (hour, min, sec) = sexagesimal(limit_to_two_PI(angl).to_degrees() / 15.0);
+
+    (hour as i32, min, sec)
+}
+
+/**
+Formats an angle in degrees, arcminutes and arcseconds
+
+The angle is written with an explicit sign and its arcseconds
+rounded to one decimal, as in `+41°16'09.0"`.
+
+# Arguments
+
+* `angl`: Angle, such as a declination *| in radians*
+**/
+pub fn fmt_dms(angl: f64) -> String {
+    let tenth_secs = (angl.to_degrees().abs() * 36000.0).round() as u64;
+
+    format!(
+        "{}{:02}°{:02}'{:02}.{}\"",
+        if angl < 0.0 { '-' } else { '+' },
+        tenth_secs / 36000,
+        tenth_secs / 600 % 60,
+        tenth_secs / 10 % 60,
+        tenth_secs % 10
+    )
+}
+
+/**
+Formats an angle in hours, minutes and seconds
+
+The angle is reduced to the range [0h, 24h) and written with its
+seconds rounded to one decimal, as in `14h15m39.7s`.
+
+# Arguments
+
+* `angl`: Angle, such as a right ascension *| in radians*
+**/
+pub fn fmt_hms(angl: f64) -> String {
+    let tenth_secs =
+        (limit_to_two_PI(angl).to_degrees() / 15.0 * 36000.0).round() as u64 % (24 * 36000);
+
+    format!(
+        "{:02}h{:02}m{:02}.{}s",
+        tenth_secs / 36000,
+        tenth_secs / 600 % 60,
+        tenth_secs / 10 % 60,
+        tenth_secs % 10
+    )
+}
+
+// Splits a non-negative number of units into units, sixtieths and
+// three-thousand-six-hundredths
+fn sexagesimal(units: f64) -> (u32, u32, f64) {
+    let secs = units * 3600.0;
+    let whole = (secs / 3600.0).floor();
+    let min = ((secs - whole * 3600.0) / 60.0).floor();
+
+    (whole as u32, min as u32, secs - whole * 3600.0 - min * 60.0)
+}
+
+/**
+Computes an angle in radians, from an angle expressed in
+degrees, arcminutes and arcseconds
+
+# Arguments
+
+* `deg`: Degrees
+* `min`: Arcminutes
+* `sec`: Arcseconds
+
+The angle is negative if any of `deg`, `min` or `sec` is negative,
+so that an angle between -1 and 0 degrees is written with a negative
+`min`, or a negative `sec` if it is also above -1 arcminute.
+**/
+pub fn dms_to_radians(deg: i32, min: i32, sec: f64) -> f64 {
+    from_sexagesimal(deg < 0 || min < 0 || sec < 0.0, deg.abs(), min.abs(), sec.abs())
+        .to_radians()
+}
+
+/**
+Computes an angle in radians, from an angle expressed in
+hours, minutes and seconds
+
+# Arguments
+
+* `hour`: Hours
+* `min` : Minutes
+* `sec` : Seconds
+
+The angle is negative if any of `hour`, `min` or `sec` is negative.
+**/
+pub fn hms_to_radians(hour: i32, min: i32, sec: f64) -> f64 {
+    (15.0 * from_sexagesimal(hour < 0 || min < 0 || sec < 0.0, hour.abs(), min.abs(), sec.abs()))
+        .to_radians()
+}
+
+/// Represents an error in parsing a sexagesimal angle from a string
+#[derive(Debug, PartialEq)]
+pub enum ParseAngleError {
+    /// The string isn't of the form `[±]D[:M[:S]]`
+    Format,
+    /// The minutes or seconds aren't less than 60
+    Range,
+}
+
+impl fmt::Display for ParseAngleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let desc = match *self {
+            ParseAngleError::Format => "angle isn't in the sexagesimal format [±]D:M:S",
+            ParseAngleError::Range => "minutes or seconds aren't less than 60",
+        };
+
+        f.write_str(desc)
+    }
+}
+
+impl error::Error for ParseAngleError {}
+
+/**
+Parses an angle expressed in degrees, arcminutes and arcseconds
+
+# Returns
+
+* `angl`: Angle *| in radians*
+
+# Arguments
+
+* `s`: Angle, as in `+41:16:09`, `-0 30 00` or `41°16'09.5"`
+
+The fields may be separated by colons, spaces, or the symbols `°`,
+`d`, `'`, `′`, `m`, `"`, `″` and `s`. The seconds, or both the
+minutes and the seconds, may be left out, and only the last field may
+have decimals. A leading `-` makes the whole angle negative, even if
+the degrees are zero.
+**/
+pub fn parse_dms(s: &str) -> Result<f64, ParseAngleError> {
+    parse_sexagesimal(s).map(|deg| deg.to_radians())
+}
+
+/**
+Parses an angle expressed in hours, minutes and seconds
+
+# Returns
+
+* `angl`: Angle *| in radians*
+
+# Arguments
+
+* `s`: Angle, as in `14h15m39.7s` or `14:15:39.7`
+
+The fields are separated as in [`parse_dms()`](./fn.parse_dms.html),
+with `h` also allowed after the hours.
+**/
+pub fn parse_hms(s: &str) -> Result<f64, ParseAngleError> {
+    parse_sexagesimal(s).map(|hour| (15.0 * hour).to_radians())
+}
+
+// Computes the number of units in a sexagesimal quantity
+#[inline]
+fn from_sexagesimal(negative: bool, whole: i32, min: i32, sec: f64) -> f64 {
+    let units = (whole as f64) + (min as f64) / 60.0 + sec / 3600.0;
+
+    if negative {
+        -units
+    } else {
+        units
+    }
+}
+
+// Parses a sexagesimal quantity into a number of units
+fn parse_sexagesimal(s: &str) -> Result<f64, ParseAngleError> {
+    let s = s.trim();
+    let (negative, s) = match s.chars().next() {
+        Some('-') => (true, &s[1..]),
+        Some('+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+
+    let is_separator = |c: char| c.is_whitespace() || ":°d'′m\"″sh".contains(c);
+    if !s.chars().all(|c| c.is_ascii_digit() || c == '.' || is_separator(c)) {
+        return Err(ParseAngleError::Format);
+    }
+
+    let fields: Vec<&str> = s.split(is_separator).filter(|field| !field.is_empty()).collect();
+    if fields.is_empty() || fields.len() > 3 {
+        return Err(ParseAngleError::Format);
+    }
+
+    let mut units = 0.0;
+    for (i, field) in fields.iter().enumerate() {
+        if i + 1 < fields.len() && field.contains('.') {
+            return Err(ParseAngleError::Format);
+        }
+        let value: f64 = field.parse().map_err(|_| ParseAngleError::Format)?;
+        if i > 0 && value >= 60.0 {
+            return Err(ParseAngleError::Range);
+        }
+        units += value / 60_f64.powi(i as i32);
+    }
+
+    if negative {
+        Ok(-units)
+    } else {
+        Ok(units)
+    }
+}
@@ -20,7 +20,7 @@ OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
 THE SOFTWARE.
 */
 
-//! Corrections for atmospheric refraction
+//! Corrections for atmospheric refraction and extinction
 
 use angle;
 use std::f64::consts::PI;
@@ -155,3 +155,113 @@ Computes the refraction term modifier for local temperature
 pub fn refrac_by_temp(temp: f64) -> f64 {
     283.0 / temp
 }
+
+/**
+Computes the refraction term for true altitude, from apparent
+altitude and the local atmospheric conditions
+
+This is Bennett's formula of
+[refrac_frm_apprnt_alt()](./fn.refrac_frm_apprnt_alt.html), which
+holds for a pressure of 1010 millibars and a temperature of 10 °C,
+scaled by [refrac_by_pressr()](./fn.refrac_by_pressr.html) and
+[refrac_by_temp()](./fn.refrac_by_temp.html). The formula diverges
+a few degrees below the horizon, and so the apparent altitude is
+clamped to the range [-1, 90] degrees.
+
+# Returns
+
+* `refrac_term`: The refraction term *| in radians*, that needs to be
+                 subtracted from the apparent altitude to get the
+                 true altitude
+
+# Arguments
+
+* `apprnt_alt`: Apparent altitude *| in radians*
+* `pressure`  : Local pressure *| in millibars*
+* `temp`      : Local temperature *| in degrees Celsius*
+**/
+pub fn refraction_from_apparent(apprnt_alt: f64, pressure: f64, temp: f64) -> f64 {
+    let apprnt_alt = clamp_alt(apprnt_alt);
+
+    refrac_frm_apprnt_alt(apprnt_alt) * refrac_by_pressr(pressure) * refrac_by_temp(temp + 273.0)
+}
+
+/**
+Computes the refraction term for apparent altitude, from true
+altitude and the local atmospheric conditions
+
+This is Saemundsson's formula of
+[refrac_frm_true_alt()](./fn.refrac_frm_true_alt.html), which holds
+for a pressure of 1010 millibars and a temperature of 10 °C, scaled
+by [refrac_by_pressr()](./fn.refrac_by_pressr.html) and
+[refrac_by_temp()](./fn.refrac_by_temp.html). The true altitude is
+clamped to the range [-1, 90] degrees.
+
+# Returns
+
+* `refrac_term`: The refraction term *| in radians*, that needs to be
+                 added to the true altitude to get the apparent
+                 altitude
+
+# Arguments
+
+* `true_alt`: True altitude *| in radians*
+* `pressure`: Local pressure *| in millibars*
+* `temp`    : Local temperature *| in degrees Celsius*
+**/
+pub fn refraction_from_true(true_alt: f64, pressure: f64, temp: f64) -> f64 {
+    let true_alt = clamp_alt(true_alt);
+
+    refrac_frm_true_alt(true_alt) * refrac_by_pressr(pressure) * refrac_by_temp(temp + 273.0)
+}
+
+/**
+Computes the airmass along the line of sight to a celestial body
+
+This is the formula of F. Kasten and A. T. Young (1989), which unlike
+the secant of the zenith distance stays finite at the horizon, where
+it gives an airmass of about 38. It gives an airmass of 0.9997 at the
+zenith. The apparent altitude is clamped to the range [0, 90] degrees,
+so that the airmass of a body below the horizon is that at the
+horizon.
+
+# Returns
+
+* `airmass`: Airmass, relative to that at the zenith
+
+# Arguments
+
+* `apprnt_alt`: Apparent altitude *| in radians*
+**/
+pub fn airmass(apprnt_alt: f64) -> f64 {
+    let alt = apprnt_alt.clamp(0.0, PI / 2.0);
+
+    1.0 / (alt.sin() + 0.50572 * (alt.to_degrees() + 6.07995).powf(-1.6364))
+}
+
+/**
+Computes the dimming of a celestial body by atmospheric extinction
+
+# Returns
+
+* `extinction`: Dimming of the body *| in magnitudes*
+
+`extinction` is to be added to the magnitude of the body outside
+the atmosphere.
+
+# Arguments
+
+* `airmass`          : [Airmass](./fn.airmass.html) along the line of sight
+* `k_mag_per_airmass`: Extinction coefficient *| in magnitudes per airmass*
+
+`k_mag_per_airmass` is about 0.2 in the visual band at a good site.
+**/
+#[inline]
+pub fn extinction(airmass: f64, k_mag_per_airmass: f64) -> f64 {
+    k_mag_per_airmass * airmass
+}
+
+#[inline]
+fn clamp_alt(alt: f64) -> f64 {
+    alt.clamp(-1_f64.to_radians(), PI / 2.0)
+}
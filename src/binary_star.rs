@@ -23,6 +23,8 @@ THE SOFTWARE.
 //! Binary stars
 
 use angle;
+use orbit;
+use time;
 
 /**
 Computes mean annual motion of companion star
@@ -139,3 +141,60 @@ pub fn ecc_of_apprnt_orb(e: f64, w: f64, i: f64) -> f64 {
 
     ((2.0 * d) / (a + c + d)).sqrt()
 }
+
+/// Holds the elements of the true orbit of a visual binary star
+#[derive(Debug)]
+pub struct BinaryElements {
+    /// Period of revolution *| in mean solar years*
+    pub P: f64,
+    /// Time of periastron passage, as a year with decimals
+    /// (eg: 1934.008)
+    pub T: f64,
+    /// Apparent semimajor axis *| in arcseconds*
+    pub a: f64,
+    /// Eccentricity of the true orbit
+    pub e: f64,
+    /// Inclination of the true orbit to a plane at right angles to
+    /// the line of sight *| in radians*
+    pub i: f64,
+    /// Position angle of the ascending node *| in radians*
+    pub asc_node: f64,
+    /// Longitude of periastron *| in radians*
+    pub w: f64,
+}
+
+/**
+Computes the apparent position of the companion of a visual binary
+star, relative to the primary
+
+Kepler's equation is solved with
+[orbit::elliptic::ecc_anom_robust()](../orbit/elliptic/fn.ecc_anom_robust.html),
+which converges for eccentricities close to unity.
+
+# Returns
+
+`(pos_angl, sepr)`
+
+* `pos_angl`: Position angle of the companion, measured eastwards
+  from the North *| in radians*
+* `sepr`    : Angular separation of the companion from the primary
+  *| in arcseconds*
+
+# Arguments
+
+* `elements`: The elements of the [true orbit](./struct.BinaryElements.html)
+* `JD`      : Julian (Ephemeris) day
+**/
+pub fn apparent_position(elements: &BinaryElements, JD: f64) -> (f64, f64) {
+    let t = time::julian_epoch_frm_julian_day(JD);
+    let M = mn_anom_of_compan(mn_ann_motion_of_compan(elements.P), t, elements.T);
+
+    let E = orbit::elliptic::ecc_anom_robust(M, elements.e);
+    let v = true_anom(elements.e, E);
+    let r = rad_vec(elements.a, elements.e, E);
+
+    (
+        apprnt_coords_angl(elements.asc_node, v, elements.w, elements.i),
+        anglr_sepr(r, v, elements.w, elements.i),
+    )
+}
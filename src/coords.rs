@@ -22,10 +22,17 @@ THE SOFTWARE.
 
 //! Transform between coordinate systems
 
+use aberr;
 use angle;
+use nutation;
+use planet;
+use precess;
+use star;
+use time;
 
 /// Represents a point on the geographical surface of the Earth
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GeographPoint {
     /// Geographical longitude
     pub long: f64,
@@ -41,6 +48,7 @@ impl GeographPoint {
 
 /// Represents a point in the equatorial coordinate system
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EqPoint {
     /// Right ascension
     pub asc: f64,
@@ -56,6 +64,7 @@ impl EqPoint {
 
 /// Represents a point in the ecliptic coordinate system
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EclPoint {
     /// Ecliptic longitude
     pub long: f64,
@@ -69,6 +78,101 @@ impl EclPoint {
     }
 }
 
+/// Represents a point in the galactic coordinate system
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GalacticPoint {
+    /// Galactic longitude
+    pub long: f64,
+    /// Galactic latitude
+    pub lat: f64,
+}
+
+impl GalacticPoint {
+    pub fn anglr_sepr(&self, other_point: &GalacticPoint) -> f64 {
+        angle::anglr_sepr(self.long, self.lat, other_point.long, other_point.lat)
+    }
+}
+
+/// Represents a point in the local horizontal coordinate system
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HorizPoint {
+    /// Azimuth, measured westwards from the South
+    pub az: f64,
+    /// Altitude
+    pub alt: f64,
+}
+
+/// Represents a celestial coordinate frame
+pub enum Frame {
+    /// Ecliptic coordinates, referred to the mean equinox of the date
+    MeanEclOfDate,
+    /// Apparent equatorial coordinates, referred to the true equinox
+    /// of the date
+    EqOfDate,
+    /// Equatorial coordinates, referred to the mean equinox of J2000.0
+    EqJ2000,
+    /// Galactic coordinates
+    Galactic,
+}
+
+/**
+Computes the angular separation between two equatorial points
+
+Unlike [EqPoint::anglr_sepr()](./struct.EqPoint.html#method.anglr_sepr),
+which takes the arccosine of the cosine of the separation, this uses
+Vincenty's formula with an arctangent, which keeps its precision for
+separations of less than an arcsecond as well as for those close to
+180 degrees.
+
+# Returns
+
+* `sepr`: Angular separation *| in radians*
+
+# Arguments
+
+* `a`: Equatorial point of the first body *| in radians*
+* `b`: Equatorial point of the second body *| in radians*
+**/
+pub fn separation(a: &EqPoint, b: &EqPoint) -> f64 {
+    let (sin_dec_a, cos_dec_a) = a.dec.sin_cos();
+    let (sin_dec_b, cos_dec_b) = b.dec.sin_cos();
+    let (sin_d_asc, cos_d_asc) = (b.asc - a.asc).sin_cos();
+
+    let x = cos_dec_b * sin_d_asc;
+    let y = cos_dec_a * sin_dec_b - sin_dec_a * cos_dec_b * cos_d_asc;
+    let z = sin_dec_a * sin_dec_b + cos_dec_a * cos_dec_b * cos_d_asc;
+
+    (x * x + y * y).sqrt().atan2(z)
+}
+
+/**
+Computes the position angle of one equatorial point relative to
+another
+
+The position angle is measured from the north, through the east, as
+for double stars.
+
+# Returns
+
+* `pos_angl`: Position angle of `b` relative to `a`, in the range
+  [0, 2π) *| in radians*
+
+# Arguments
+
+* `a`: Equatorial point of the reference body *| in radians*
+* `b`: Equatorial point of the other body *| in radians*
+**/
+pub fn position_angle(a: &EqPoint, b: &EqPoint) -> f64 {
+    let d_asc = b.asc - a.asc;
+
+    angle::limit_to_two_PI(
+        (b.dec.cos() * d_asc.sin())
+            .atan2(a.dec.cos() * b.dec.sin() - a.dec.sin() * b.dec.cos() * d_asc.cos()),
+    )
+}
+
 /**
 Computes the hour angle from geographical longitude and Greenwhich
 sidereal time
@@ -335,6 +439,232 @@ pub fn dec_frm_hz(az: f64, alt: f64, observer_lat: f64) -> f64 {
     (observer_lat.sin() * alt.sin() - observer_lat.cos() * az.cos() * az.cos()).asin()
 }
 
+/**
+Computes the parallactic angle of a celestial body
+
+This is the angle between the great circle through the body and the
+zenith, and the hour circle of the body. At the zenith (and at the
+celestial poles for an observer on them) it's undefined, and `0.0` is
+returned by convention.
+
+# Returns
+
+* `parallac_angle`: Parallactic angle *| in radians*
+
+It's negative east of the meridian, zero on it, and positive west of it.
+
+# Arguments
+
+* `hour_angle`  : Local hour angle *| in radians*
+* `dec`         : Declination *| in radians*
+* `observer_lat`: Observer's geographical latitude *| in radians*
+**/
+pub fn parallactic_angle(hour_angle: f64, dec: f64, observer_lat: f64) -> f64 {
+    let y = hour_angle.sin() * observer_lat.cos();
+    let x = observer_lat.sin() * dec.cos() - observer_lat.cos() * dec.sin() * hour_angle.cos();
+
+    if y.abs() < 1e-12 && x.abs() < 1e-12 {
+        0.0
+    } else {
+        y.atan2(x)
+    }
+}
+
+/**
+Computes the field rotation angle of a celestial body
+
+This is the angle between the direction to the north celestial pole
+and the direction to the zenith, at the position of the body; ie, the
+parallactic angle. It is the angle by which the field of an alt-azimuth
+mounted telescope is rotated with respect to celestial north.
+
+# Returns
+
+* `field_rot_angl`: Field rotation angle *| in radians*. It's negative
+                    east of the meridian, zero on it, and positive
+                    west of it.
+
+# Arguments
+
+* `eq_point`      : Equatorial point of the body *| in radians*
+* `geograph_point`: Geographic point of the observer *| in radians*
+* `local_sidr`    : Local sidereal time *| in radians*
+**/
+#[inline]
+pub fn field_rotation_angle(
+    eq_point: &EqPoint,
+    geograph_point: &GeographPoint,
+    local_sidr: f64,
+) -> f64 {
+    parallactic_angle(
+        hr_angl_frm_loc_sidr(local_sidr, eq_point.asc),
+        eq_point.dec,
+        geograph_point.lat,
+    )
+}
+
+/**
+Computes the rate of field rotation of a celestial body
+
+This is the rate of change of the
+[field rotation angle](./fn.field_rotation_angle.html) due to the
+diurnal motion. It's fastest for bodies transiting near the zenith,
+and diverges at the zenith itself.
+
+# Returns
+
+* `field_rot_rate`: Field rotation rate *| in radians per second*
+
+# Arguments
+
+* `eq_point`      : Equatorial point of the body *| in radians*
+* `geograph_point`: Geographic point of the observer *| in radians*
+* `local_sidr`    : Local sidereal time *| in radians*
+**/
+pub fn field_rotation_rate(
+    eq_point: &EqPoint,
+    geograph_point: &GeographPoint,
+    local_sidr: f64,
+) -> f64 {
+    let H = hr_angl_frm_loc_sidr(local_sidr, eq_point.asc);
+    let x = geograph_point.lat.tan() * eq_point.dec.cos();
+    let d = x - eq_point.dec.sin() * H.cos();
+
+    planet::earth::rot_angular_velocity() * (x * H.cos() - eq_point.dec.sin())
+        / (H.sin() * H.sin() + d * d)
+}
+
+/**
+Computes the rate of change of the angular separation between two
+celestial bodies
+
+The rate is the change in separation between the two epochs, divided
+by the interval. It's most accurate for the instant midway between
+the epochs, and vanishes near the least separation of the bodies,
+which makes it suitable for refining the instant of a conjunction or
+an appulse with Newton's method.
+
+# Returns
+
+* `sepr_rate`: Rate of change of the angular separation
+               *| in radians per day*
+
+# Arguments
+
+* `p1a`          : Equatorial point of the first body at the first
+                   epoch *| in radians*
+* `p1b`          : Equatorial point of the first body at the second
+                   epoch *| in radians*
+* `p2a`          : Equatorial point of the second body at the first
+                   epoch *| in radians*
+* `p2b`          : Equatorial point of the second body at the second
+                   epoch *| in radians*
+* `interval_days`: Interval between the two epochs *| in days*
+**/
+pub fn separation_rate(
+    p1a: &EqPoint,
+    p1b: &EqPoint,
+    p2a: &EqPoint,
+    p2b: &EqPoint,
+    interval_days: f64,
+) -> f64 {
+    (p1b.anglr_sepr(p2b) - p1a.anglr_sepr(p2a)) / interval_days
+}
+
+/**
+Computes the matrix that rotates ecliptic rectangular coordinates
+into equatorial rectangular coordinates
+
+The transpose of this matrix rotates equatorial rectangular
+coordinates into ecliptic rectangular coordinates.
+
+# Returns
+
+* `matrix`: Rotation matrix, as an array of rows
+
+# Arguments
+
+* `oblq_eclip`: Obliquity of the ecliptic *| in radians*
+**/
+pub fn obliquity_rotation_matrix(oblq_eclip: f64) -> [[f64; 3]; 3] {
+    let (s, c) = oblq_eclip.sin_cos();
+
+    [[1.0, 0.0, 0.0], [0.0, c, -s], [0.0, s, c]]
+}
+
+/**
+Computes the direction of the mean equinox of the date, referred to
+the mean equator and equinox of J2000.0
+
+In the mean equatorial frame of the date, the equinox is along the
+x-axis. Expressing it in the frame of J2000.0 shows the motion of the
+equinox due to precession, of about 50.3 arcseconds a year along the
+ecliptic.
+
+# Returns
+
+* `direction`: Unit vector towards the mean equinox of the date, in
+               equatorial rectangular coordinates of J2000.0
+
+# Arguments
+
+* `JD`: Julian (Ephemeris) day
+**/
+pub fn vernal_equinox_direction(JD: f64) -> [f64; 3] {
+    apply_matrix(&precess::precession_matrix_eq(JD, 2451545.0), &[1.0, 0.0, 0.0])
+}
+
+/**
+Applies a rotation matrix to a vector
+
+# Returns
+
+* `rotated_vector`: The product of `matrix` and `vector`
+
+# Arguments
+
+* `matrix`: Rotation matrix, as an array of rows
+* `vector`: Rectangular coordinates
+**/
+pub fn apply_matrix(matrix: &[[f64; 3]; 3], vector: &[f64; 3]) -> [f64; 3] {
+    let mut rotated_vector = [0.0; 3];
+
+    for (row, x) in matrix.iter().zip(rotated_vector.iter_mut()) {
+        *x = row[0] * vector[0] + row[1] * vector[1] + row[2] * vector[2];
+    }
+
+    rotated_vector
+}
+
+/**
+Computes the local horizontal coordinates of the Galactic center
+
+The Galactic center is taken at the radio source Sgr A*, with it's
+equatorial coordinates referred to the equinox of J2000.0. Precession
+of these coordinates to the date is neglected, which amounts to less
+than a minute of arc over a few decades around J2000.0.
+
+# Returns
+
+* `horiz_point`: Horizontal point of the Galactic center
+                 *| in radians*
+
+# Arguments
+
+* `geograph_point`: Geographic point of the observer *| in radians*
+* `local_sidr`    : Local sidereal time *| in radians*
+**/
+pub fn galactic_center_horiz(geograph_point: &GeographPoint, local_sidr: f64) -> HorizPoint {
+    let asc = angle::deg_frm_hms(17, 45, 40.04).to_radians();
+    let dec = angle::deg_frm_dms(-29, 0, 28.1).to_radians();
+    let hour_angle = hr_angl_frm_loc_sidr(local_sidr, asc);
+
+    HorizPoint {
+        az: az_frm_eq(hour_angle, dec, geograph_point.lat),
+        alt: alt_frm_eq(hour_angle, dec, geograph_point.lat),
+    }
+}
+
 /**
 Computes the galactic longitude from equatorial coordinates
 
@@ -478,3 +808,402 @@ macro_rules! eq_frm_gal {
         )
     }};
 }
+
+/**
+Computes galactic coordinates from equatorial coordinates referred to
+any epoch
+
+The galactic pole and origin of longitude are the IAU 1958 ones,
+defined in the equatorial frame of B1950.0. The equatorial
+coordinates are therefore first precessed to B1950.0 with
+[precess::precess_eq_coords()](../precess/fn.precess_eq_coords.html).
+The difference between the FK4 system of B1950.0 and the FK5 system
+of J2000.0 is neglected, which amounts to about an arcsecond.
+
+# Returns
+
+* `gal_point`: Galactic point *| in radians*
+
+# Arguments
+
+* `eq_point`: Equatorial point *| in radians*
+* `epoch`   : Julian (Ephemeris) day of the epoch to which `eq_point`
+              is referred. Eg: `2451545.0` for J2000.0.
+**/
+pub fn galactic_frm_eq(eq_point: &EqPoint, epoch: f64) -> GalacticPoint {
+    let (asc, dec) = precess::precess_eq_coords(eq_point.asc, eq_point.dec, epoch, 2433282.4235);
+
+    GalacticPoint {
+        long: angle::limit_to_two_PI(gal_long_frm_eq(asc, dec)),
+        lat: gal_lat_frm_eq(asc, dec),
+    }
+}
+
+/**
+Computes equatorial coordinates referred to any epoch from galactic
+coordinates
+
+This is the inverse of [galactic_frm_eq()](./fn.galactic_frm_eq.html).
+
+# Returns
+
+* `eq_point`: Equatorial point *| in radians*, referred to `epoch`
+
+# Arguments
+
+* `gal_point`: Galactic point *| in radians*
+* `epoch`    : Julian (Ephemeris) day of the epoch to which the
+               equatorial point is to be referred
+**/
+pub fn eq_frm_galactic(gal_point: &GalacticPoint, epoch: f64) -> EqPoint {
+    let asc = asc_frm_gal(gal_point.long, gal_point.lat);
+    let dec = dec_frm_gal(gal_point.long, gal_point.lat);
+    let (asc, dec) = precess::precess_eq_coords(asc, dec, 2433282.4235, epoch);
+
+    EqPoint {
+        asc: angle::limit_to_two_PI(asc),
+        dec,
+    }
+}
+
+/**
+Converts an equatorial point from the FK4 system to the FK5 system,
+for an object whose proper motion is unknown
+
+The point, referred to the mean equator and equinox of B1950.0 in the
+FK4 system, has the elliptic terms of aberration (E-terms) removed,
+and is then carried to J2000.0 in the FK5 system by the matrix of
+E. M. Standish (1982), which also corrects the equinox and the
+rotation of the FK4 system.
+
+As the FK4 system rotates, an object that's at rest in the FK5
+system appears to have a fictitious proper motion in the FK4 system.
+The conversion assumes the object to be at rest in the FK5 system, so
+`eq_point_FK5` depends on the epoch at which `eq_point_FK4` was
+observed.
+
+# Returns
+
+* `eq_point_FK5`: Equatorial point in the FK5 system, referred to
+  J2000.0 *| in radians*
+
+# Arguments
+
+* `eq_point_FK4`: Equatorial point in the FK4 system, referred to
+  B1950.0 *| in radians*
+* `epoch`       : Julian (Ephemeris) day on which the object was at
+  `eq_point_FK4`. Eg: `2433282.4235` for B1950.0.
+**/
+pub fn fk4_to_fk5(eq_point_FK4: &EqPoint, epoch: f64) -> EqPoint {
+    eq_point_frm_vector(&fk4_vector_to_fk5(&vector_frm_eq_point(eq_point_FK4), epoch))
+}
+
+/**
+Converts an equatorial point from the FK5 system to the FK4 system,
+for an object whose proper motion is unknown
+
+This is the inverse of [fk4_to_fk5()](./fn.fk4_to_fk5.html), and
+assumes the object to be at rest in the FK5 system.
+
+# Returns
+
+* `eq_point_FK4`: Equatorial point in the FK4 system, referred to
+  B1950.0 *| in radians*
+
+# Arguments
+
+* `eq_point_FK5`: Equatorial point in the FK5 system, referred to
+  J2000.0 *| in radians*
+* `epoch`       : Julian (Ephemeris) day at which the FK4 point is to
+  be observed
+**/
+pub fn fk5_to_fk4(eq_point_FK5: &EqPoint, epoch: f64) -> EqPoint {
+    let fk5 = vector_frm_eq_point(eq_point_FK5);
+
+    // As fk4_vector_to_fk5() is a rotation to within a few parts in a
+    // million, correcting by the inverse rotation converges quickly
+    let mut fk4 = fk5;
+    for _ in 0..4 {
+        let err = fk4_vector_to_fk5(&fk4, epoch);
+        for i in 0..3 {
+            for j in 0..3 {
+                fk4[i] += FK4_TO_FK5[j][i] * (fk5[j] - err[j]);
+            }
+        }
+    }
+
+    eq_point_frm_vector(&fk4)
+}
+
+/**
+Converts an equatorial point and the space motion of a star from the
+FK4 system to the FK5 system
+
+This is the rigorous conversion of E. M. Standish (1982), which also
+converts the E-terms and the rotation of the FK4 system for the
+proper motion.
+
+# Returns
+
+`(eq_point_FK5, pm_asc_FK5, pm_dec_FK5, parallax_FK5, radial_velocity_FK5)`
+
+* `eq_point_FK5`       : Equatorial point in the FK5 system, at and
+  referred to J2000.0 *| in radians*
+* `pm_asc_FK5`         : Proper motion in right ascension *| in radians
+  per Julian year*
+* `pm_dec_FK5`         : Proper motion in declination *| in radians per
+  Julian year*
+* `parallax_FK5`       : Annual parallax *| in radians*
+* `radial_velocity_FK5`: Radial velocity *| in km/s*
+
+# Arguments
+
+* `eq_point_FK4`   : Equatorial point in the FK4 system, at and
+  referred to B1950.0 *| in radians*
+* `pm_asc`         : Proper motion in right ascension *| in radians
+  per tropical year*
+* `pm_dec`         : Proper motion in declination *| in radians per
+  tropical year*
+* `parallax`       : Annual parallax *| in radians*
+* `radial_velocity`: Radial velocity *| in km/s*, positive if the star
+  is receding
+
+A zero parallax leaves the radial velocity unchanged.
+**/
+pub fn fk4_to_fk5_with_motion(
+    eq_point_FK4: &EqPoint,
+    pm_asc: f64,
+    pm_dec: f64,
+    parallax: f64,
+    radial_velocity: f64,
+) -> (EqPoint, f64, f64, f64, f64) {
+    let (asc_sin, asc_cos) = eq_point_FK4.asc.sin_cos();
+    let (dec_sin, dec_cos) = eq_point_FK4.dec.sin_cos();
+    let pm_asc = pm_asc * RAD_PER_YR_TO_ARCSEC_PER_CENT;
+    let pm_dec = pm_dec * RAD_PER_YR_TO_ARCSEC_PER_CENT;
+    let parallax_arcsec = parallax.to_degrees() * 3600.0;
+
+    let r = vector_frm_eq_point(eq_point_FK4);
+    let w = KM_PER_S_TO_AU_PER_CENT * radial_velocity * parallax_arcsec;
+    let r_dot = [
+        -asc_sin * dec_cos * pm_asc - asc_cos * dec_sin * pm_dec + w * r[0],
+        asc_cos * dec_cos * pm_asc - asc_sin * dec_sin * pm_dec + w * r[1],
+        dec_cos * pm_dec + w * r[2],
+    ];
+
+    // Remove the E-terms from the position and the velocity
+    let e_r = dot_product(&r, &E_TERMS);
+    let e_r_dot = dot_product(&r, &E_TERMS_RATE);
+    let mut v1 = [0.0; 6];
+    for i in 0..3 {
+        v1[i] = r[i] - E_TERMS[i] + e_r * r[i];
+        v1[i + 3] = r_dot[i] - E_TERMS_RATE[i] + e_r_dot * r[i];
+    }
+
+    let mut v2 = [0.0; 6];
+    for (i, v2_i) in v2.iter_mut().enumerate() {
+        *v2_i = FK4_TO_FK5[i].iter().zip(&v1).map(|(m, v)| m * v).sum();
+    }
+
+    let (x, y, z) = (v2[0], v2[1], v2[2]);
+    let (x_dot, y_dot, z_dot) = (v2[3], v2[4], v2[5]);
+    let rxy_sqr = x * x + y * y;
+    let rxy = rxy_sqr.sqrt();
+    let rxyz = (rxy_sqr + z * z).sqrt();
+    let xy_dot = x * x_dot + y * y_dot;
+
+    let (pm_asc, pm_dec) = if rxy > 0.0 {
+        (
+            (x * y_dot - y * x_dot) / rxy_sqr,
+            (z_dot * rxy_sqr - z * xy_dot) / (rxyz * rxyz * rxy),
+        )
+    } else {
+        (0.0, 0.0)
+    };
+
+    let (parallax, radial_velocity) = if parallax > 0.0 {
+        (
+            parallax / rxyz,
+            (xy_dot + z * z_dot) / (parallax_arcsec * rxyz * KM_PER_S_TO_AU_PER_CENT),
+        )
+    } else {
+        (parallax, radial_velocity)
+    };
+
+    (
+        eq_point_frm_vector(&[x, y, z]),
+        pm_asc / RAD_PER_YR_TO_ARCSEC_PER_CENT,
+        pm_dec / RAD_PER_YR_TO_ARCSEC_PER_CENT,
+        parallax,
+        radial_velocity,
+    )
+}
+
+// The E-terms of aberration at B1950.0, and their rate of change in
+// arcseconds per tropical century
+const E_TERMS: [f64; 3] = [-1.62557e-6, -0.31919e-6, -0.13843e-6];
+const E_TERMS_RATE: [f64; 3] = [1.245e-3, -1.580e-3, -0.659e-3];
+
+// Converts radians per year to arcseconds per century
+const RAD_PER_YR_TO_ARCSEC_PER_CENT: f64 = 100.0 * 3600.0 * 180.0 / std::f64::consts::PI;
+
+// Converts km/s to AU per tropical century, per arcsecond of parallax
+const KM_PER_S_TO_AU_PER_CENT: f64 = 21.095;
+
+// The matrix of E. M. Standish (1982), acting on position vectors and
+// velocities in arcseconds per century
+const FK4_TO_FK5: [[f64; 6]; 6] = [
+    [
+        0.9999256782,
+        -0.0111820611,
+        -0.0048579477,
+        0.00000242395018,
+        -0.00000002710663,
+        -0.00000001177656,
+    ],
+    [
+        0.0111820610,
+        0.9999374784,
+        -0.0000271765,
+        0.00000002710663,
+        0.00000242397878,
+        -0.00000000006587,
+    ],
+    [
+        0.0048579479,
+        -0.0000271474,
+        0.9999881997,
+        0.00000001177656,
+        -0.00000000006582,
+        0.00000242410173,
+    ],
+    [-0.000551, -0.238565, 0.435739, 0.99994704, -0.01118251, -0.00485767],
+    [0.238514, -0.002667, -0.008541, 0.01118251, 0.99995883, -0.00002718],
+    [-0.435623, 0.012254, 0.002117, 0.00485767, -0.00002714, 1.00000956],
+];
+
+// Converts a position vector from the FK4 system to the FK5 system,
+// for an object at rest in the FK5 system
+fn fk4_vector_to_fk5(r: &[f64; 3], epoch: f64) -> [f64; 3] {
+    let besselian_yr = time::besselian_epoch_frm_julian_day(epoch);
+    let julian_yr = time::julian_epoch_frm_julian_day(epoch);
+
+    // Remove the E-terms, adjusted to give the object zero proper
+    // motion in the FK5 system
+    let t = (besselian_yr - 1950.0) / RAD_PER_YR_TO_ARCSEC_PER_CENT;
+    let mut e_terms = [0.0; 3];
+    for i in 0..3 {
+        e_terms[i] = E_TERMS[i] + t * E_TERMS_RATE[i];
+    }
+    let e_r = dot_product(r, &e_terms);
+    let mut v1 = [0.0; 3];
+    for i in 0..3 {
+        v1[i] = r[i] - e_terms[i] + e_r * r[i];
+    }
+
+    // Rotate to the FK5 system, and allow for the fictitious proper
+    // motion in the FK4 system
+    let t = (julian_yr - 2000.0) / RAD_PER_YR_TO_ARCSEC_PER_CENT;
+    let mut v2 = [0.0; 3];
+    for (i, v2_i) in v2.iter_mut().enumerate() {
+        for j in 0..3 {
+            *v2_i += (FK4_TO_FK5[i][j] + t * FK4_TO_FK5[i + 3][j]) * v1[j];
+        }
+    }
+
+    v2
+}
+
+#[inline]
+fn dot_product(a: &[f64; 3], b: &[f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+#[inline]
+fn vector_frm_eq_point(eq_point: &EqPoint) -> [f64; 3] {
+    [
+        eq_point.dec.cos() * eq_point.asc.cos(),
+        eq_point.dec.cos() * eq_point.asc.sin(),
+        eq_point.dec.sin(),
+    ]
+}
+
+#[inline]
+fn eq_point_frm_vector(r: &[f64; 3]) -> EqPoint {
+    EqPoint {
+        asc: angle::limit_to_two_PI(r[1].atan2(r[0])),
+        dec: r[2].atan2((r[0] * r[0] + r[1] * r[1]).sqrt()),
+    }
+}
+
+/**
+Computes the apparent place of a star from its catalog position
+
+The catalog position, referred to the mean equator and equinox of
+J2000.0, is carried to the date by the star's
+[space motion](../star/fn.apply_space_motion.html), and then
+[precessed](../precess/fn.precess_eq_coords.html) to the mean equinox
+of the date. It's then corrected for
+[nutation](../nutation/fn.apply_nutation.html) and for
+[annual aberration](../aberr/fn.annual_aberr.html), in that order.
+
+# Returns
+
+* `apprnt_eq_point`: Apparent equatorial point of the star *| in radians*
+
+# Arguments
+
+* `catalog_eq`     : Equatorial point of the star at J2000.0 *| in radians*
+* `pm_asc`         : Annual proper motion in right ascension *| in radians*
+* `pm_dec`         : Annual proper motion in declination *| in radians*
+* `parallax`       : Annual parallax of the star *| in radians*
+* `radial_velocity`: Radial velocity of the star *| in km/s*
+* `JD`             : Julian (Ephemeris) day
+
+`apprnt_eq_point` is referred to the true equator and equinox of the
+date. The units of the space motion are those of
+[star::apply_space_motion()](../star/fn.apply_space_motion.html).
+**/
+pub fn apparent_place(
+    catalog_eq: &EqPoint,
+    pm_asc: f64,
+    pm_dec: f64,
+    parallax: f64,
+    radial_velocity: f64,
+    JD: f64,
+) -> EqPoint {
+    let J2000 = 2451545.0;
+    let eq_point =
+        star::apply_space_motion(catalog_eq, pm_asc, pm_dec, parallax, radial_velocity, J2000, JD);
+
+    let (asc, dec) = precess::precess_eq_coords(eq_point.asc, eq_point.dec, J2000, JD);
+    let eq_point = nutation::apply_nutation(&EqPoint { asc, dec }, JD);
+
+    let (abrr_in_asc, abrr_in_dec) = aberr::annual_aberr(&eq_point, JD);
+
+    EqPoint {
+        asc: angle::limit_to_two_PI(eq_point.asc + abrr_in_asc),
+        dec: eq_point.dec + abrr_in_dec,
+    }
+}
+
+/**
+Computes the apparent place of an object whose space motion is
+unknown, from its catalog position
+
+This is the same as [apparent_place()](./fn.apparent_place.html), with
+the proper motion, parallax and radial velocity all taken as zero.
+
+# Returns
+
+* `apprnt_eq_point`: Apparent equatorial point of the object *| in radians*
+
+# Arguments
+
+* `catalog_eq`: Equatorial point of the object at J2000.0 *| in radians*
+* `JD`        : Julian (Ephemeris) day
+**/
+#[inline]
+pub fn apparent_place_no_motion(catalog_eq: &EqPoint, JD: f64) -> EqPoint {
+    apparent_place(catalog_eq, 0.0, 0.0, 0.0, 0.0, JD)
+}
@@ -0,0 +1,66 @@
+/*
+Copyright (c) 2015, 2016 Saurav Sachidanand
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+//! Solar eclipses
+
+use angle;
+use lunar;
+use sun;
+
+/**
+Computes the geocentric angular separation and the position angle of
+the Moon relative to the Sun
+
+During a solar eclipse, the separation of the centers is less than
+the sum of the semidiameters of the Sun and the Moon, as seen from some
+place on the Earth. As the Moon's parallax is of about a degree, the
+geocentric separation of the disks can be larger than that sum.
+
+# Returns
+
+`(sepr, pos_angl)`
+
+* `sepr`    : Geocentric separation of the centers *| in radians*
+* `pos_angl`: Position angle of the Moon's center *| in radians*
+
+The position angle is that of the Moon's center relative to the
+Sun's, measured eastwards from the North.
+
+# Arguments
+
+* `JD`: Julian (Ephemeris) day
+**/
+pub fn sun_moon_geometry(JD: f64) -> (f64, f64) {
+    let (sun_eq_point, _) = sun::apprnt_eq_pos(JD);
+    let (moon_eq_point, _) = lunar::apprnt_eq_pos(JD);
+
+    let d_asc = moon_eq_point.asc - sun_eq_point.asc;
+    let pos_angl = (moon_eq_point.dec.cos() * d_asc.sin()).atan2(
+        moon_eq_point.dec.sin() * sun_eq_point.dec.cos()
+            - moon_eq_point.dec.cos() * sun_eq_point.dec.sin() * d_asc.cos(),
+    );
+
+    (
+        sun_eq_point.anglr_sepr(&moon_eq_point),
+        angle::limit_to_two_PI(pos_angl),
+    )
+}
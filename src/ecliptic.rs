@@ -38,12 +38,17 @@ The accuracy of `mn_oblq` is estimated to be 0.01 arcseconds
 for 1000 years before and after 2000 AD, and a few arcseconds
 for 10000 years before and after 2000 AD.
 
+The formula is valid only over those 10000 years either side of
+2000 AD, outside of which the polynomial quickly diverges. For a `JD`
+outside that range, the obliquity at the nearer end of it is
+returned.
+
 # Arguments
 
 * `JD`: Julian (Ephemeris) day
 **/
 pub fn mn_oblq_laskar(JD: f64) -> f64 {
-    let u = time::julian_cent(JD) / 100.0;
+    let u = (time::julian_cent(JD) / 100.0).clamp(-1.0, 1.0);
 
     Horner_eval!(
         u,
@@ -78,10 +83,10 @@ The error in `mn_oblq` reaches 1 arcsecond over a period of
 * `JD`: Julian (Ephemeris) day
 **/
 pub fn mn_oblq_IAU(JD: f64) -> f64 {
-    let u = time::julian_cent(JD) / 100.0;
+    let t = time::julian_cent(JD);
 
     Horner_eval!(
-        u,
+        t,
         angle::deg_frm_dms(23, 26, 21.448),
         -angle::deg_frm_dms(0, 0, 46.815),
         -angle::deg_frm_dms(0, 0, 0.00059),
@@ -0,0 +1,154 @@
+/*
+Copyright (c) 2015, 2016 Saurav Sachidanand
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+//! Positions of the Sun, the Moon and the planets in a chosen frame
+
+use aberr;
+use angle;
+use coords;
+use ecliptic;
+use lunar;
+use nutation;
+use planet;
+use precess;
+use sun;
+
+/// Represents a body of the Solar System
+pub enum Body {
+    /// The Sun
+    Sun,
+    /// The Moon
+    Moon,
+    /// A planet
+    Planet(planet::Planet),
+}
+
+/**
+Computes the geocentric position of a body of the Solar System,
+referred to a chosen frame
+
+The positions of the planets are corrected for light-time, and that
+of the Sun for aberration. Precession to J2000.0 and to the equinox
+of B1950.0 (to which the galactic frame is referred) is done with
+[precess::precess_eq_coords()](../precess/fn.precess_eq_coords.html).
+
+# Returns
+
+* `point`: Position of the body *| in radians*. For
+           `Frame::MeanEclOfDate` and `Frame::Galactic`, `asc` holds
+           the longitude, and `dec` the latitude.
+
+# Arguments
+
+* `body` : The `Body`. `Planet::Earth` can't be passed.
+* `JD`   : Julian (Ephemeris) day
+* `frame`: The [Frame](../coords/enum.Frame.html) of the position
+**/
+pub fn position<'a>(
+    body: &Body,
+    JD: f64,
+    frame: &coords::Frame,
+) -> Result<coords::EqPoint, &'a str> {
+    let ecl_point = match *body {
+        Body::Sun => {
+            let (sun_ecl_point, sun_earth_dist) = sun::geocent_ecl_pos(JD);
+            let (long, lat) = sun::ecl_coords_to_FK5(JD, sun_ecl_point.long, sun_ecl_point.lat);
+            let lat = angle::limit_to_pm_PI(lat);
+
+            coords::EclPoint {
+                long: long + aberr::sol_aberr(sun_earth_dist),
+                lat,
+            }
+        }
+        Body::Moon => lunar::geocent_ecl_pos(JD).0,
+        Body::Planet(planet::Planet::Earth) => {
+            return Err("Planet::Earth was passed to the function ephem::position()");
+        }
+        Body::Planet(ref p) => planet::geocent_apprnt_ecl_coords(p, JD).0,
+    };
+
+    Ok(ecl_point_to_frame(&ecl_point, JD, frame))
+}
+
+/**
+Converts a geocentric ecliptic position, referred to the mean equinox
+of the date, to a chosen frame
+
+Precession is done as in [position()](./fn.position.html).
+
+# Returns
+
+* `point`: Position in `frame` *| in radians*. For
+           `Frame::MeanEclOfDate` and `Frame::Galactic`, `asc` holds
+           the longitude, and `dec` the latitude.
+
+# Arguments
+
+* `ecl_point`: Ecliptic point *| in radians*, referred to the mean
+               equinox of the date
+* `JD`       : Julian (Ephemeris) day
+* `frame`    : The [Frame](../coords/enum.Frame.html) of the position
+**/
+pub fn ecl_point_to_frame(
+    ecl_point: &coords::EclPoint,
+    JD: f64,
+    frame: &coords::Frame,
+) -> coords::EqPoint {
+    let mn_oblq = ecliptic::mn_oblq_laskar(JD);
+    let mn_eq_point = || coords::EqPoint {
+        asc: coords::asc_frm_ecl(ecl_point.long, ecl_point.lat, mn_oblq),
+        dec: coords::dec_frm_ecl(ecl_point.long, ecl_point.lat, mn_oblq),
+    };
+
+    match *frame {
+        coords::Frame::MeanEclOfDate => coords::EqPoint {
+            asc: ecl_point.long,
+            dec: ecl_point.lat,
+        },
+        coords::Frame::EqOfDate => {
+            let (nut_in_long, nut_in_oblq) = nutation::nutation(JD);
+            let true_oblq = mn_oblq + nut_in_oblq;
+            let long = ecl_point.long + nut_in_long;
+
+            coords::EqPoint {
+                asc: coords::asc_frm_ecl(long, ecl_point.lat, true_oblq),
+                dec: coords::dec_frm_ecl(long, ecl_point.lat, true_oblq),
+            }
+        }
+        coords::Frame::EqJ2000 => {
+            let eq_point = mn_eq_point();
+            let (asc, dec) = precess::precess_eq_coords(eq_point.asc, eq_point.dec, JD, 2451545.0);
+
+            coords::EqPoint { asc, dec }
+        }
+        coords::Frame::Galactic => {
+            let eq_point = mn_eq_point();
+            let (asc, dec) =
+                precess::precess_eq_coords(eq_point.asc, eq_point.dec, JD, 2433282.4235);
+
+            coords::EqPoint {
+                asc: coords::gal_long_frm_eq(asc, dec),
+                dec: coords::gal_lat_frm_eq(asc, dec),
+            }
+        }
+    }
+}
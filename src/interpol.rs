@@ -48,6 +48,38 @@ pub fn three_values(y1: f64, y2: f64, y3: f64, n: f64) -> f64 {
     y2 + n * (a + b + n * c) / 2.0
 }
 
+/**
+Interpolates an intermediate value of a function, and its rate of
+change, from three of its given values
+
+# Returns
+
+`(interpol_val, deriv)`
+
+* `interpol_val`: Intermediate value of the function
+* `deriv`       : Rate of change of the function, per tabular
+  interval
+
+Dividing `deriv` by the tabular interval gives the rate of change per
+unit of the argument, e.g. per day for daily values.
+
+# Arguments
+
+* `y1`: Value 1 of the function
+* `y2`: Value 2 of the function
+* `y3`: Value 3 of the function
+* `n` : Interpolating factor, measured from the central value
+  `y2`, positively towards `y3`
+**/
+#[inline]
+pub fn three_values_with_derivative(y1: f64, y2: f64, y3: f64, n: f64) -> (f64, f64) {
+    let a = y2 - y1;
+    let b = y3 - y2;
+    let c = b - a;
+
+    (y2 + n * (a + b + n * c) / 2.0, (a + b) / 2.0 + n * c)
+}
+
 /**
 Interpolates an intermediate value of a function from five of it's
 given values
@@ -84,3 +116,217 @@ pub fn five_values(y1: f64, y2: f64, y3: f64, y4: f64, y5: f64, n: f64) -> f64 {
 
     y3 + Horner_eval!(n, 0.0, b + c - h_j_12, f - k, h_j_12, k) / 2.0
 }
+
+/**
+Interpolates an intermediate value of a function from any number of
+its given values, using Lagrange's formula
+
+# Returns
+
+* `interpol_val`: Intermediate value of the function
+
+# Arguments
+
+* `xs`: Values of the argument of the function
+* `ys`: Values of the function, one for each value in `xs`
+* `x` : Argument at which to interpolate
+
+The values in `xs` need not be equidistant or in order, but must be
+distinct. For five equidistant values,
+[`five_values()`](./fn.five_values.html) is faster.
+
+# Panics
+
+If `xs` and `ys` differ in length, or are empty.
+**/
+pub fn lagrange(xs: &[f64], ys: &[f64], x: f64) -> f64 {
+    assert!(
+        xs.len() == ys.len(),
+        "interpol::lagrange() was passed {} arguments and {} function values",
+        xs.len(),
+        ys.len()
+    );
+    assert!(!xs.is_empty(), "interpol::lagrange() was passed no values");
+
+    let mut interpol_val = 0.0;
+    for (i, (x_i, y_i)) in xs.iter().zip(ys).enumerate() {
+        let mut c = 1.0;
+        for (j, x_j) in xs.iter().enumerate() {
+            if j != i {
+                c *= (x - x_j) / (x_i - x_j);
+            }
+        }
+        interpol_val += c * y_i;
+    }
+
+    interpol_val
+}
+
+/**
+Interpolates the extremum of a function from three of its given
+values
+
+# Returns
+
+`Some((n_m, y_m))` if the three values have an extremum, which they
+don't if they lie on a straight line, else `None`
+
+* `n_m`: Interpolating factor of the extremum, measured from the
+  central value `y2`, positively towards `y3`
+* `y_m`: Value of the function at the extremum
+
+# Arguments
+
+* `y1`: Value 1 of the function
+* `y2`: Value 2 of the function
+* `y3`: Value 3 of the function
+
+`n_m` may be beyond the interval [-1, 1] spanned by the three values,
+in which case `y_m` is extrapolated and should be used with caution.
+**/
+pub fn extremum(y1: f64, y2: f64, y3: f64) -> Option<(f64, f64)> {
+    let a = y2 - y1;
+    let b = y3 - y2;
+    let c = b - a;
+
+    if c == 0.0 {
+        return None;
+    }
+
+    Some((-(a + b) / (2.0 * c), y2 - (a + b) * (a + b) / (8.0 * c)))
+}
+
+/**
+Interpolates the zero of a function from three of its given values
+
+# Returns
+
+`Some(n_0)` if the function interpolated from the three values is zero
+in the interval [-1, 1] spanned by them, else `None`
+
+* `n_0`: Interpolating factor of the zero, measured from the central
+  value `y2`, positively towards `y3`
+
+If the function has two zeros in the interval, the one nearer to `y2`
+is returned.
+
+# Arguments
+
+* `y1`: Value 1 of the function
+* `y2`: Value 2 of the function
+* `y3`: Value 3 of the function
+**/
+pub fn zero(y1: f64, y2: f64, y3: f64) -> Option<f64> {
+    let a = y2 - y1;
+    let b = y3 - y2;
+    let c = b - a;
+
+    // The zeros of y2 + n(a + b)/2 + n²c/2, solved so as to avoid
+    // the loss of precision when c is small
+    let p = a + b;
+    let disc = p * p - 8.0 * c * y2;
+    if disc < 0.0 {
+        return None;
+    }
+    let q = -(p + p.signum() * disc.sqrt()) / 2.0;
+
+    let mut zeros = Vec::new();
+    if q != 0.0 {
+        zeros.push(2.0 * y2 / q);
+    } else if y2 == 0.0 {
+        zeros.push(0.0);
+    }
+    if c != 0.0 {
+        zeros.push(q / c);
+    }
+
+    zeros
+        .into_iter()
+        .filter(|n: &f64| n.abs() <= 1.0)
+        .min_by(|n1, n2| n1.abs().partial_cmp(&n2.abs()).unwrap())
+}
+
+// Refines an extremum of a function, found near `x` by sampling it in
+// steps of `step`, until the interval of interpolation is shorter than
+// `tol`. Each pass interpolates the extremum from the values at x and
+// a step either side of it, moves there and shortens the step, and an
+// extremum beyond the three values only moves x by a whole step. Once
+// the step is short, rounding in f can keep putting the extremum
+// beyond the values, and so at most 100 passes are made.
+pub(crate) fn refine_extremum(f: &dyn Fn(f64) -> f64, x: f64, step: f64, tol: f64) -> f64 {
+    let (mut x, mut h) = (x, step);
+
+    for _ in 0..100 {
+        if h <= tol {
+            break;
+        }
+
+        let n_m = match extremum(f(x - h), f(x), f(x + h)) {
+            Some((n_m, _)) => n_m,
+            None => break,
+        };
+
+        if n_m.abs() > 1.0 {
+            x += n_m.signum() * h;
+        } else {
+            x += n_m * h;
+            h /= 4.0;
+        }
+    }
+
+    x
+}
+
+// Refines a zero of a function bracketed by `x1` and `x2`, at which
+// it has the values `y1` and `y2` of opposite signs, until the bracket
+// is shorter than `tol`. Each pass interpolates the zero from the
+// values at the ends and the middle of the bracket, and keeps the
+// shortest part of it across which the function changes sign, which
+// is at most half of it.
+pub(crate) fn refine_zero(
+    f: &dyn Fn(f64) -> f64,
+    x1: f64,
+    x2: f64,
+    y1: f64,
+    y2: f64,
+    tol: f64,
+) -> f64 {
+    let (mut x1, mut x2, mut y1, mut y2) = (x1, x2, y1, y2);
+
+    while (x2 - x1).abs() > tol {
+        let x_mid = (x1 + x2) / 2.0;
+        let y_mid = f(x_mid);
+
+        let mut points = vec![(x1, y1), (x_mid, y_mid), (x2, y2)];
+        if let Some(n_0) = zero(y1, y_mid, y2) {
+            let x_0 = x_mid + n_0 * (x2 - x1) / 2.0;
+            if x_0 != x1 && x_0 != x_mid && x_0 != x2 {
+                points.push((x_0, f(x_0)));
+            }
+        }
+        points.sort_by(|p1, p2| {
+            ((p1.0 - x1) / (x2 - x1))
+                .partial_cmp(&((p2.0 - x1) / (x2 - x1)))
+                .unwrap()
+        });
+
+        let (p1, p2) = points
+            .windows(2)
+            .map(|w| (w[0], w[1]))
+            .filter(|&(p1, p2)| p1.1 * p2.1 <= 0.0)
+            .min_by(|&(p1, p2), &(q1, q2)| {
+                (p2.0 - p1.0)
+                    .abs()
+                    .partial_cmp(&(q2.0 - q1.0).abs())
+                    .unwrap()
+            })
+            .unwrap();
+
+        x1 = p1.0;
+        y1 = p1.1;
+        x2 = p2.0;
+        y2 = p2.1;
+    }
+
+    (x1 + x2) / 2.0
+}
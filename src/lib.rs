@@ -23,6 +23,9 @@ THE SOFTWARE.
 #![allow(non_snake_case)]
 #![allow(non_camel_case_types)]
 
+#[cfg(feature = "serde")]
+extern crate serde;
+
 #[macro_use]
 pub mod util;
 
@@ -34,7 +37,9 @@ pub mod asteroid;
 pub mod atmos;
 pub mod binary_star;
 pub mod consts;
+pub mod eclipse;
 pub mod ecliptic;
+pub mod ephem;
 pub mod interpol;
 pub mod lunar;
 pub mod misc;
@@ -44,6 +49,7 @@ pub mod parallax;
 pub mod planet;
 pub mod pluto;
 pub mod precess;
+pub mod sidereal;
 pub mod star;
 pub mod sun;
 pub mod time;
@@ -24,6 +24,13 @@ THE SOFTWARE.
 
 use angle;
 use coords;
+use ecliptic;
+use interpol;
+use nutation;
+use parallax;
+use sidereal;
+use planet;
+use sun;
 use time;
 
 /**
@@ -60,6 +67,23 @@ pub fn semidiameter(earth_moon_dist: f64) -> f64 {
     0.272481 * eq_hz_parllx(earth_moon_dist).sin()
 }
 
+/**
+Computes the Moon's geocentric apparent diameter on a given day
+
+# Returns
+
+* `diameter`: Geocentric apparent diameter of the Moon *| in radians*
+
+# Arguments
+
+* `JD`: Julian (Ephemeris) day
+**/
+pub fn apparent_diameter(JD: f64) -> f64 {
+    let (_, earth_moon_dist) = geocent_ecl_pos(JD);
+
+    2.0 * semidiameter(earth_moon_dist)
+}
+
 /**
 Computes the inclination of the mean lunar equator with the
 ecliptic
@@ -285,12 +309,64 @@ latitude
                          nutation
 **/
 pub fn total_libr(JD: f64, mn_ecl_long_moon: f64, apprnt_ecl_lat_moon: f64) -> (f64, f64) {
-    let (opt_long, opt_lat) = optical_libr(mn_ecl_long_moon, apprnt_ecl_lat_moon, JD);
-    let (phys_long, phys_lat) = physical_libr(mn_ecl_long_moon, apprnt_ecl_lat_moon, JD, opt_lat);
+    let (opt_long, opt_lat) = optical_libr(JD, mn_ecl_long_moon, apprnt_ecl_lat_moon);
+    let (phys_long, phys_lat) = physical_libr(JD, mn_ecl_long_moon, apprnt_ecl_lat_moon, opt_lat);
 
     (opt_long + phys_long, opt_lat + phys_lat)
 }
 
+/**
+Computes the fraction of the Moon's surface seen from the Earth over
+a period of time
+
+The total librations in longitude and latitude are sampled over the
+period, and the union of the hemispheres facing the Earth at each
+sample is accumulated on a one degree selenographic grid. Over a full
+cycle of libration, the fraction approaches about 0.59.
+
+# Returns
+
+* `visible_frac`: Fraction of the lunar surface seen over the period
+
+# Arguments
+
+* `JD_start` : Julian (Ephemeris) day at the start of the period
+* `step_days`: Interval between samples *| in days*
+* `count`    : Number of samples
+**/
+pub fn visible_fraction_over_period(JD_start: f64, step_days: f64, count: usize) -> f64 {
+    let sub_earth_points: Vec<(f64, f64)> = (0..count)
+        .map(|i| {
+            let JD = JD_start + (i as f64) * step_days;
+            let (moon_ecl_point, _) = geocent_ecl_pos(JD);
+
+            total_libr(JD, moon_ecl_point.long, moon_ecl_point.lat)
+        })
+        .collect();
+
+    let mut seen_area = 0.0;
+    let mut total_area = 0.0;
+
+    for i in 0..180 {
+        let lat = ((i as f64) - 89.5).to_radians();
+        let area = lat.cos();
+
+        for j in 0..360 {
+            let long = (j as f64).to_radians();
+            total_area += area;
+
+            let seen = sub_earth_points.iter().any(|&(l, b)| {
+                lat.sin() * b.sin() + lat.cos() * b.cos() * (long - l).cos() > 0.0
+            });
+            if seen {
+                seen_area += area;
+            }
+        }
+    }
+
+    seen_area / total_area
+}
+
 /**
 Computes the position angle of the axis of rotation of the Moon
 
@@ -334,6 +410,62 @@ pub fn pos_angl_of_axis_of_rot(
     ((X * X + Y * Y).sqrt() * (apprnt_moon_asc - w).cos() / total_lib_lat.cos()).asin()
 }
 
+/// Holds the geocentric libration of the Moon
+#[derive(Debug)]
+pub struct Libration {
+    /// Total libration in longitude, in the interval (-PI, PI]
+    /// *| in radians*. Positive when the limb near Mare Crisium is
+    /// turned towards the Earth.
+    pub l: f64,
+    /// Total libration in latitude *| in radians*. Positive when the
+    /// Moon's northern polar region is turned towards the Earth.
+    pub b: f64,
+    /// Position angle of the Moon's axis of rotation *| in radians*
+    pub pa_axis: f64,
+}
+
+/**
+Computes the geocentric libration of the Moon on a given day
+
+The optical and physical librations, and the position angle of the
+axis, are found as in [total_libr()](./fn.total_libr.html) and
+[pos_angl_of_axis_of_rot()](./fn.pos_angl_of_axis_of_rot.html), with
+the Moon's position, nutation and the obliquity of the ecliptic
+computed internally. Mare Orientale, on the limb opposite to Mare
+Crisium, is best seen when `l` is most negative.
+
+# Returns
+
+* `libration`: Libration of the Moon. *All angles are in radians*
+
+# Arguments
+
+* `JD`: Julian (Ephemeris) day
+**/
+pub fn libration(JD: f64) -> Libration {
+    let (moon_ecl_point, _) = geocent_ecl_pos(JD);
+    let (l, b) = total_libr(JD, moon_ecl_point.long, moon_ecl_point.lat);
+
+    let (nut_in_long, nut_in_oblq) = nutation::nutation(JD);
+    let true_oblq = ecliptic::mn_oblq_laskar(JD) + nut_in_oblq;
+    let (moon_eq_point, _) = apprnt_eq_pos(JD);
+
+    let pa_axis = pos_angl_of_axis_of_rot(
+        JD,
+        mn_ascend_node(time::julian_cent(JD)),
+        b,
+        nut_in_long,
+        true_oblq,
+        moon_eq_point.asc,
+    );
+
+    Libration {
+        l: angle::limit_to_pm_PI(l),
+        b,
+        pa_axis,
+    }
+}
+
 /**
 Computes the topocentric librations of the Moon
 
@@ -601,6 +733,498 @@ pub fn geocent_ecl_pos(JD: f64) -> (coords::EclPoint, f64) {
     (ecl_point, rad_vec)
 }
 
+/**
+Computes the distance between an observer and the Moon
+
+# Returns
+
+* `topocent_dist`: Observer-Moon distance *| in kilometers*
+
+This is shorter than the geocentric distance by up to about one
+radius of the Earth, when the Moon is at the observer's zenith.
+
+# Arguments
+
+* `JD`            : Julian (Ephemeris) day. It's also used as the
+                    Julian day for the sidereal time, so the effect
+                    of ΔT on the observer's position is neglected.
+* `geograph_point`: Geographic point of the observer *| in radians*
+* `observer_ht`   : Height of the observer above sea level *| in meters*
+**/
+pub fn topocentric_distance(
+    JD: f64,
+    geograph_point: &coords::GeographPoint,
+    observer_ht: f64,
+) -> f64 {
+    topocentric_eq(JD, geograph_point, observer_ht).1
+}
+
+/**
+Computes the Moon's apparent topocentric equatorial position, and
+its distance from the observer
+
+The position is shifted from the geocentric one by the lunar
+parallax, by up to about one degree when the Moon is at the
+observer's horizon.
+
+# Returns
+
+`(topocent_eq_point, topocent_dist)`
+
+* `topocent_eq_point`: Apparent topocentric equatorial point of the
+  Moon, referred to the true equinox of the date *| in radians*
+* `topocent_dist`    : Observer-Moon distance *| in kilometers*
+
+# Arguments
+
+* `JD`            : Julian (Ephemeris) day
+* `geograph_point`: Geographic point of the observer *| in radians*
+* `observer_ht`   : Height of the observer above sea level *| in meters*
+
+`JD` is also used as the Julian day for the sidereal time, so the
+effect of ΔT on the observer's position is neglected.
+**/
+pub fn topocentric_eq(
+    JD: f64,
+    geograph_point: &coords::GeographPoint,
+    observer_ht: f64,
+) -> (coords::EqPoint, f64) {
+    let (moon_eq_point, earth_moon_dist) = apprnt_eq_pos(JD);
+    let greenw_sidr = sidereal::apparent_sidereal_greenwich(JD);
+    let loc_sidr = greenw_sidr - geograph_point.long;
+
+    let topocent_eq_point = parallax::topocent_eq_coords(
+        &moon_eq_point,
+        eq_hz_parllx(earth_moon_dist),
+        geograph_point,
+        observer_ht,
+        greenw_sidr,
+    );
+
+    let (rho_sin, rho_cos) = planet::earth::rho_sin_cos_phi(geograph_point.lat, observer_ht);
+    let earth_rad = planet::earth::eq_rad();
+
+    let x = earth_moon_dist * moon_eq_point.dec.cos() * moon_eq_point.asc.cos()
+        - earth_rad * rho_cos * loc_sidr.cos();
+    let y = earth_moon_dist * moon_eq_point.dec.cos() * moon_eq_point.asc.sin()
+        - earth_rad * rho_cos * loc_sidr.sin();
+    let z = earth_moon_dist * moon_eq_point.dec.sin() - earth_rad * rho_sin;
+
+    (topocent_eq_point, (x * x + y * y + z * z).sqrt())
+}
+
+/**
+Computes the Moon's apparent equatorial positions and equatorial
+horizontal parallaxes on three consecutive days
+
+These are the inputs needed by
+[transit::time()](../transit/fn.time.html) for the Moon.
+
+# Returns
+
+`[(eq_point, eq_hz_parllx); 3]`, on `JD_center - 1`, `JD_center`
+and `JD_center + 1`
+
+* `eq_point`    : Apparent geocentric equatorial point of the Moon,
+                  referred to the true equinox of the date
+                  *| in radians*
+* `eq_hz_parllx`: Equatorial horizontal parallax of the Moon
+                  *| in radians*
+
+# Arguments
+
+* `JD_center`: Julian (Ephemeris) day of the middle position
+**/
+pub fn positions_and_parallax(JD_center: f64) -> [(coords::EqPoint, f64); 3] {
+    let position = |JD: f64| {
+        let (moon_eq_point, earth_moon_dist) = apprnt_eq_pos(JD);
+
+        (moon_eq_point, eq_hz_parllx(earth_moon_dist))
+    };
+
+    [
+        position(JD_center - 1.0),
+        position(JD_center),
+        position(JD_center + 1.0),
+    ]
+}
+
+/**
+Computes the Moon's apparent geocentric equatorial position
+
+The position is corrected for nutation.
+
+# Returns
+
+`(moon_eq_point, earth_moon_dist)`
+
+* `moon_eq_point`  : Apparent equatorial point of the Moon *| in radians*
+* `earth_moon_dist`: Earth-Moon distance *| in kilometers*
+
+The equatorial point is referred to the true equinox of the date.
+
+# Arguments
+
+* `JD`: Julian (Ephemeris) day
+**/
+pub fn apprnt_eq_pos(JD: f64) -> (coords::EqPoint, f64) {
+    let (moon_ecl_point, earth_moon_dist) = geocent_ecl_pos(JD);
+    let (nut_in_long, nut_in_oblq) = nutation::nutation(JD);
+    let true_oblq = ecliptic::mn_oblq_laskar(JD) + nut_in_oblq;
+    let long = moon_ecl_point.long + nut_in_long;
+
+    let moon_eq_point = coords::EqPoint {
+        asc: coords::asc_frm_ecl(long, moon_ecl_point.lat, true_oblq),
+        dec: coords::dec_frm_ecl(long, moon_ecl_point.lat, true_oblq),
+    };
+
+    (moon_eq_point, earth_moon_dist)
+}
+
+/**
+Computes the instant of conjunction of the Moon with a planet
+
+The geocentric angular separation between the Moon and the planet is
+scanned over the search window, and it's minimum is then refined. As
+the separation is geocentric, an occultation flagged here is only
+possible somewhere on the Earth, and one may also be seen from some
+places when the separation slightly exceeds the Moon's semidiameter,
+because of the lunar parallax.
+
+# Returns
+
+`(JD, sepr, occultation)`
+
+* `JD`         : Julian (Ephemeris) day of the least separation
+* `sepr`       : Least geocentric separation between the Moon and
+                 the planet *| in radians*
+* `occultation`: `true` if `sepr` is less than the Moon's
+                 semidiameter, ie, if an occultation is possible
+
+# Arguments
+
+* `planet`     : Any variant of [Planet](../planet/enum.Planet.html)
+                 other than `Planet::Earth`
+* `JD_near`    : Julian (Ephemeris) day near the conjunction
+* `window_days`: Half-width of the search window around `JD_near`
+                 *| in days*
+**/
+pub fn conjunction_with_planet(
+    planet: &planet::Planet,
+    JD_near: f64,
+    window_days: f64,
+) -> (f64, f64, bool) {
+    let sepr = |JD: f64| -> f64 {
+        let (moon_ecl_point, _) = geocent_ecl_pos(JD);
+        let (planet_ecl_point, _) = planet::geocent_apprnt_ecl_coords(planet, JD);
+
+        moon_ecl_point.anglr_sepr(&planet_ecl_point)
+    };
+
+    let step = 0.25;
+    let n = ((2.0 * window_days / step).ceil() as usize).max(1);
+    let JD_start = JD_near - window_days;
+
+    let mut JD_min = JD_start;
+    let mut sepr_min = sepr(JD_min);
+    for i in 1..(n + 1) {
+        let JD = JD_start + (i as f64) * step;
+        let s = sepr(JD);
+        if s < sepr_min {
+            JD_min = JD;
+            sepr_min = s;
+        }
+    }
+
+    let JD = interpol::refine_extremum(&sepr, JD_min, step, 1e-5);
+    let sepr_min = sepr(JD);
+    let (_, earth_moon_dist) = geocent_ecl_pos(JD);
+
+    (JD, sepr_min, sepr_min < semidiameter(earth_moon_dist))
+}
+
+/**
+Computes the instant and the distance of the first lunar perigee after
+a given instant
+
+The mean instant of the perigee is found from Meeus's series in `k`
+(chapter 50), and is then corrected with the periodic terms of his
+tables 50.A and 50.B, which also give the Moon's parallax at perigee.
+These corrections can shift the perigee by over half a day from it's
+mean instant, so each corrected instant is compared to `after_JD`.
+
+*Meeus* gives the greatest errors of these series, over 1950 to 2050,
+as 31 minutes in the instant and 0.124" in the parallax (about 12 km
+in the distance).
+
+# Returns
+
+`(JD, dist)`
+
+* `JD`  : Julian (Ephemeris) day of the perigee
+* `dist`: Earth-Moon distance at perigee *| in kilometers*
+
+# Arguments
+
+* `after_JD`: Julian (Ephemeris) day after which to search
+**/
+pub fn perigee(after_JD: f64) -> (f64, f64) {
+    apsis(after_JD, &Apsis::Perigee)
+}
+
+/**
+Computes the instant and the distance of the first lunar apogee after
+a given instant
+
+This is found as in [perigee()](./fn.perigee.html), with Meeus's
+periodic terms for the apogee. *Meeus* gives the greatest errors of
+these, over 1950 to 2050, as 3 minutes in the instant and 0.051" in
+the parallax (about 7 km in the distance).
+
+# Returns
+
+`(JD, dist)`
+
+* `JD`  : Julian (Ephemeris) day of the apogee
+* `dist`: Earth-Moon distance at apogee *| in kilometers*
+
+# Arguments
+
+* `after_JD`: Julian (Ephemeris) day after which to search
+**/
+pub fn apogee(after_JD: f64) -> (f64, f64) {
+    apsis(after_JD, &Apsis::Apogee)
+}
+
+enum Apsis {
+    Perigee,
+    Apogee,
+}
+
+// Finds the first apsis after a given instant, from the mean instants
+// of successive values of k
+fn apsis(after_JD: f64, apsis: &Apsis) -> (f64, f64) {
+    let offset = match *apsis {
+        Apsis::Perigee => 0.0,
+        Apsis::Apogee => 0.5,
+    };
+    let mut k = ((after_JD - 2451534.6698) / 27.55454989 - offset).floor() - 1.0 + offset;
+
+    loop {
+        let (JD, parallax) = apsis_frm_k(k, apsis);
+        if JD > after_JD {
+            return (JD, 6378.14 / parallax.sin());
+        }
+        k += 1.0;
+    }
+}
+
+// Computes the instant of an apsis and the Moon's equatorial horizontal
+// parallax then, in radians, from Meeus's tables 50.A and 50.B. k is an
+// integer for a perigee, and an integer increased by 0.5 for an apogee.
+fn apsis_frm_k(k: f64, apsis: &Apsis) -> (f64, f64) {
+    // Multiples of D, M and F, and the coefficient and its rate of
+    // change with T
+    struct terms(i8, i8, i8, f64, f64);
+
+    let T = k / 1325.55;
+    let JD_mn = 2451534.6698
+        + 27.55454989 * k
+        + T * T * (-0.0006691 + T * (-0.000001098 + T * 0.0000000052));
+
+    let D = (171.9179 + 335.9106046 * k
+        + T * T * (-0.0100383 + T * (-0.00001156 + T * 0.000000055)))
+        .to_radians();
+    let M = (347.3477 + 27.1577721 * k + T * T * (-0.000813 - T * 0.000001)).to_radians();
+    let F = (316.6109 + 364.5287911 * k + T * T * (-0.0125053 - T * 0.0000148)).to_radians();
+
+    let (terms_for_time, parallax_0, terms_for_parallax) = match *apsis {
+        Apsis::Perigee => (
+            vec![
+                terms(2, 0, 0, -1.6769, 0.0),
+                terms(4, 0, 0, 0.4589, 0.0),
+                terms(6, 0, 0, -0.1856, 0.0),
+                terms(8, 0, 0, 0.0883, 0.0),
+                terms(2, -1, 0, -0.0773, 0.00019),
+                terms(0, 1, 0, 0.0502, -0.00013),
+                terms(10, 0, 0, -0.0460, 0.0),
+                terms(4, -1, 0, 0.0422, -0.00011),
+                terms(6, -1, 0, -0.0256, 0.0),
+                terms(12, 0, 0, 0.0253, 0.0),
+                terms(1, 0, 0, 0.0237, 0.0),
+                terms(8, -1, 0, 0.0162, 0.0),
+                terms(14, 0, 0, -0.0145, 0.0),
+                terms(0, 0, 2, 0.0129, 0.0),
+                terms(3, 0, 0, -0.0112, 0.0),
+                terms(10, -1, 0, -0.0104, 0.0),
+                terms(16, 0, 0, 0.0086, 0.0),
+                terms(12, -1, 0, 0.0069, 0.0),
+                terms(5, 0, 0, 0.0066, 0.0),
+                terms(2, 0, 2, -0.0053, 0.0),
+                terms(18, 0, 0, -0.0052, 0.0),
+                terms(14, -1, 0, -0.0046, 0.0),
+                terms(7, 0, 0, -0.0041, 0.0),
+                terms(2, 1, 0, 0.0040, 0.0),
+                terms(20, 0, 0, 0.0032, 0.0),
+                terms(1, 1, 0, -0.0032, 0.0),
+                terms(16, -1, 0, 0.0031, 0.0),
+                terms(4, 1, 0, -0.0029, 0.0),
+                terms(9, 0, 0, 0.0027, 0.0),
+                terms(4, 0, 2, 0.0027, 0.0),
+                terms(2, -2, 0, -0.0027, 0.0),
+                terms(4, -2, 0, 0.0024, 0.0),
+                terms(6, -2, 0, -0.0021, 0.0),
+                terms(22, 0, 0, -0.0021, 0.0),
+                terms(18, -1, 0, -0.0021, 0.0),
+                terms(6, 1, 0, 0.0019, 0.0),
+                terms(11, 0, 0, -0.0018, 0.0),
+                terms(8, 1, 0, -0.0014, 0.0),
+                terms(4, 0, -2, -0.0014, 0.0),
+                terms(6, 0, 2, -0.0014, 0.0),
+                terms(3, 1, 0, 0.0014, 0.0),
+                terms(5, 1, 0, -0.0014, 0.0),
+                terms(13, 0, 0, 0.0013, 0.0),
+                terms(20, -1, 0, 0.0013, 0.0),
+                terms(3, 2, 0, 0.0011, 0.0),
+                terms(4, -2, 2, -0.0011, 0.0),
+                terms(1, 2, 0, -0.0010, 0.0),
+                terms(22, -1, 0, -0.0009, 0.0),
+                terms(0, 0, 4, -0.0008, 0.0),
+                terms(6, 0, -2, 0.0008, 0.0),
+                terms(2, 1, -2, 0.0008, 0.0),
+                terms(0, 2, 0, 0.0007, 0.0),
+                terms(0, -1, 2, 0.0007, 0.0),
+                terms(2, 0, 4, 0.0007, 0.0),
+                terms(0, -2, 2, -0.0006, 0.0),
+                terms(2, 2, -2, -0.0006, 0.0),
+                terms(24, 0, 0, 0.0006, 0.0),
+                terms(4, 0, -4, 0.0005, 0.0),
+                terms(2, 2, 0, 0.0005, 0.0),
+                terms(1, -1, 0, -0.0004, 0.0),
+            ],
+            3629.215,
+            vec![
+                terms(2, 0, 0, 63.224, 0.0),
+                terms(4, 0, 0, -6.990, 0.0),
+                terms(2, -1, 0, 2.834, -0.0071),
+                terms(6, 0, 0, 1.927, 0.0),
+                terms(1, 0, 0, -1.263, 0.0),
+                terms(8, 0, 0, -0.702, 0.0),
+                terms(0, 1, 0, 0.696, -0.0017),
+                terms(0, 0, 2, -0.690, 0.0),
+                terms(4, -1, 0, -0.629, 0.0016),
+                terms(2, 0, -2, -0.392, 0.0),
+                terms(10, 0, 0, 0.297, 0.0),
+                terms(6, -1, 0, 0.260, 0.0),
+                terms(3, 0, 0, 0.201, 0.0),
+                terms(2, 1, 0, -0.161, 0.0),
+                terms(1, 1, 0, 0.157, 0.0),
+                terms(12, 0, 0, -0.138, 0.0),
+                terms(8, -1, 0, -0.127, 0.0),
+                terms(2, 0, 2, 0.104, 0.0),
+                terms(2, -2, 0, 0.104, 0.0),
+                terms(5, 0, 0, -0.079, 0.0),
+                terms(14, 0, 0, 0.068, 0.0),
+                terms(10, -1, 0, 0.067, 0.0),
+                terms(4, 1, 0, 0.054, 0.0),
+                terms(12, -1, 0, -0.038, 0.0),
+                terms(4, -2, 0, -0.038, 0.0),
+                terms(7, 0, 0, 0.037, 0.0),
+                terms(4, 0, 2, -0.037, 0.0),
+                terms(16, 0, 0, -0.035, 0.0),
+                terms(3, 1, 0, -0.030, 0.0),
+                terms(1, -1, 0, 0.029, 0.0),
+                terms(6, 1, 0, -0.025, 0.0),
+                terms(0, 2, 0, 0.023, 0.0),
+                terms(14, -1, 0, 0.023, 0.0),
+                terms(2, 2, 0, -0.023, 0.0),
+                terms(6, -2, 0, 0.022, 0.0),
+                terms(2, -1, -2, -0.021, 0.0),
+                terms(9, 0, 0, -0.020, 0.0),
+                terms(18, 0, 0, 0.019, 0.0),
+                terms(6, 0, 2, 0.017, 0.0),
+                terms(0, -1, 2, 0.014, 0.0),
+                terms(16, -1, 0, -0.014, 0.0),
+                terms(4, 0, -2, 0.013, 0.0),
+                terms(8, 1, 0, 0.012, 0.0),
+                terms(11, 0, 0, 0.011, 0.0),
+                terms(5, 1, 0, 0.010, 0.0),
+                terms(20, 0, 0, -0.010, 0.0),
+            ],
+        ),
+        Apsis::Apogee => (
+            vec![
+                terms(2, 0, 0, 0.4392, 0.0),
+                terms(4, 0, 0, 0.0684, 0.0),
+                terms(0, 1, 0, 0.0456, -0.00011),
+                terms(2, -1, 0, 0.0426, -0.00011),
+                terms(0, 0, 2, 0.0212, 0.0),
+                terms(1, 0, 0, -0.0189, 0.0),
+                terms(6, 0, 0, 0.0144, 0.0),
+                terms(4, -1, 0, 0.0113, 0.0),
+                terms(2, 0, 2, 0.0047, 0.0),
+                terms(1, 1, 0, 0.0036, 0.0),
+                terms(8, 0, 0, 0.0035, 0.0),
+                terms(6, -1, 0, 0.0034, 0.0),
+                terms(2, 0, -2, -0.0034, 0.0),
+                terms(2, -2, 0, 0.0022, 0.0),
+                terms(3, 0, 0, -0.0017, 0.0),
+                terms(4, 0, 2, 0.0013, 0.0),
+                terms(8, -1, 0, 0.0011, 0.0),
+                terms(4, -2, 0, 0.0010, 0.0),
+                terms(10, 0, 0, 0.0009, 0.0),
+                terms(3, 1, 0, 0.0007, 0.0),
+                terms(0, 2, 0, 0.0006, 0.0),
+                terms(2, 1, 0, 0.0005, 0.0),
+                terms(2, 2, 0, 0.0005, 0.0),
+                terms(6, 0, 2, 0.0004, 0.0),
+                terms(6, -2, 0, 0.0004, 0.0),
+                terms(10, -1, 0, 0.0004, 0.0),
+                terms(5, 0, 0, -0.0004, 0.0),
+                terms(4, 0, -2, -0.0004, 0.0),
+                terms(0, 1, 2, 0.0003, 0.0),
+                terms(12, 0, 0, 0.0003, 0.0),
+                terms(2, -1, 2, 0.0003, 0.0),
+                terms(1, -1, 0, -0.0003, 0.0),
+            ],
+            3245.251,
+            vec![
+                terms(2, 0, 0, -9.147, 0.0),
+                terms(1, 0, 0, -0.841, 0.0),
+                terms(0, 0, 2, 0.697, 0.0),
+                terms(0, 1, 0, -0.656, 0.0016),
+                terms(4, 0, 0, 0.355, 0.0),
+                terms(2, -1, 0, 0.159, 0.0),
+                terms(1, 1, 0, 0.127, 0.0),
+                terms(4, -1, 0, 0.065, 0.0),
+                terms(6, 0, 0, 0.052, 0.0),
+                terms(2, 1, 0, 0.043, 0.0),
+                terms(2, 0, 2, 0.031, 0.0),
+                terms(2, 0, -2, -0.023, 0.0),
+                terms(2, -2, 0, 0.022, 0.0),
+                terms(2, 2, 0, 0.019, 0.0),
+                terms(0, 2, 0, -0.016, 0.0),
+                terms(6, -1, 0, 0.014, 0.0),
+                terms(8, 0, 0, 0.010, 0.0),
+            ],
+        ),
+    };
+
+    let arg = |x: &terms| (x.0 as f64) * D + (x.1 as f64) * M + (x.2 as f64) * F;
+
+    let mut JD = JD_mn;
+    for x in terms_for_time.iter() {
+        JD += (x.3 + x.4 * T) * arg(x).sin();
+    }
+
+    let mut parallax = parallax_0;
+    for x in terms_for_parallax.iter() {
+        parallax += (x.3 + x.4 * T) * arg(x).cos();
+    }
+
+    (JD, angle::deg_frm_dms(0, 0, parallax).to_radians())
+}
+
 /**
 Computes the longitude of the mean ascending node of the Moon
 
@@ -746,14 +1370,85 @@ pub fn illum_frac_frm_ecl_coords(
     )
 }
 
+/**
+Computes the illuminated fraction of the lunar disk on a given day
+
+# Returns
+
+* `illum_frac`: Illuminated fraction of the lunar disk
+
+# Arguments
+
+* `JD`: Julian (Ephemeris) day
+**/
+pub fn illuminated_fraction(JD: f64) -> f64 {
+    (1.0 + phase_angle(JD).cos()) / 2.0
+}
+
+/**
+Computes the phase angle of the Moon on a given day
+
+The phase angle is the selenocentric elongation of the Earth from the
+Sun, found from the apparent geocentric positions of the Sun and the
+Moon. It's 0 at full Moon and 180 degrees at new Moon.
+
+# Returns
+
+* `phase_angl`: Phase angle of the Moon *| in radians*
+
+# Arguments
+
+* `JD`: Julian (Ephemeris) day
+**/
+pub fn phase_angle(JD: f64) -> f64 {
+    let (sun_eq_point, earth_sun_dist) = sun::apprnt_eq_pos(JD);
+    let (moon_eq_point, earth_moon_dist) = apprnt_eq_pos(JD);
+
+    phase_angl(
+        sun_eq_point.anglr_sepr(&moon_eq_point),
+        earth_moon_dist,
+        earth_sun_dist * 149597870.7,
+    )
+}
+
+/**
+Computes the position angle of the Moon's bright limb on a given day
+
+The position angle is that of the midpoint of the illuminated limb,
+reckoned eastwards from the North Point of the disk (and not from the
+axis of rotation of the Moon), as in
+[bright_limb()](./fn.bright_limb.html).
+
+# Returns
+
+* `pos_angl_of_bright_limb`: Position angle of the midpoint of the
+                             illuminated limb of the Moon
+                             *| in radians*
+
+# Arguments
+
+* `JD`: Julian (Ephemeris) day
+**/
+pub fn bright_limb_position_angle(JD: f64) -> f64 {
+    let (sun_eq_point, _) = sun::apprnt_eq_pos(JD);
+    let (moon_eq_point, _) = apprnt_eq_pos(JD);
+
+    angle::limit_to_two_PI(bright_limb(sun_eq_point, moon_eq_point))
+}
+
 #[inline]
 fn illuminated_frac(moon_geocent_elong: f64, earth_moon_dist: f64, earth_sun_dist: f64) -> f64 {
-    let i = (earth_sun_dist * moon_geocent_elong.sin())
-        .atan2(earth_moon_dist - earth_sun_dist * moon_geocent_elong.cos());
+    let i = phase_angl(moon_geocent_elong, earth_moon_dist, earth_sun_dist);
 
     (1.0 + i.cos()) / 2.0
 }
 
+#[inline]
+fn phase_angl(moon_geocent_elong: f64, earth_moon_dist: f64, earth_sun_dist: f64) -> f64 {
+    (earth_sun_dist * moon_geocent_elong.sin())
+        .atan2(earth_moon_dist - earth_sun_dist * moon_geocent_elong.cos())
+}
+
 /**
 Computes the times of passage of the Moon through the ascending and
 descending nodes, close to a given date
@@ -782,6 +1477,50 @@ pub fn time_of_passage_through_nodes(date: &time::Date) -> (f64, f64) {
     )
 }
 
+/// Represents a node of the Moon's orbit
+pub enum LunarNode {
+    /// The ascending node
+    Ascending,
+    /// The descending node
+    Descending,
+}
+
+/**
+Computes the Julian day of the first passage of the Moon through a
+node of it's orbit after a given instant
+
+As defined by Meeus (chapter 51), an integer `k` gives a passage
+through the ascending node, and an integer increased by 0.5 one
+through the descending node. Successive values of `k` are tried,
+starting a little before `after_JD`, until the passage falls
+strictly after `after_JD`.
+
+# Returns
+
+* `JD`: Julian (Ephemeris) day of the passage through the node
+
+# Arguments
+
+* `after_JD`: Julian (Ephemeris) day after which to search
+* `node`    : The [LunarNode](./enum.LunarNode.html)
+**/
+pub fn node_passage(after_JD: f64, node: &LunarNode) -> f64 {
+    let offset = match *node {
+        LunarNode::Ascending => 0.0,
+        LunarNode::Descending => 0.5,
+    };
+
+    let mut k = ((after_JD - 2451565.1619) / 27.212220817 - offset).floor() - 1.0 + offset;
+
+    loop {
+        let JD = time_of_passage_through_node(k, k / 1342.23);
+        if JD > after_JD {
+            return JD;
+        }
+        k += 1.0;
+    }
+}
+
 fn time_of_passage_through_node(k: f64, T: f64) -> f64 {
     let D = (183.638 + 331.73735682 * k + T * T * (0.0014852 + T * (0.00000209 - T * 0.00000001)))
         .to_radians();
@@ -848,6 +1587,79 @@ pub fn time_of_phase(date: &time::Date, phase: &Phase) -> f64 {
         &Phase::Last => K + 0.75,
     };
 
+    phase_time_frm_k(k, phase)
+}
+
+/**
+Computes the date and local time of one of the four phases of the
+Moon
+
+The Julian Ephemeris day of [time_of_phase()](./fn.time_of_phase.html)
+is reduced to UT with [time::delta_t()](../time/fn.delta_t.html), and
+shifted from UT by `utc_offset_hours` with
+[Date::to_local()](../time/struct.Date.html#method.to_local).
+
+# Returns
+
+* `date`: Date of the phase closest to `date`, in local time
+
+# Arguments
+
+* `date`            : Date of interest, close to the phase
+* `phase`           : The [Phase](./enum.Phase.html)
+* `utc_offset_hours`: Offset of the local time from UTC *| in hours*.
+                      Eg: 5.5 for Indian Standard Time.
+**/
+pub fn time_of_phase_local<'a>(
+    date: &time::Date,
+    phase: &Phase,
+    utc_offset_hours: f64,
+) -> Result<time::Date, &'a str> {
+    let JDE = time_of_phase(date, phase);
+    let delta_t = time::delta_t(date.year as i32, date.month as u8);
+
+    time::Date::from_julian_day(JDE - delta_t / 86400.0)?.to_local(utc_offset_hours)
+}
+
+/**
+Computes the Julian day of the first phase of the Moon of a given
+kind after a given instant
+
+The mean instant of the phase is computed for successive lunations,
+starting a little before `after_JD`, and the first true instant that
+falls strictly after `after_JD` is returned. So a phase whose mean
+instant is slightly before `after_JD` but whose true instant is after
+it is not skipped, and one that has just occurred is not returned.
+
+# Returns
+
+* `JD`: Julian (Ephemeris) day of the phase
+
+# Arguments
+
+* `after_JD`: Julian (Ephemeris) day after which to search
+* `phase`   : The [Phase](./enum.Phase.html)
+**/
+pub fn next_phase(after_JD: f64, phase: &Phase) -> f64 {
+    let offset = match *phase {
+        Phase::New => 0.0,
+        Phase::First => 0.25,
+        Phase::Full => 0.5,
+        Phase::Last => 0.75,
+    };
+
+    let mut k = ((after_JD - 2451550.09766) / 29.530588861 - offset).floor() - 1.0 + offset;
+
+    loop {
+        let JD = phase_time_frm_k(k, phase);
+        if JD > after_JD {
+            return JD;
+        }
+        k += 1.0;
+    }
+}
+
+fn phase_time_frm_k(k: f64, phase: &Phase) -> f64 {
     let T = k / 1236.85;
 
     let mut JD = 2451550.09766 + k * 29.530588861
@@ -890,8 +1702,8 @@ pub fn time_of_phase(date: &time::Date, phase: &Phase) -> f64 {
     };
 
     if is_quarter {
-        let W = 0.00306 - 0.00038 * E * M.cos() + 0.00026 * M1.cos();
-        -0.00002 * ((M1 - M).cos() - (M1 + M).cos() - (2.0 * F).cos());
+        let W = 0.00306 - 0.00038 * E * M.cos() + 0.00026 * M1.cos()
+            - 0.00002 * ((M1 - M).cos() - (M1 + M).cos() - (2.0 * F).cos());
 
         JD += match phase {
             &Phase::Last => -W,
@@ -22,9 +22,14 @@ THE SOFTWARE.
 
 //! Miscellaneous routines
 
+use coords;
+
 /**
 Computes the parallactic angle of a celestial body
 
+This is [coords::parallactic_angle()](../coords/fn.parallactic_angle.html)
+with it's arguments in a different order.
+
 # Returns
 
 * `parallac_angle`: Parallactic angle of the celestial
@@ -38,9 +43,7 @@ Computes the parallactic angle of a celestial body
 **/
 #[inline]
 pub fn parllc_angl(observer_lat: f64, hour_angle: f64, dec: f64) -> f64 {
-    hour_angle
-        .sin()
-        .atan2(observer_lat.tan() * dec.cos() - hour_angle.cos() * dec.sin())
+    coords::parallactic_angle(hour_angle, dec, observer_lat)
 }
 
 /**
@@ -25,6 +25,7 @@ THE SOFTWARE.
 use angle;
 use time;
 use coords;
+use ecliptic;
 
 /**
 Computes nutation in ecliptic longitude and obliquity
@@ -137,6 +138,147 @@ pub fn nutation(JD: f64) -> (f64, f64) {
     (nut_in_long.to_radians(), nut_in_oblq.to_radians())
 }
 
+/**
+Computes nutation in ecliptic longitude and obliquity, with the IAU
+2000B model
+
+This is the abridged form of the IAU 2000A model, of McCarthy and
+Luzum (2003), with 77 luni-solar terms and constant offsets standing in
+for the planetary terms. It agrees with the full model to about a
+milliarcsecond between 1995 and 2050. [nutation()](./fn.nutation.html),
+from the IAU 1980 theory, differs from it by several milliarcseconds.
+
+The angles are those of the IAU 2000 precession-nutation framework,
+and are meant to be used with a modern precession model, such as that
+of [precess_eq_coords_2006()](../precess/fn.precess_eq_coords_2006.html).
+
+# Returns
+
+`(nut_in_long, nut_in_oblq)`
+
+* `nut_in_long`: Nutation in ecliptic longitude *| in radians*
+* `nut_in_oblq`: Nutation in obliquity of the ecliptic *| in radians*
+
+# Arguments
+
+`JD`: Julian (Ephemeris) day
+**/
+pub fn nutation_2000b(JD: f64) -> (f64, f64) {
+    struct terms(i8, i8, i8, i8, i8, f64, f64, f64, f64, f64, f64);
+    let terms_for_nutation = [
+        terms(0, 0, 0, 0, 1, -172064161.0, -174666.0, 33386.0, 92052331.0, 9086.0, 15377.0),
+        terms(0, 0, 2, -2, 2, -13170906.0, -1675.0, -13696.0, 5730336.0, -3015.0, -4587.0),
+        terms(0, 0, 2, 0, 2, -2276413.0, -234.0, 2796.0, 978459.0, -485.0, 1374.0),
+        terms(0, 0, 0, 0, 2, 2074554.0, 207.0, -698.0, -897492.0, 470.0, -291.0),
+        terms(0, 1, 0, 0, 0, 1475877.0, -3633.0, 11817.0, 73871.0, -184.0, -1924.0),
+        terms(0, 1, 2, -2, 2, -516821.0, 1226.0, -524.0, 224386.0, -677.0, -174.0),
+        terms(1, 0, 0, 0, 0, 711159.0, 73.0, -872.0, -6750.0, 0.0, 358.0),
+        terms(0, 0, 2, 0, 1, -387298.0, -367.0, 380.0, 200728.0, 18.0, 318.0),
+        terms(1, 0, 2, 0, 2, -301461.0, -36.0, 816.0, 129025.0, -63.0, 367.0),
+        terms(0, -1, 2, -2, 2, 215829.0, -494.0, 111.0, -95929.0, 299.0, 132.0),
+        terms(0, 0, 2, -2, 1, 128227.0, 137.0, 181.0, -68982.0, -9.0, 39.0),
+        terms(-1, 0, 2, 0, 2, 123457.0, 11.0, 19.0, -53311.0, 32.0, -4.0),
+        terms(-1, 0, 0, 2, 0, 156994.0, 10.0, -168.0, -1235.0, 0.0, 82.0),
+        terms(1, 0, 0, 0, 1, 63110.0, 63.0, 27.0, -33228.0, 0.0, -9.0),
+        terms(-1, 0, 0, 0, 1, -57976.0, -63.0, -189.0, 31429.0, 0.0, -75.0),
+        terms(-1, 0, 2, 2, 2, -59641.0, -11.0, 149.0, 25543.0, -11.0, 66.0),
+        terms(1, 0, 2, 0, 1, -51613.0, -42.0, 129.0, 26366.0, 0.0, 78.0),
+        terms(-2, 0, 2, 0, 1, 45893.0, 50.0, 31.0, -24236.0, -10.0, 20.0),
+        terms(0, 0, 0, 2, 0, 63384.0, 11.0, -150.0, -1220.0, 0.0, 29.0),
+        terms(0, 0, 2, 2, 2, -38571.0, -1.0, 158.0, 16452.0, -11.0, 68.0),
+        terms(0, -2, 2, -2, 2, 32481.0, 0.0, 0.0, -13870.0, 0.0, 0.0),
+        terms(-2, 0, 0, 2, 0, -47722.0, 0.0, -18.0, 477.0, 0.0, -25.0),
+        terms(2, 0, 2, 0, 2, -31046.0, -1.0, 131.0, 13238.0, -11.0, 59.0),
+        terms(1, 0, 2, -2, 2, 28593.0, 0.0, -1.0, -12338.0, 10.0, -3.0),
+        terms(-1, 0, 2, 0, 1, 20441.0, 21.0, 10.0, -10758.0, 0.0, -3.0),
+        terms(2, 0, 0, 0, 0, 29243.0, 0.0, -74.0, -609.0, 0.0, 13.0),
+        terms(0, 0, 2, 0, 0, 25887.0, 0.0, -66.0, -550.0, 0.0, 11.0),
+        terms(0, 1, 0, 0, 1, -14053.0, -25.0, 79.0, 8551.0, -2.0, -45.0),
+        terms(-1, 0, 0, 2, 1, 15164.0, 10.0, 11.0, -8001.0, 0.0, -1.0),
+        terms(0, 2, 2, -2, 2, -15794.0, 72.0, -16.0, 6850.0, -42.0, -5.0),
+        terms(0, 0, -2, 2, 0, 21783.0, 0.0, 13.0, -167.0, 0.0, 13.0),
+        terms(1, 0, 0, -2, 1, -12873.0, -10.0, -37.0, 6953.0, 0.0, -14.0),
+        terms(0, -1, 0, 0, 1, -12654.0, 11.0, 63.0, 6415.0, 0.0, 26.0),
+        terms(-1, 0, 2, 2, 1, -10204.0, 0.0, 25.0, 5222.0, 0.0, 15.0),
+        terms(0, 2, 0, 0, 0, 16707.0, -85.0, -10.0, 168.0, -1.0, 10.0),
+        terms(1, 0, 2, 2, 2, -7691.0, 0.0, 44.0, 3268.0, 0.0, 19.0),
+        terms(-2, 0, 2, 0, 0, -11024.0, 0.0, -14.0, 104.0, 0.0, 2.0),
+        terms(0, 1, 2, 0, 2, 7566.0, -21.0, -11.0, -3250.0, 0.0, -5.0),
+        terms(0, 0, 2, 2, 1, -6637.0, -11.0, 25.0, 3353.0, 0.0, 14.0),
+        terms(0, -1, 2, 0, 2, -7141.0, 21.0, 8.0, 3070.0, 0.0, 4.0),
+        terms(0, 0, 0, 2, 1, -6302.0, -11.0, 2.0, 3272.0, 0.0, 4.0),
+        terms(1, 0, 2, -2, 1, 5800.0, 10.0, 2.0, -3045.0, 0.0, -1.0),
+        terms(2, 0, 2, -2, 2, 6443.0, 0.0, -7.0, -2768.0, 0.0, -4.0),
+        terms(-2, 0, 0, 2, 1, -5774.0, -11.0, -15.0, 3041.0, 0.0, -5.0),
+        terms(2, 0, 2, 0, 1, -5350.0, 0.0, 21.0, 2695.0, 0.0, 12.0),
+        terms(0, -1, 2, -2, 1, -4752.0, -11.0, -3.0, 2719.0, 0.0, -3.0),
+        terms(0, 0, 0, -2, 1, -4940.0, -11.0, -21.0, 2720.0, 0.0, -9.0),
+        terms(-1, -1, 0, 2, 0, 7350.0, 0.0, -8.0, -51.0, 0.0, 4.0),
+        terms(2, 0, 0, -2, 1, 4065.0, 0.0, 6.0, -2206.0, 0.0, 1.0),
+        terms(1, 0, 0, 2, 0, 6579.0, 0.0, -24.0, -199.0, 0.0, 2.0),
+        terms(0, 1, 2, -2, 1, 3579.0, 0.0, 5.0, -1900.0, 0.0, 1.0),
+        terms(1, -1, 0, 0, 0, 4725.0, 0.0, -6.0, -41.0, 0.0, 3.0),
+        terms(-2, 0, 2, 0, 2, -3075.0, 0.0, -2.0, 1313.0, 0.0, -1.0),
+        terms(3, 0, 2, 0, 2, -2904.0, 0.0, 15.0, 1233.0, 0.0, 7.0),
+        terms(0, -1, 0, 2, 0, 4348.0, 0.0, -10.0, -81.0, 0.0, 2.0),
+        terms(1, -1, 2, 0, 2, -2878.0, 0.0, 8.0, 1232.0, 0.0, 4.0),
+        terms(0, 0, 0, 1, 0, -4230.0, 0.0, 5.0, -20.0, 0.0, -2.0),
+        terms(-1, -1, 2, 2, 2, -2819.0, 0.0, 7.0, 1207.0, 0.0, 3.0),
+        terms(-1, 0, 2, 0, 0, -4056.0, 0.0, 5.0, 40.0, 0.0, -2.0),
+        terms(0, -1, 2, 2, 2, -2647.0, 0.0, 11.0, 1129.0, 0.0, 5.0),
+        terms(-2, 0, 0, 0, 1, -2294.0, 0.0, -10.0, 1266.0, 0.0, -4.0),
+        terms(1, 1, 2, 0, 2, 2481.0, 0.0, -7.0, -1062.0, 0.0, -3.0),
+        terms(2, 0, 0, 0, 1, 2179.0, 0.0, -2.0, -1129.0, 0.0, -2.0),
+        terms(-1, 1, 0, 1, 0, 3276.0, 0.0, 1.0, -9.0, 0.0, 0.0),
+        terms(1, 1, 0, 0, 0, -3389.0, 0.0, 5.0, 35.0, 0.0, -2.0),
+        terms(1, 0, 2, 0, 0, 3339.0, 0.0, -13.0, -107.0, 0.0, 1.0),
+        terms(-1, 0, 2, -2, 1, -1987.0, 0.0, -6.0, 1073.0, 0.0, -2.0),
+        terms(1, 0, 0, 0, 2, -1981.0, 0.0, 0.0, 854.0, 0.0, 0.0),
+        terms(-1, 0, 0, 1, 0, 4026.0, 0.0, -353.0, -553.0, 0.0, -139.0),
+        terms(0, 0, 2, 1, 2, 1660.0, 0.0, -5.0, -710.0, 0.0, -2.0),
+        terms(-1, 0, 2, 4, 2, -1521.0, 0.0, 9.0, 647.0, 0.0, 4.0),
+        terms(-1, 1, 0, 1, 1, 1314.0, 0.0, 0.0, -700.0, 0.0, 0.0),
+        terms(0, -2, 2, -2, 1, -1283.0, 0.0, 0.0, 672.0, 0.0, 0.0),
+        terms(1, 0, 2, 2, 1, -1331.0, 0.0, 8.0, 663.0, 0.0, 4.0),
+        terms(-2, 0, 2, 2, 2, 1383.0, 0.0, -2.0, -594.0, 0.0, -2.0),
+        terms(-1, 0, 0, 0, 2, 1405.0, 0.0, 4.0, -610.0, 0.0, 2.0),
+        terms(1, 1, 2, -2, 2, 1290.0, 0.0, 0.0, -556.0, 0.0, 0.0),
+    ];
+
+    let t = time::julian_cent(JD);
+
+    // Fundamental arguments of Simon et al. (1994), in arcseconds
+    let fund_arg =
+        |a: f64, b: f64| -> f64 { angle::deg_frm_dms(0, 0, (a + b * t) % 1296000.0).to_radians() };
+    let M1 = fund_arg(485868.249036, 1717915923.2178);
+    let M = fund_arg(1287104.79305, 129596581.0481);
+    let F = fund_arg(335779.526232, 1739527262.8478);
+    let D = fund_arg(1072260.70369, 1602961601.2090);
+    let om = fund_arg(450160.398036, -6962890.5431);
+
+    let mut nut_in_long = 0.0;
+    let mut nut_in_oblq = 0.0;
+
+    for x in terms_for_nutation.iter().rev() {
+        let arg = (x.0 as f64) * M1 + (x.1 as f64) * M + (x.2 as f64) * F + (x.3 as f64) * D
+            + (x.4 as f64) * om;
+        let (sin_arg, cos_arg) = arg.sin_cos();
+
+        nut_in_long += (x.5 + x.6 * t) * sin_arg + x.7 * cos_arg;
+        nut_in_oblq += (x.8 + x.9 * t) * cos_arg + x.10 * sin_arg;
+    }
+
+    // The terms are in units of 0.1 microarcseconds, and the constant
+    // offsets in milliarcseconds
+    nut_in_long = nut_in_long * 1e-7 - 0.000135;
+    nut_in_oblq = nut_in_oblq * 1e-7 + 0.000388;
+
+    (
+        angle::deg_frm_dms(0, 0, nut_in_long).to_radians(),
+        angle::deg_frm_dms(0, 0, nut_in_oblq).to_radians(),
+    )
+}
+
 /**
 Computes nutation in equatorial coordinates
 
@@ -173,3 +315,74 @@ pub fn nutation_in_eq_coords(
 
     (nut_asc, nut_dec)
 }
+
+/**
+Computes the nutation matrix for a given day
+
+The matrix rotates equatorial rectangular coordinates referred to the
+mean equator and equinox of the date into coordinates referred to the
+true equator and equinox of the date. It's built from the nutation in
+longitude and obliquity, and the mean and true obliquities of the
+ecliptic. Its transpose does the opposite rotation.
+
+# Returns
+
+* `matrix`: Rotation matrix, as an array of rows
+
+# Arguments
+
+* `JD`: Julian (Ephemeris) day
+**/
+pub fn nutation_matrix(JD: f64) -> [[f64; 3]; 3] {
+    let (nut_in_long, nut_in_oblq) = nutation(JD);
+    let mn_oblq = ecliptic::mn_oblq_laskar(JD);
+    let tru_oblq = mn_oblq + nut_in_oblq;
+
+    let (s_psi, c_psi) = nut_in_long.sin_cos();
+    let (s_mn, c_mn) = mn_oblq.sin_cos();
+    let (s_tru, c_tru) = tru_oblq.sin_cos();
+
+    [
+        [c_psi, -s_psi * c_mn, -s_psi * s_mn],
+        [
+            s_psi * c_tru,
+            c_psi * c_tru * c_mn + s_tru * s_mn,
+            c_psi * c_tru * s_mn - s_tru * c_mn,
+        ],
+        [
+            s_psi * s_tru,
+            c_psi * s_tru * c_mn - c_tru * s_mn,
+            c_psi * s_tru * s_mn + c_tru * c_mn,
+        ],
+    ]
+}
+
+/**
+Corrects an equatorial point for nutation
+
+Unlike [nutation_in_eq_coords()](./fn.nutation_in_eq_coords.html),
+this applies the [nutation matrix](./fn.nutation_matrix.html), and so
+also holds close to the celestial poles.
+
+# Returns
+
+* `eq_point`: True equatorial point of the date *| in radians*
+
+# Arguments
+
+* `eq_point`: Mean equatorial point of the date *| in radians*
+* `JD`      : Julian (Ephemeris) day
+**/
+pub fn apply_nutation(eq_point: &coords::EqPoint, JD: f64) -> coords::EqPoint {
+    let vector = [
+        eq_point.dec.cos() * eq_point.asc.cos(),
+        eq_point.dec.cos() * eq_point.asc.sin(),
+        eq_point.dec.sin(),
+    ];
+    let v = coords::apply_matrix(&nutation_matrix(JD), &vector);
+
+    coords::EqPoint {
+        asc: angle::limit_to_two_PI(v[1].atan2(v[0])),
+        dec: v[2].atan2((v[0] * v[0] + v[1] * v[1]).sqrt()),
+    }
+}
@@ -22,6 +22,7 @@ THE SOFTWARE.
 
 //! Elliptic orbits
 
+use angle;
 use orbit;
 use std::f64::consts::PI;
 
@@ -106,6 +107,42 @@ pub fn ecc_anom(mean_anom: f64, ecc: f64, accuracy: f64) -> f64 {
     E
 }
 
+/**
+Computes the eccentric anomaly of a body in an elliptic orbit, for
+any eccentricity
+
+Unlike [ecc_anom()](./fn.ecc_anom.html), whose iteration converges
+slowly or not at all for eccentricities near unity, Kepler's equation
+is solved by Newton's method, falling back to bisection when that
+converges slowly, to full precision.
+
+# Returns
+
+* `ecc_anom`: Eccentric anomaly of the body *| in radians*, in the
+  range (-π, π]
+
+# Arguments
+
+* `mean_anom`: Mean anomaly of the body *| in radians*
+* `ecc`      : Eccentricity of the orbit *| less than 1*
+**/
+pub fn ecc_anom_robust(mean_anom: f64, ecc: f64) -> f64 {
+    // Small mean anomalies are kept as they are, as the orbits of
+    // eccentricity near unity are sensitive to their last bits
+    let M = if mean_anom.abs() > PI {
+        angle::limit_to_pm_PI(mean_anom)
+    } else {
+        mean_anom
+    };
+
+    orbit::root_of_kepler(
+        &|E: f64| (E - ecc * E.sin() - M, 1.0 - ecc * E.cos()),
+        if ecc < 0.8 { M } else { PI.copysign(M) },
+        -PI,
+        PI,
+    )
+}
+
 /**
 Computes the velocity of a body in an elliptic orbit
 
@@ -26,6 +26,13 @@ pub mod elliptic;
 pub mod parabolic;
 pub mod near_parabolic;
 
+use angle;
+use consts;
+use coords;
+use ecliptic;
+use orbit;
+use sun;
+
 /// Represents an orbital node
 pub enum Node {
     /// Ascending node
@@ -33,3 +40,344 @@ pub enum Node {
     /// Descending node
     Descend,
 }
+
+/// Holds the orbital elements of a body. For a minor body of the
+/// Solar System, these are heliocentric and referred to the ecliptic
+/// and mean equinox of J2000.0.
+#[derive(Debug)]
+pub struct OrbitalElements {
+    /// Semimajor axis if the orbit is elliptic, and perihelion
+    /// distance otherwise *| in AU*
+    pub a_or_q: f64,
+    /// Eccentricity
+    pub e: f64,
+    /// Inclination *| in radians*
+    pub i: f64,
+    /// Longitude of the ascending node *| in radians*
+    pub node: f64,
+    /// Argument of the perihelion *| in radians*
+    pub arg_peri: f64,
+    /// Epoch of the mean anomaly, in Julian (Ephemeris) day. Unused
+    /// if the orbit isn't elliptic.
+    pub epoch: f64,
+    /// Mean anomaly at `epoch` *| in radians* if the orbit is
+    /// elliptic, and time of passage through the perihelion, in
+    /// Julian (Ephemeris) day, otherwise
+    pub mean_anomaly_or_time: f64,
+}
+
+/**
+Computes the true anomaly and radius vector of a body in an elliptic,
+parabolic or hyperbolic orbit at a given time
+
+Kepler's equation is solved according to the eccentricity of the
+orbit. Elliptic and hyperbolic orbits are solved by Newton's method,
+falling back to bisection when that converges slowly, as it may for
+near-parabolic orbits. Orbits whose eccentricity is within `1e-8` of
+unity are treated as parabolic, and solved by Barker's equation.
+
+# Returns
+
+`(true_anom, rad_vec)`
+
+* `true_anom`: True anomaly of the body at time `t` *| in radians*
+* `rad_vec`  : Radius vector of the body at time `t` *| in AU*
+
+# Arguments
+
+* `t`  : Time of interest, in Julian (Ephemeris) day
+* `T`  : Time of passage through the perihelion, in Julian (Ephemeris) day
+* `q`  : Perihelion distance *| in AU*
+* `ecc`: Eccentricity of the orbit
+**/
+pub fn solve_kepler(t: f64, T: f64, q: f64, ecc: f64) -> (f64, f64) {
+    if (ecc - 1.0).abs() < 1e-8 {
+        return orbit::parabolic::true_anom_and_rad_vec(t, T, q);
+    }
+
+    let a = q / (1.0 - ecc).abs();
+    let mn_motion = consts::GAUSS_GRAV / a.powf(1.5);
+    let M = mn_motion * (t - T);
+
+    if ecc < 1.0 {
+        let E = orbit::elliptic::ecc_anom_robust(M, ecc);
+        let v = orbit::elliptic::true_anom(E, ecc);
+
+        (v, a * (1.0 - ecc * E.cos()))
+    } else {
+        let bound = (6.0 * M.abs()).cbrt();
+        let H = root_of_kepler(
+            &|H: f64| (ecc * H.sinh() - H - M, ecc * H.cosh() - 1.0),
+            (2.0 * M.abs() / ecc + 1.8).ln().copysign(M),
+            -bound,
+            bound,
+        );
+        let v = 2.0 * (((ecc + 1.0) / (ecc - 1.0)).sqrt() * (H / 2.0).tanh()).atan();
+
+        (v, a * (ecc * H.cosh() - 1.0))
+    }
+}
+
+// Computes the root of a form of Kepler's equation, whose value and
+// derivative are given by f, by Newton's method starting from x0, and
+// by bisection of the bracket [lo, hi] if that fails to converge
+fn root_of_kepler(f: &dyn Fn(f64) -> (f64, f64), x0: f64, lo: f64, hi: f64) -> f64 {
+    let mut x = x0;
+    for _ in 0..50 {
+        let (value, derivative) = f(x);
+        let step = value / derivative;
+        x -= step;
+        if !x.is_finite() || x < lo || x > hi {
+            break;
+        }
+        if step.abs() <= 1e-14 * x.abs().max(1.0) {
+            return x;
+        }
+    }
+
+    let mut lo = lo;
+    let mut hi = hi;
+    while hi - lo > 1e-15 * hi.abs().max(lo.abs()).max(1.0) {
+        let mid = (lo + hi) / 2.0;
+        if mid <= lo || mid >= hi {
+            break;
+        }
+        if f(mid).0 < 0.0 {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+
+    (lo + hi) / 2.0
+}
+
+/**
+Computes the geocentric equatorial coordinates of a minor body from
+its orbital elements
+
+The heliocentric rectangular coordinates of the body are computed
+with [solve_kepler()](./fn.solve_kepler.html), and added to the
+Sun's geocentric rectangular coordinates. The position is corrected
+for light-time, but not for aberration or nutation, and so is
+referred to the mean equinox of J2000.0, as in a comet or asteroid
+ephemeris.
+
+# Returns
+
+* `eq_point`: Equatorial point of the body *| in radians*, referred
+              to the mean equinox of J2000.0
+
+# Arguments
+
+* `elements`: Orbital elements of the body, referred to the ecliptic
+              and mean equinox of J2000.0
+* `JD`      : Julian (Ephemeris) day
+**/
+pub fn ephemeris(elements: &OrbitalElements, JD: f64) -> coords::EqPoint {
+    let (sun_x, sun_y, sun_z) = sun::geocent_rect_coords_J2000(JD);
+
+    let mut light_time: f64 = 0.0;
+    let mut prev_light_time = -1.0;
+    let mut rect = [0.0; 3];
+    let mut dist = 0.0;
+    while (light_time - prev_light_time).abs() > 1e-9 {
+        let (x, y, z) = heliocent_rect_coords(elements, JD - light_time);
+        rect = [sun_x + x, sun_y + y, sun_z + z];
+        dist = (rect[0] * rect[0] + rect[1] * rect[1] + rect[2] * rect[2]).sqrt();
+
+        prev_light_time = light_time;
+        light_time = 0.0057755183 * dist;
+    }
+
+    coords::EqPoint {
+        asc: angle::limit_to_two_PI(rect[1].atan2(rect[0])),
+        dec: (rect[2] / dist).asin(),
+    }
+}
+
+// Computes the heliocentric equatorial rectangular coordinates of a
+// body, referred to the mean equinox of J2000.0
+fn heliocent_rect_coords(elements: &OrbitalElements, JD: f64) -> (f64, f64, f64) {
+    let mu = consts::GAUSS_GRAV * consts::GAUSS_GRAV;
+    let (ecl_rect, _) = elements_to_state(elements, mu, JD);
+    let eq_rect = coords::apply_matrix(
+        &coords::obliquity_rotation_matrix(ecliptic::mn_oblq_laskar(2451545.0)),
+        &ecl_rect,
+    );
+
+    (eq_rect[0], eq_rect[1], eq_rect[2])
+}
+
+/**
+Computes the state vector of a body from its orbital elements
+
+The true anomaly is found with [solve_kepler()](./fn.solve_kepler.html),
+its time scale adjusted for the gravitational parameter `mu`, and the
+position and velocity in the plane of the orbit are then rotated to
+the reference frame of the elements.
+
+# Returns
+
+`(pos, vel)`
+
+* `pos`: Position of the body, in the reference frame of `elements`
+         *| in AU*
+* `vel`: Velocity of the body, in the reference frame of `elements`
+         *| in AU per day*
+
+# Arguments
+
+* `elements`: Orbital elements of the body
+* `mu`      : Gravitational parameter of the central body
+              *| in AU<sup>3</sup> per day<sup>2</sup>*. For the
+              Sun, this is the square of `consts::GAUSS_GRAV`.
+* `JD`      : Julian (Ephemeris) day
+**/
+pub fn elements_to_state(
+    elements: &OrbitalElements,
+    mu: f64,
+    JD: f64,
+) -> ([f64; 3], [f64; 3]) {
+    let e = elements.e;
+    let (q, T) = if e < 1.0 {
+        let a = elements.a_or_q;
+        let mn_motion = (mu / (a * a * a)).sqrt();
+
+        (a * (1.0 - e), elements.epoch - elements.mean_anomaly_or_time / mn_motion)
+    } else {
+        (elements.a_or_q, elements.mean_anomaly_or_time)
+    };
+
+    let scaled_JD = T + (JD - T) * mu.sqrt() / consts::GAUSS_GRAV;
+    let (v, r) = solve_kepler(scaled_JD, T, q, e);
+
+    let p = q * (1.0 + e);
+    let vel_scale = (mu / p).sqrt();
+    let perifocal_pos = [r * v.cos(), r * v.sin()];
+    let perifocal_vel = [-vel_scale * v.sin(), vel_scale * (e + v.cos())];
+
+    let (sin_node, cos_node) = elements.node.sin_cos();
+    let (sin_i, cos_i) = elements.i.sin_cos();
+    let (sin_w, cos_w) = elements.arg_peri.sin_cos();
+    let P = [
+        cos_node * cos_w - sin_node * sin_w * cos_i,
+        sin_node * cos_w + cos_node * sin_w * cos_i,
+        sin_w * sin_i,
+    ];
+    let Q = [
+        -cos_node * sin_w - sin_node * cos_w * cos_i,
+        -sin_node * sin_w + cos_node * cos_w * cos_i,
+        cos_w * sin_i,
+    ];
+
+    let mut pos = [0.0; 3];
+    let mut vel = [0.0; 3];
+    for k in 0..3 {
+        pos[k] = perifocal_pos[0] * P[k] + perifocal_pos[1] * Q[k];
+        vel[k] = perifocal_vel[0] * P[k] + perifocal_vel[1] * Q[k];
+    }
+
+    (pos, vel)
+}
+
+/**
+Computes the orbital elements of a body from its state vector
+
+The elements are found from the angular momentum and eccentricity
+vectors. Where they are ill-defined, these conventions are followed:
+
+* For an orbit in the reference plane (`i` of 0 or π), `node` is 0,
+  and `arg_peri` is measured from the x-axis
+* For a circular orbit (`e` below `1e-10`), `arg_peri` is 0, and the
+  perihelion is taken to be at the ascending node
+
+# Returns
+
+* `elements`: Orbital elements of the body, in the reference frame of
+              the state vector. For an elliptic orbit, `epoch` is
+              `JD`.
+
+# Arguments
+
+* `pos`: Position of the body *| in AU*
+* `vel`: Velocity of the body *| in AU per day*
+* `mu` : Gravitational parameter of the central body
+         *| in AU<sup>3</sup> per day<sup>2</sup>*
+* `JD` : Julian (Ephemeris) day
+**/
+pub fn state_to_elements(pos: &[f64; 3], vel: &[f64; 3], mu: f64, JD: f64) -> OrbitalElements {
+    let dot = |a: &[f64; 3], b: &[f64; 3]| a[0] * b[0] + a[1] * b[1] + a[2] * b[2];
+    let cross = |a: &[f64; 3], b: &[f64; 3]| {
+        [
+            a[1] * b[2] - a[2] * b[1],
+            a[2] * b[0] - a[0] * b[2],
+            a[0] * b[1] - a[1] * b[0],
+        ]
+    };
+
+    let r = dot(pos, pos).sqrt();
+    let v_sqr = dot(vel, vel);
+    let h = cross(pos, vel);
+    let h_mag = dot(&h, &h).sqrt();
+
+    let e_coeff = v_sqr - mu / r;
+    let r_dot_v = dot(pos, vel);
+    let mut e_vec = [0.0; 3];
+    for k in 0..3 {
+        e_vec[k] = (e_coeff * pos[k] - r_dot_v * vel[k]) / mu;
+    }
+    let mut e = dot(&e_vec, &e_vec).sqrt();
+
+    let i = (h[2] / h_mag).acos();
+    let node_mag = h[0].hypot(h[1]);
+    let node = if node_mag > 1e-12 * h_mag {
+        angle::limit_to_two_PI(h[0].atan2(-h[1]))
+    } else {
+        0.0
+    };
+
+    let N = [node.cos(), node.sin(), 0.0];
+    let W = [h[0] / h_mag, h[1] / h_mag, h[2] / h_mag];
+    let M = cross(&W, &N);
+
+    let u = dot(pos, &M).atan2(dot(pos, &N));
+    let arg_peri = if e > 1e-10 {
+        angle::limit_to_two_PI(dot(&e_vec, &M).atan2(dot(&e_vec, &N)))
+    } else {
+        e = 0.0;
+        0.0
+    };
+    let v = angle::limit_to_two_PI(u - arg_peri);
+
+    let p = h_mag * h_mag / mu;
+    let q = p / (1.0 + e);
+
+    let (a_or_q, mean_anomaly_or_time) = if (e - 1.0).abs() < 1e-8 {
+        let D = (v / 2.0).tan();
+        let time_frm_perih = 0.5 * (p * p * p / mu).sqrt() * (D + D * D * D / 3.0);
+
+        (q, JD - time_frm_perih)
+    } else if e < 1.0 {
+        let a = q / (1.0 - e);
+        let E = 2.0 * (((1.0 - e) / (1.0 + e)).sqrt() * (v / 2.0).tan()).atan();
+
+        (a, angle::limit_to_two_PI(E - e * E.sin()))
+    } else {
+        let a = q / (e - 1.0);
+        let H = 2.0 * (((e - 1.0) / (e + 1.0)).sqrt() * (v / 2.0).tan()).atanh();
+        let mn_motion = (mu / (a * a * a)).sqrt();
+
+        (q, JD - (e * H.sinh() - H) / mn_motion)
+    };
+
+    OrbitalElements {
+        a_or_q,
+        e,
+        i,
+        node,
+        arg_peri,
+        epoch: JD,
+        mean_anomaly_or_time,
+    }
+}
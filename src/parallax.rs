@@ -69,18 +69,61 @@ pub fn topocent_eq_coords(
     observer_ht: f64,
     greenw_sidr: f64,
 ) -> coords::EqPoint {
-    let (rho_sin, rho_cos) = planet::earth::rho_sin_cos_phi(geograph_point.lat, observer_ht);
-
     let geocent_hr_angl =
         coords::hr_angl_frm_observer_long(greenw_sidr, geograph_point.long, eq_point.asc);
 
+    topocent_eq_coords_frm_hr_angl(
+        eq_point,
+        eq_hz_parllx,
+        geograph_point,
+        observer_ht,
+        geocent_hr_angl,
+    )
+}
+
+/**
+Computes the topocentric equatorial coordinates of a celestial body
+from its local hour angle
+
+This is the same as
+[topocent_eq_coords()](./fn.topocent_eq_coords.html), but takes the
+geocentric hour angle of the body instead of the sidereal time. The
+observer's geocentric position, `ρ sin φ'` and `ρ cos φ'`, accounts
+for both the latitude and the height above sea level.
+
+# Returns
+
+* `topocent_eq_point`: Topocentric equatorial point of the
+                      celestial body *| in radians*
+
+# Arguments
+
+* `eq_point`      : Geocentric equatorial point of the celestial body
+                    *| in radians*
+* `eq_hz_parllx`  : Equatorial horizontal parallax of the celestial
+                    body *| in radians*
+* `geograph_point`: Geographic point of the observer *| in radians*
+* `observer_ht`   : Height of the observer above sea level
+                    *| in meters*
+* `hr_angl`       : Geocentric local hour angle of the celestial body
+                    *| in radians*
+**/
+pub fn topocent_eq_coords_frm_hr_angl(
+    eq_point: &coords::EqPoint,
+    eq_hz_parllx: f64,
+    geograph_point: &coords::GeographPoint,
+    observer_ht: f64,
+    hr_angl: f64,
+) -> coords::EqPoint {
+    let (rho_sin, rho_cos) = planet::earth::rho_sin_cos_phi(geograph_point.lat, observer_ht);
+
     let eq_hz_parllx_sin = eq_hz_parllx.sin();
 
-    let del_asc = (-rho_cos * eq_hz_parllx_sin * geocent_hr_angl.sin())
-        .atan2(eq_point.dec.cos() - rho_cos * eq_hz_parllx_sin * geocent_hr_angl.cos());
+    let del_asc = (-rho_cos * eq_hz_parllx_sin * hr_angl.sin())
+        .atan2(eq_point.dec.cos() - rho_cos * eq_hz_parllx_sin * hr_angl.cos());
 
     let dec_1 = ((eq_point.dec.sin() - rho_sin * eq_hz_parllx_sin) * del_asc.cos())
-        .atan2(eq_point.dec.cos() - rho_cos * eq_hz_parllx_sin * geocent_hr_angl.cos());
+        .atan2(eq_point.dec.cos() - rho_cos * eq_hz_parllx_sin * hr_angl.cos());
 
     coords::EqPoint {
         asc: eq_point.asc + del_asc,
@@ -25,6 +25,7 @@ THE SOFTWARE.
 pub mod moon;
 
 use angle;
+use ecliptic;
 use nutation;
 use planet;
 use coords;
@@ -203,3 +204,56 @@ pub fn ephemeris(JD: f64, mn_oblq: f64, nut_in_long: f64, nut_in_oblq: f64) -> E
         w2: w2,
     }
 }
+
+/**
+Computes the longitudes of Jupiter's central meridian in Rotational
+Systems I and II
+
+This is a convenience over [ephemeris()](./fn.ephemeris.html), with
+nutation and the obliquity of the ecliptic computed internally. The
+light-time from Jupiter is accounted for, so that the longitudes are
+those of the meridian seen at the center of the disk at `JD`. A
+feature at System II longitude `L`, such as the Great Red Spot,
+transits the central meridian when `cm_system_II` equals `L`.
+
+# Returns
+
+`(cm_system_I, cm_system_II)`
+
+* `cm_system_I` : Longitude of the central meridian in System I
+                  *| in radians*
+* `cm_system_II`: Longitude of the central meridian in System II
+                  *| in radians*
+
+# Arguments
+
+* `JD`: Julian (Ephemeris) day
+**/
+pub fn central_meridian(JD: f64) -> (f64, f64) {
+    let ephemeris = ephemeris_of_date(JD);
+
+    (ephemeris.w1, ephemeris.w2)
+}
+
+/**
+Computes the geocentric position angle of Jupiter's axis of rotation
+
+# Returns
+
+* `pos_angl`: Position angle of Jupiter's northern rotation pole,
+              measured eastwards from the North *| in radians*
+
+# Arguments
+
+* `JD`: Julian (Ephemeris) day
+**/
+pub fn axis_position_angle(JD: f64) -> f64 {
+    angle::limit_to_two_PI(ephemeris_of_date(JD).P)
+}
+
+#[inline]
+fn ephemeris_of_date(JD: f64) -> Ephemeris {
+    let (nut_in_long, nut_in_oblq) = nutation::nutation(JD);
+
+    ephemeris(JD, ecliptic::mn_oblq_laskar(JD), nut_in_long, nut_in_oblq)
+}
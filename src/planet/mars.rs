@@ -26,6 +26,8 @@ use angle;
 use planet;
 use time;
 use coords;
+use ecliptic;
+use nutation;
 
 /**
 Returns the equatorial coordinates of Mars's north pole for the epoch
@@ -208,3 +210,54 @@ pub fn ephemeris(
         d: d,
     }
 }
+
+/// Holds Mars's physical ephemeris on a given day
+#[derive(Debug)]
+pub struct MarsEphemeris {
+    /// Longitude of the central meridian, as seen from the Earth
+    pub central_meridian: f64,
+    /// Geocentric position angle of Mars's northern rotation pole
+    pub pole_pa: f64,
+    /// Mars-centric declination of the Earth, ie, the tilt of the
+    /// rotation axis towards the Earth
+    pub tilt: f64,
+    /// Angular amount of the greatest defect of illumination
+    pub defect_of_illum: f64,
+}
+
+/**
+Computes Mars's physical ephemeris on a given day
+
+This is a convenience over [ephemeris()](./fn.ephemeris.html), with
+the ecliptic coordinates of the north pole, nutation and the obliquity
+of the ecliptic computed internally. As there, the positions of Mars
+and the central meridian are corrected for light-time by evaluating
+them at `JD` less the Mars-Earth travel time.
+
+# Returns
+
+* `ephemeris`: Mars's physical ephemeris. *All angles are in radians*
+
+# Arguments
+
+* `JD`: Julian (Ephemeris) day
+**/
+pub fn physical_ephemeris(JD: f64) -> MarsEphemeris {
+    let (nut_in_long, nut_in_oblq) = nutation::nutation(JD);
+    let mn_oblq = ecliptic::mn_oblq_laskar(JD);
+
+    let ephemeris = ephemeris(
+        JD,
+        &north_pol_ecl_coords(time::julian_cent(JD)),
+        mn_oblq,
+        nut_in_long,
+        nut_in_oblq,
+    );
+
+    MarsEphemeris {
+        central_meridian: ephemeris.w,
+        pole_pa: ephemeris.P,
+        tilt: ephemeris.De,
+        defect_of_illum: ephemeris.q,
+    }
+}
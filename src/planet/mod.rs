@@ -29,8 +29,18 @@ pub mod mars;
 pub mod jupiter;
 pub mod saturn;
 
+use aberr;
 use angle;
 use coords;
+use ecliptic;
+use ephem;
+use interpol;
+use nutation;
+use parallax;
+use precess;
+use std;
+use sidereal;
+use sun;
 use time;
 
 /// Represents a planet
@@ -166,6 +176,46 @@ pub fn semidiameter<'a>(planet: &Planet, planet_earth_dist: f64) -> Result<f64,
     Ok(s / planet_earth_dist)
 }
 
+/**
+Computes a planet's apparent equatorial and polar diameters on a
+given day
+
+The Planet-Earth distance is corrected for light-time. The polar
+diameters of Jupiter and Saturn account for their oblateness, and for
+Saturn also for the tilt of it's axis towards the Earth (the ring
+tilt `B` of [saturn::ring_elements()](./saturn/fn.ring_elements.html)).
+The other planets are taken to be spherical.
+
+# Returns
+
+`(eq_diameter, pol_diameter)`
+
+* `eq_diameter` : Apparent equatorial diameter *| in radians*
+* `pol_diameter`: Apparent polar diameter *| in radians*
+
+# Arguments
+
+* `planet`: Any variant of [Planet](./enum.Planet.html) other than
+            `Planet::Earth`
+* `JD`    : Julian (Ephemeris) day
+**/
+pub fn apparent_diameter<'a>(planet: &Planet, JD: f64) -> Result<(f64, f64), &'a str> {
+    if let Planet::Earth = *planet {
+        return Err("Planet::Earth was passed to the function planet::apparent_diameter()");
+    }
+
+    let (_, delta, _) = sun_planet_earth_dists(planet, JD);
+    let eq_semidiameter = semidiameter(planet, delta)?;
+
+    let pol_semidiameter = match *planet {
+        Planet::Jupiter => jupiter::pol_semidiameter(delta),
+        Planet::Saturn => saturn::pol_semidiameter(delta, saturn::ring_elements(JD).B),
+        _ => eq_semidiameter,
+    };
+
+    Ok((2.0 * eq_semidiameter, 2.0 * pol_semidiameter))
+}
+
 /**
 Computes a planet's orbital elements, referred to the mean equinox of
 the date
@@ -206,7 +256,7 @@ pub fn orb_elements(planet: &Planet, JD: f64) -> (f64, f64, f64, f64, f64, f64,
     match planet {
         &Planet::Mercury => {
             L = 252.250906 + 149474.0722491 * T + 0.0003035 * TT + 0.000000018 * TTT;
-            a = 0.038709831;
+            a = 0.387098310;
             e = 0.20563175 + 0.000020407 * T - 0.0000000283 * TT + 0.00000000018 * TTT;
             i = 7.004986 + 0.0018215 * T - 0.0000181 * TT + 0.000000056 * TTT;
             omega = 48.330893 + 1.1861883 * T + 0.00017542 * TT + 0.000000215 * TTT;
@@ -289,6 +339,276 @@ pub fn orb_elements(planet: &Planet, JD: f64) -> (f64, f64, f64, f64, f64, f64,
     )
 }
 
+/**
+Computes a planet's sidereal period of revolution
+
+The period is found from the mean motion `n = k / a^1.5`, where `k`
+is the Gaussian gravitational constant and `a` the semimajor axis of
+the mean orbit at J2000.0. The mass of the planet is neglected.
+
+# Returns
+
+* `period`: Sidereal period of revolution *| in days*
+
+# Arguments
+
+* `planet`: Any variant of [Planet](./enum.Planet.html)
+**/
+pub fn sidereal_period(planet: &Planet) -> f64 {
+    let (_, a, _, _, _, _, _, _) = orb_elements(planet, 2451545.0);
+    let n = 0.01720209895 / a.powf(1.5);
+
+    angle::TWO_PI / n
+}
+
+/**
+Computes a planet's tropical period of revolution
+
+The period is found from the rate of change of the planet's mean
+longitude at J2000.0, referred to the mean equinox of the date.
+
+# Returns
+
+* `period`: Tropical period of revolution *| in days*
+
+# Arguments
+
+* `planet`: Any variant of [Planet](./enum.Planet.html)
+**/
+pub fn tropical_period(planet: &Planet) -> f64 {
+    let (L1, _, _, _, _, _, _, _) = orb_elements(planet, 2451544.5);
+    let (L2, _, _, _, _, _, _, _) = orb_elements(planet, 2451545.5);
+    let n = angle::limit_to_two_PI(L2 - L1);
+
+    angle::TWO_PI / n
+}
+
+/**
+Computes a planet's mean synodic period with respect to the Earth
+
+# Returns
+
+* `period`: Mean synodic period *| in days*
+
+# Arguments
+
+* `planet`: Any variant of [Planet](./enum.Planet.html) other than
+            `Planet::Earth`
+**/
+pub fn synodic_period_with_earth<'a>(planet: &Planet) -> Result<f64, &'a str> {
+    if let Planet::Earth = *planet {
+        return Err("Planet::Earth was passed to the function planet::synodic_period_with_earth()");
+    }
+
+    let P = sidereal_period(planet);
+    let E = sidereal_period(&Planet::Earth);
+
+    Ok(1.0 / (1.0 / P - 1.0 / E).abs())
+}
+
+/// Selects the corrections applied in
+/// [apparent_place_with_options()](./fn.apparent_place_with_options.html)
+#[derive(Debug)]
+pub struct ReductionOptions {
+    /// Correct the planet's position for light-time
+    pub light_time: bool,
+    /// Correct for the annual aberration
+    pub aberration: bool,
+    /// Correct for the deflection of light by the Sun
+    pub light_deflection: bool,
+    /// Refer the position to the true equinox of the date, instead of
+    /// the mean equinox
+    pub nutation: bool,
+    /// Convert the position from the VSOP87 frame to the FK5 system
+    pub FK5: bool,
+}
+
+impl ReductionOptions {
+    /// Returns options with every correction applied
+    pub fn all() -> ReductionOptions {
+        ReductionOptions {
+            light_time: true,
+            aberration: true,
+            light_deflection: true,
+            nutation: true,
+            FK5: true,
+        }
+    }
+
+    /// Returns options with no correction applied, for the geometric
+    /// position
+    pub fn none() -> ReductionOptions {
+        ReductionOptions {
+            light_time: false,
+            aberration: false,
+            light_deflection: false,
+            nutation: false,
+            FK5: false,
+        }
+    }
+}
+
+/**
+Computes a planet's apparent geocentric equatorial position
+
+This applies all the corrections of
+[apparent_place_with_options()](./fn.apparent_place_with_options.html).
+
+# Returns
+
+* `eq_point`: Apparent equatorial point of the planet, referred to the
+              true equinox of the date *| in radians*
+
+# Arguments
+
+* `planet`: Any variant of [Planet](./enum.Planet.html) other than
+            `Planet::Earth`
+* `JD`    : Julian (Ephemeris) day
+**/
+pub fn apparent_place(planet: &Planet, JD: f64) -> coords::EqPoint {
+    apparent_place_with_options(planet, JD, &ReductionOptions::all())
+}
+
+/**
+Computes a planet's geocentric equatorial position, with a chosen set
+of corrections
+
+The corrections are applied in the order of Meeus's chapter 33:
+light-time, conversion to FK5, aberration and light deflection, and
+nutation. Aberration is computed in ecliptic coordinates, from the
+Earth's orbital velocity (Meeus, chapter 23), and light deflection
+with the first-order formula of general relativity. With no
+correction, the geometric position referred to the mean equinox of
+the date is returned.
+
+# Returns
+
+* `eq_point`: Equatorial point of the planet *| in radians*
+
+# Arguments
+
+* `planet`: Any variant of [Planet](./enum.Planet.html) other than
+            `Planet::Earth`
+* `JD`    : Julian (Ephemeris) day
+* `opts`  : The [ReductionOptions](./struct.ReductionOptions.html)
+**/
+pub fn apparent_place_with_options(
+    planet: &Planet,
+    JD: f64,
+    opts: &ReductionOptions,
+) -> coords::EqPoint {
+    let (L0, B0, R0) = heliocent_coords(&Planet::Earth, JD);
+
+    let (L, B, R) = heliocent_coords(planet, JD);
+    let (mut lambda, mut beta, _, mut t) = geocent_geomet_ecl_coords(L0, B0, R0, L, B, R);
+    if opts.light_time {
+        for _ in 0..3 {
+            let (L, B, R) = heliocent_coords(planet, JD - t);
+            let (new_lambda, new_beta, _, new_t) = geocent_geomet_ecl_coords(L0, B0, R0, L, B, R);
+            lambda = new_lambda;
+            beta = new_beta;
+            t = new_t;
+        }
+    }
+
+    let sun_long = L0 + std::f64::consts::PI;
+    let sun_lat = -B0;
+    if opts.FK5 {
+        let (FK5_long, FK5_lat) = ecl_coords_to_FK5(JD, lambda, beta);
+        lambda = FK5_long;
+        beta = FK5_lat;
+    }
+
+    if opts.aberration {
+        let T = time::julian_cent(JD);
+        let k = angle::deg_frm_dms(0, 0, 20.49552).to_radians();
+        let e = 0.016708634 - T * (0.000042037 + T * 0.0000001267);
+        let pi = (102.93735 + T * (1.71946 + T * 0.00046)).to_radians();
+
+        let d_lambda = (-k * (sun_long - lambda).cos() + e * k * (pi - lambda).cos()) / beta.cos();
+        let d_beta = -k * beta.sin() * ((sun_long - lambda).sin() - e * (pi - lambda).sin());
+        lambda += d_lambda;
+        beta += d_beta;
+    }
+
+    if opts.light_deflection {
+        let p = [
+            beta.cos() * lambda.cos(),
+            beta.cos() * lambda.sin(),
+            beta.sin(),
+        ];
+        let s = [
+            sun_lat.cos() * sun_long.cos(),
+            sun_lat.cos() * sun_long.sin(),
+            sun_lat.sin(),
+        ];
+        let cos_E = p[0] * s[0] + p[1] * s[1] + p[2] * s[2];
+        let sin_E = (1.0 - cos_E * cos_E).sqrt();
+
+        if sin_E > 0.0 {
+            let deflection =
+                angle::deg_frm_dms(0, 0, 0.004072).to_radians() * (1.0 + cos_E) / (sin_E * R0);
+            let q: Vec<f64> = (0..3)
+                .map(|i| p[i] + deflection * (p[i] * cos_E - s[i]) / sin_E)
+                .collect();
+
+            lambda = q[1].atan2(q[0]);
+            beta = q[2].atan2((q[0] * q[0] + q[1] * q[1]).sqrt());
+        }
+    }
+
+    let mut oblq = ecliptic::mn_oblq_laskar(JD);
+    if opts.nutation {
+        let (nut_in_long, nut_in_oblq) = nutation::nutation(JD);
+        lambda += nut_in_long;
+        oblq += nut_in_oblq;
+    }
+
+    coords::EqPoint {
+        asc: angle::limit_to_two_PI(coords::asc_frm_ecl(lambda, beta, oblq)),
+        dec: coords::dec_frm_ecl(lambda, beta, oblq),
+    }
+}
+
+/// Holds a planet's heliocentric ecliptic coordinates
+#[derive(Debug)]
+pub struct HelioCoords {
+    /// Heliocentric longitude *| in radians*
+    pub long: f64,
+    /// Heliocentric latitude *| in radians*
+    pub lat: f64,
+    /// Heliocentric radius vector *| in AU*
+    pub rad_vec: f64,
+}
+
+/**
+Computes a planet's heliocentric coordinates, referred to the mean
+equinox of the date, as a `HelioCoords`
+
+This is the same as [heliocent_coords()](./fn.heliocent_coords.html),
+but with the coordinates named, and the latitude negative south of the
+ecliptic instead of limited to [0, 2π). They're taken straight from
+the VSOP87 series, with no correction for light-time or aberration.
+
+# Returns
+
+* `helio_coords`: Heliocentric coordinates of the planet
+
+# Arguments
+
+* `planet`: Any variant of [Planet](./enum.Planet.html)
+* `JD`    : Julian (Ephemeris) day
+**/
+pub fn heliocent_pos(planet: &Planet, JD: f64) -> HelioCoords {
+    let (long, lat, rad_vec) = heliocent_coords(planet, JD);
+
+    HelioCoords {
+        long,
+        lat: angle::limit_to_pm_PI(lat),
+        rad_vec,
+    }
+}
+
 /**
 Computes a planet's heliocentric coordinates, referred to the mean
 equinox of the date
@@ -307,6 +627,12 @@ equinox of the date
 * `JD`    : Julian (Ephemeris) day
 **/
 pub fn heliocent_coords(planet: &Planet, JD: f64) -> (f64, f64, f64) {
+    heliocent_coords_frm_JM(planet, time::julian_mill(JD))
+}
+
+// Computes a planet's heliocentric coordinates from the Julian
+// millennium of the date
+fn heliocent_coords_frm_JM(planet: &Planet, JM: f64) -> (f64, f64, f64) {
     let VSOPD87_Terms = match planet {
         &Planet::Mercury => VSOPD_87::mercury::terms(),
         &Planet::Venus => VSOPD_87::venus::terms(),
@@ -322,8 +648,6 @@ pub fn heliocent_coords(planet: &Planet, JD: f64) -> (f64, f64, f64) {
     let mut B = 0.0;
     let mut R = 0.0;
 
-    let JM = time::julian_mill(JD);
-
     let mut n: u8 = 1; // L, then B, then R
     for i in VSOPD87_Terms.iter() {
         // L or B or R
@@ -360,6 +684,62 @@ pub fn heliocent_coords(planet: &Planet, JD: f64) -> (f64, f64, f64) {
     (L, B, R)
 }
 
+/**
+Computes the heliocentric coordinates of all the planets, referred to
+the mean equinox of J2000.0
+
+The coordinates of each planet are those of
+[heliocent_coords()](./fn.heliocent_coords.html), precessed from the
+date to J2000.0 as by
+[precess::precess_ecl_coords()](../precess/fn.precess_ecl_coords.html).
+The time argument of the series and the
+[precession matrix](../precess/fn.precession_matrix_ecl.html) are
+computed once for all the planets.
+
+# Returns
+
+An array of `(planet, long, lat, rad_vec)`, from Mercury to Neptune
+
+* `planet` : The [Planet](./enum.Planet.html)
+* `long`   : Heliocentric longitude *| in radians*
+* `lat`    : Heliocentric latitude *| in radians*
+* `rad_vec`: Heliocentric radius vector *| in AU*
+
+# Arguments
+
+* `JD`: Julian (Ephemeris) day
+**/
+pub fn all_heliocent_coords_J2000(JD: f64) -> [(Planet, f64, f64, f64); 8] {
+    let JM = time::julian_mill(JD);
+    let matrix = precess::precession_matrix_ecl(JD, 2451545.0);
+
+    let coords = |planet: Planet| {
+        let (L, B, R) = heliocent_coords_frm_JM(&planet, JM);
+        let v = coords::apply_matrix(
+            &matrix,
+            &[B.cos() * L.cos(), B.cos() * L.sin(), B.sin()],
+        );
+
+        (
+            planet,
+            angle::limit_to_two_PI(v[1].atan2(v[0])),
+            v[2].atan2((v[0] * v[0] + v[1] * v[1]).sqrt()),
+            R,
+        )
+    };
+
+    [
+        coords(Planet::Mercury),
+        coords(Planet::Venus),
+        coords(Planet::Earth),
+        coords(Planet::Mars),
+        coords(Planet::Jupiter),
+        coords(Planet::Saturn),
+        coords(Planet::Uranus),
+        coords(Planet::Neptune),
+    ]
+}
+
 #[inline(always)]
 fn light_time(dist: f64) -> f64 {
     0.0057755183 * dist
@@ -594,6 +974,1027 @@ pub fn heliocent_coords_frm_orb_elements(i: f64, sigma: f64, w: f64, v: f64, r:
     (y.atan2(x), z.atan2((x * x + y * y).sqrt()))
 }
 
+/**
+Computes the instant of dichotomy of an inferior planet
+
+Dichotomy is the instant when the planet's disk appears exactly
+half illuminated, ie, when the phase angle is 90 degrees. The
+mean instant of inferior conjunction (Meeus, chapter 36) is used
+to bracket the event, which is then found by bisection on the
+Sun-planet-Earth triangle.
+
+# Returns
+
+* `JD`: Julian (Ephemeris) day of the first dichotomy of the
+        requested kind after the start of `year`
+
+# Arguments
+
+* `planet` : `Planet::Mercury` or `Planet::Venus`
+* `year`   : Year, with decimals if needed
+* `evening`: `true` for the dichotomy while the planet is an evening
+             object (before inferior conjunction), `false` for the
+             one while it is a morning object
+**/
+pub fn dichotomy_instant<'a>(planet: &Planet, year: f64, evening: bool) -> Result<f64, &'a str> {
+    let (A, B) = match *planet {
+        Planet::Mercury => (2451612.023, 115.8774771),
+        Planet::Venus => (2451996.706, 583.921361),
+        _ => {
+            return Err("Only Planet::Mercury and Planet::Venus can be passed to the function planet::dichotomy_instant()");
+        }
+    };
+
+    let JD_start = 2451545.0 + (year - 2000.0) * 365.25;
+    let mut k = ((JD_start - A) / B).floor();
+
+    loop {
+        let conj = A + k * B;
+        let (JD1, JD2) = if evening {
+            (conj - B / 2.0, conj)
+        } else {
+            (conj, conj + B / 2.0)
+        };
+
+        let f1 = cos_phase_angl(planet, JD1);
+        let f2 = cos_phase_angl(planet, JD2);
+        if f1 * f2 > 0.0 {
+            return Err("Could not bracket the dichotomy in planet::dichotomy_instant()");
+        }
+
+        let JD = interpol::refine_zero(&|JD| cos_phase_angl(planet, JD), JD1, JD2, f1, f2, 1e-6);
+        if JD >= JD_start {
+            return Ok(JD);
+        }
+        k += 1.0;
+    }
+}
+
+/**
+Computes a planet's phase angle on a given day
+
+The phase angle is found from the Sun-planet-Earth triangle, with the
+planet's heliocentric position corrected for light-time. For Mercury
+and Venus, it takes all values between 0 (full, near superior
+conjunction) and 180 degrees (new, near inferior conjunction); for
+the outer planets it stays small.
+
+# Returns
+
+* `phase_angle`: Phase angle of the planet *| in radians*
+
+# Arguments
+
+* `planet`: Any variant of [Planet](./enum.Planet.html) other than
+            `Planet::Earth`
+* `JD`    : Julian (Ephemeris) day
+**/
+#[inline]
+pub fn phase_angle(planet: &Planet, JD: f64) -> f64 {
+    cos_phase_angl(planet, JD).acos()
+}
+
+/**
+Computes the illuminated fraction of a planet's disk on a given day
+
+For Mercury and Venus, the illuminated fraction takes all values
+between 1 (full) and 0 (new).
+
+# Returns
+
+* `illum_frac`: Illuminated fraction of the planet's disk
+
+# Arguments
+
+* `planet`: Any variant of [Planet](./enum.Planet.html) other than
+            `Planet::Earth`
+* `JD`    : Julian (Ephemeris) day
+**/
+#[inline]
+pub fn illuminated_fraction(planet: &Planet, JD: f64) -> f64 {
+    (1.0 + cos_phase_angl(planet, JD)) / 2.0
+}
+
+/**
+Computes the instant and the separation of a stellar occultation by a
+planet
+
+The geocentric separation between the star and the planet is scanned
+over the search window, and it's minimum is then refined. An
+occultation occurs if the least separation is smaller than the
+planet's apparent equatorial semidiameter; the instant returned is
+then that of the central flash, when the star is nearest to the
+center of the disk.
+
+# Returns
+
+* `Some((JD, sepr))`: Julian (Ephemeris) day of the least separation
+                      between the star and the center of the planet,
+                      and that separation *| in radians*, if an
+                      occultation occurs
+* `None`            : If no occultation occurs within the window
+
+# Arguments
+
+* `planet`     : Any variant of [Planet](./enum.Planet.html) other
+                 than `Planet::Earth`
+* `star_eq`    : Apparent equatorial point of the star, referred to
+                 the true equinox of the date *| in radians*
+* `JD_near`    : Julian (Ephemeris) day near the occultation
+* `window_days`: Half-width of the search window around `JD_near`
+                 *| in days*
+**/
+pub fn stellar_occultation(
+    planet: &Planet,
+    star_eq: &coords::EqPoint,
+    JD_near: f64,
+    window_days: f64,
+) -> Option<(f64, f64)> {
+    if let Planet::Earth = *planet {
+        return None;
+    }
+
+    let sepr = |JD: f64| -> f64 {
+        let (planet_ecl_point, _) = geocent_apprnt_ecl_coords(planet, JD);
+        let (nut_in_long, nut_in_oblq) = nutation::nutation(JD);
+        let true_oblq = ecliptic::mn_oblq_laskar(JD) + nut_in_oblq;
+        let long = planet_ecl_point.long + nut_in_long;
+
+        let planet_eq_point = coords::EqPoint {
+            asc: coords::asc_frm_ecl(long, planet_ecl_point.lat, true_oblq),
+            dec: coords::dec_frm_ecl(long, planet_ecl_point.lat, true_oblq),
+        };
+
+        planet_eq_point.anglr_sepr(star_eq)
+    };
+
+    let step = 0.1;
+    let n = ((2.0 * window_days / step).ceil() as usize).max(1);
+    let JD_start = JD_near - window_days;
+
+    let mut JD_min = JD_start;
+    let mut sepr_min = sepr(JD_min);
+    for i in 1..(n + 1) {
+        let JD = JD_start + (i as f64) * step;
+        let s = sepr(JD);
+        if s < sepr_min {
+            JD_min = JD;
+            sepr_min = s;
+        }
+    }
+
+    let JD = interpol::refine_extremum(&sepr, JD_min, step, 1e-6);
+    let sepr_min = sepr(JD);
+    let (_, planet_earth_dist) = geocent_apprnt_ecl_coords(planet, JD);
+
+    match semidiameter(planet, planet_earth_dist) {
+        Ok(s) if sepr_min < s => Some((JD, sepr_min)),
+        _ => None,
+    }
+}
+
+/// Represents the circumstances of a transit of a planet across the
+/// Sun, as seen by an observer
+#[derive(Debug)]
+pub struct PlanetTransit {
+    /// First contact, when the planet's disk touches the Sun's from
+    /// outside
+    pub contact1: f64,
+    /// Second contact, when the planet's disk is wholly inside the
+    /// Sun's; `None` for a grazing transit
+    pub contact2: Option<f64>,
+    /// Time of the least separation between the centers of the disks
+    pub greatest: f64,
+    /// Third contact, when the planet's disk starts leaving the Sun's;
+    /// `None` for a grazing transit
+    pub contact3: Option<f64>,
+    /// Fourth contact, when the planet's disk leaves the Sun's
+    pub contact4: f64,
+    /// Least separation between the centers of the disks *| in radians*
+    pub min_sepr: f64,
+}
+
+/**
+Computes the local circumstances of a transit of Mercury or Venus
+across the Sun
+
+The transit searched for is the one at the inferior conjunction
+nearest to `year`. The contacts are found from the topocentric
+positions of the planet and the Sun, so they differ from the
+geocentric ones by up to several minutes.
+
+# Returns
+
+* `planet_transit`: The [PlanetTransit](./struct.PlanetTransit.html)
+
+The times are Julian (Ephemeris) days. `None` is returned if `planet`
+isn't Mercury or Venus, if there's no transit at that conjunction, or
+if the Sun is below the horizon throughout it.
+
+# Arguments
+
+* `planet`        : The [Planet](./enum.Planet.html)
+* `year`          : Decimal year near the transit
+* `geograph_point`: Geographic point of the observer *| in radians*
+* `observer_ht`   : Height of the observer above sea level *| in meters*
+**/
+pub fn transit_across_sun_local(
+    planet: &Planet,
+    year: f64,
+    geograph_point: &coords::GeographPoint,
+    observer_ht: f64,
+) -> Option<PlanetTransit> {
+    match *planet {
+        Planet::Mercury | Planet::Venus => {}
+        _ => return None,
+    }
+
+    let delta_t = time::delta_t_frm_decimal_year(year) / 86400.0;
+
+    // Topocentric separation of the centers, and the sum and difference
+    // of the semidiameters
+    let sepr = |JD: f64| -> (f64, f64, f64) {
+        let (sun_eq_point, sun_earth_dist) = sun::apprnt_eq_pos(JD);
+        let planet_eq_point = apparent_place(planet, JD);
+        let (_, planet_earth_dist) = geocent_apprnt_ecl_coords(planet, JD);
+
+        let greenw_sidr = sidereal::apparent_sidereal_greenwich(JD - delta_t);
+
+        let sun_topo = parallax::topocent_eq_coords(
+            &sun_eq_point,
+            parallax::eq_hz_parallax(sun_earth_dist),
+            geograph_point,
+            observer_ht,
+            greenw_sidr,
+        );
+        let planet_topo = parallax::topocent_eq_coords(
+            &planet_eq_point,
+            parallax::eq_hz_parallax(planet_earth_dist),
+            geograph_point,
+            observer_ht,
+            greenw_sidr,
+        );
+
+        let sun_semid = sun::semidiameter(sun_earth_dist).to_radians();
+        let planet_semid = semidiameter(planet, planet_earth_dist).unwrap_or(0.0);
+
+        (
+            sun_topo.anglr_sepr(&planet_topo),
+            sun_semid + planet_semid,
+            sun_semid - planet_semid,
+        )
+    };
+
+    let syn_period = synodic_period_with_earth(planet).ok()?;
+    let JD_year = 2451545.0 + (year - 2000.0) * 365.25;
+    let JD_start = JD_year - syn_period / 2.0;
+
+    let mut JD_conj = None;
+    let mut sepr_min = std::f64::consts::PI;
+    for i in 0..(syn_period.ceil() as usize + 1) {
+        let JD = JD_start + (i as f64);
+        let (planet_ecl_point, planet_earth_dist) = geocent_apprnt_ecl_coords(planet, JD);
+        let (sun_ecl_point, sun_earth_dist) = sun::geocent_ecl_pos(JD);
+        let s = planet_ecl_point.anglr_sepr(&sun_ecl_point);
+        if planet_earth_dist < sun_earth_dist && s < sepr_min {
+            JD_conj = Some(JD);
+            sepr_min = s;
+        }
+    }
+    let JD_conj = JD_conj?;
+
+    let greatest = interpol::refine_extremum(&|JD| sepr(JD).0, JD_conj, 1.0, 1e-5);
+    let (min_sepr, outer, inner) = sepr(greatest);
+    if min_sepr >= outer {
+        return None;
+    }
+
+    let contact = |JD_out: f64, internal: bool| -> f64 {
+        let overlap = |JD: f64| -> f64 {
+            let (s, outer, inner) = sepr(JD);
+            s - if internal { inner } else { outer }
+        };
+
+        interpol::refine_zero(
+            &overlap,
+            JD_out,
+            greatest,
+            overlap(JD_out),
+            overlap(greatest),
+            1e-6,
+        )
+    };
+
+    let contact1 = contact(greatest - 0.5, false);
+    let contact4 = contact(greatest + 0.5, false);
+    let (contact2, contact3) = if min_sepr < inner {
+        (
+            Some(contact(greatest - 0.5, true)),
+            Some(contact(greatest + 0.5, true)),
+        )
+    } else {
+        (None, None)
+    };
+
+    let mut visible = false;
+    let mut JD = contact1;
+    while JD < contact4 + 0.01 {
+        if let sun::SkyState::Day = sun::sky_state(JD.min(contact4) - delta_t, geograph_point) {
+            visible = true;
+            break;
+        }
+        JD += 0.01;
+    }
+    if !visible {
+        return None;
+    }
+
+    Some(PlanetTransit {
+        contact1,
+        contact2,
+        greatest,
+        contact3,
+        contact4,
+        min_sepr,
+    })
+}
+
+/**
+Computes the time at which a planet reaches an apparent geocentric
+ecliptic longitude
+
+All the crossings within the window are found, by scanning it in
+half-day steps and bisecting, so that a planet near a stationary
+point, which may cross the longitude thrice, is handled.
+
+# Returns
+
+* `JD`: Julian (Ephemeris) day of the crossing nearest to `JD_near`,
+        or `None` if the planet doesn't cross `target_long` within
+        the window, or if `Planet::Earth` is passed
+
+# Arguments
+
+* `planet`     : The `Planet`
+* `target_long`: Apparent ecliptic longitude, referred to the true
+                 equinox of the date *| in radians*
+* `JD_near`    : Julian (Ephemeris) day around which to search
+* `window_days`: Half-width of the search window *| in days*
+**/
+pub fn longitude_crossing(
+    planet: &Planet,
+    target_long: f64,
+    JD_near: f64,
+    window_days: f64,
+) -> Option<f64> {
+    if let Planet::Earth = *planet {
+        return None;
+    }
+
+    let offset = |JD: f64| -> f64 {
+        let (planet_ecl_point, _) = geocent_apprnt_ecl_coords(planet, JD);
+        let (nut_in_long, _) = nutation::nutation(JD);
+
+        angle::limit_to_pm_PI(planet_ecl_point.long + nut_in_long - target_long)
+    };
+
+    let step = 0.5;
+    let n = ((2.0 * window_days / step).ceil() as usize).max(1);
+    let JD_start = JD_near - window_days;
+
+    let mut nearest: Option<f64> = None;
+    let mut JD1 = JD_start;
+    let mut d1 = offset(JD1);
+    for i in 1..(n + 1) {
+        let JD2 = JD_start + (i as f64) * step;
+        let d2 = offset(JD2);
+
+        if d1 * d2 <= 0.0 && (d1 - d2).abs() < std::f64::consts::PI {
+            let JD = interpol::refine_zero(&offset, JD1, JD2, d1, d2, 1e-7);
+            nearest = match nearest {
+                Some(JD_prev) if (JD_prev - JD_near).abs() <= (JD - JD_near).abs() => {
+                    Some(JD_prev)
+                }
+                _ => Some(JD),
+            };
+        }
+
+        JD1 = JD2;
+        d1 = d2;
+    }
+
+    nearest
+}
+
+/**
+Computes the middle of a retrograde motion of a planet
+
+The stationary points, where the planet's apparent geocentric
+longitude stops changing, are found by scanning the window in one-day
+steps and bisecting. The instant halfway between a stationary point
+starting a retrograde motion and the one ending it is close to the
+opposition of an outer planet, and to the inferior conjunction of an
+inner one, though not exactly at it, as the loop isn't quite
+symmetrical.
+
+# Returns
+
+* `JD`: Julian (Ephemeris) day of the middle of the retrograde motion
+
+`JD` is halfway between the two stationary points of the retrograde
+motion nearest to `JD_near`. `None` is returned if no whole retrograde
+motion lies within the window, or if `Planet::Earth` is passed.
+
+# Arguments
+
+* `planet`     : The `Planet`
+* `JD_near`    : Julian (Ephemeris) day around which to search
+* `window_days`: Half-width of the search window *| in days*
+**/
+pub fn retrograde_midpoint(planet: &Planet, JD_near: f64, window_days: f64) -> Option<f64> {
+    if let Planet::Earth = *planet {
+        return None;
+    }
+
+    let rate = |JD: f64| long_rate(planet, JD);
+
+    let step = 1.0;
+    let n = ((2.0 * window_days / step).ceil() as usize).max(1);
+    let JD_start = JD_near - window_days;
+
+    let mut nearest: Option<f64> = None;
+    let mut retro_start: Option<f64> = None;
+    let mut JD1 = JD_start;
+    let mut r1 = rate(JD1);
+    for i in 1..(n + 1) {
+        let JD2 = JD_start + (i as f64) * step;
+        let r2 = rate(JD2);
+
+        if r1 * r2 <= 0.0 && r1 != r2 {
+            let JD_station = interpol::refine_zero(&rate, JD1, JD2, r1, r2, 1e-6);
+
+            if r1 > 0.0 || r2 < 0.0 {
+                retro_start = Some(JD_station);
+            } else if let Some(JD_retro_start) = retro_start {
+                let JD = (JD_retro_start + JD_station) / 2.0;
+                nearest = match nearest {
+                    Some(JD_prev) if (JD_prev - JD_near).abs() <= (JD - JD_near).abs() => {
+                        Some(JD_prev)
+                    }
+                    _ => Some(JD),
+                };
+                retro_start = None;
+            }
+        }
+
+        JD1 = JD2;
+        r1 = r2;
+    }
+
+    nearest
+}
+
+/// Represents a planet whose orbit lies inside the Earth's
+#[derive(Debug)]
+pub enum InnerPlanet {
+    /// Mercury
+    Mercury,
+    /// Venus
+    Venus,
+}
+
+/// Represents the side of the Sun on which an inner planet is seen
+#[derive(Debug)]
+pub enum Elongation {
+    /// East of the Sun, in the evening sky
+    East,
+    /// West of the Sun, in the morning sky
+    West,
+}
+
+/**
+Computes the next greatest elongation of Mercury or Venus
+
+The search starts from the mean instant of the greatest elongation of
+Meeus's chapter 36, which is the mean instant of inferior conjunction
+of table 36.A, `A + kB`, less 21.6101 days (Mercury) or 70.7600 days
+(Venus) for an eastern elongation, and plus 21.6249 days or 70.7462
+days for a western one. `k` is advanced from the first integer whose
+mean instant can be after `after_JD`. The true instant, which differs
+from the mean by up to a few days, is then found by refining the
+maximum of the elongation, the geocentric angular separation of the
+planet's and the Sun's apparent positions, instead of from Meeus's
+periodic terms.
+
+Passing the `JD` of a greatest elongation as `after_JD` gives the next
+one on the same side of the Sun.
+
+# Returns
+
+`(JD, elong)`
+
+* `JD`   : Julian (Ephemeris) day of the greatest elongation
+* `elong`: The greatest elongation *| in radians*
+
+An `Err` is returned if no greatest elongation is found near the mean
+instants.
+
+# Arguments
+
+* `planet`   : The [InnerPlanet](./enum.InnerPlanet.html)
+* `after_JD` : Julian (Ephemeris) day after which to search
+* `direction`: The [Elongation](./enum.Elongation.html) to search for
+**/
+pub fn greatest_elongation<'a>(
+    planet: &InnerPlanet,
+    after_JD: f64,
+    direction: &Elongation,
+) -> Result<(f64, f64), &'a str> {
+    let (planet, A, B, to_east, to_west) = match *planet {
+        InnerPlanet::Mercury => (Planet::Mercury, 2451612.023, 115.8774771, -21.6101, 21.6249),
+        InnerPlanet::Venus => (Planet::Venus, 2451996.706, 583.921361, -70.76, 70.7462),
+    };
+    let (east, A) = match *direction {
+        Elongation::East => (true, A + to_east),
+        Elongation::West => (false, A + to_west),
+    };
+
+    // The elongation, negative on the other side of the Sun
+    let elong = |JD: f64| -> f64 {
+        let planet_eq_point = apparent_place(&planet, JD);
+        let (sun_eq_point, _) = sun::apprnt_eq_pos(JD);
+        let sepr = planet_eq_point.anglr_sepr(&sun_eq_point);
+
+        if ((planet_eq_point.asc - sun_eq_point.asc).sin() > 0.0) == east {
+            sepr
+        } else {
+            -sepr
+        }
+    };
+
+    // The true instants differ from the mean ones by less than 10 days
+    let k = ((after_JD - 10.0 - A) / B).ceil();
+
+    for n in 0..3 {
+        let JD_mean = A + (k + n as f64) * B;
+        let JD = interpol::refine_extremum(&elong, JD_mean, 2.0, 1e-5);
+        let e = elong(JD);
+
+        if JD > after_JD + 1e-3 && e > 0.0 && (JD - JD_mean).abs() < 10.0 {
+            return Ok((JD, e));
+        }
+    }
+
+    Err("No greatest elongation was found by planet::greatest_elongation()")
+}
+
+/**
+Computes the instant of a planet's next opposition
+
+At opposition, the geocentric apparent longitudes of the planet and
+the Sun differ by 180 degrees. The search starts from the mean
+instant of opposition of Meeus's table 36.A, `A + kB`, with `k` the
+first integer whose mean instant can be after `after_JD`. The true
+instant, which differs from the mean by up to a few days, is then
+found from the apparent longitudes, instead of from Meeus's periodic
+terms.
+
+# Returns
+
+* `JD`: Julian (Ephemeris) day of the opposition
+
+# Arguments
+
+* `planet`  : Any [Planet](./enum.Planet.html) from Mars to Neptune
+* `after_JD`: Julian (Ephemeris) day after which to search
+**/
+pub fn opposition<'a>(planet: &Planet, after_JD: f64) -> Result<f64, &'a str> {
+    let (A, B) = match *planet {
+        Planet::Mars => (2452097.382, 779.936104),
+        Planet::Jupiter => (2451870.628, 398.884046),
+        Planet::Saturn => (2451870.17, 378.091904),
+        Planet::Uranus => (2451764.317, 369.656035),
+        Planet::Neptune => (2451753.122, 367.486703),
+        _ => {
+            return Err("An inner planet or Planet::Earth was passed to planet::opposition()")
+        }
+    };
+
+    let long_diff = |JD: f64| apprnt_long_diff(planet, JD, std::f64::consts::PI);
+    let (start, end) = next_mean_event(A, B, after_JD);
+
+    zero_of_long_diff(&long_diff, max_long_rate(planet) + SUN_LONG_RATE, start, end)
+        .ok_or("No opposition was found by planet::opposition()")
+}
+
+/**
+Computes the instant of a planet's next conjunction with the Sun
+
+At conjunction, the geocentric apparent longitudes of the planet and
+the Sun are equal. For Mercury and Venus, this is the next inferior
+or superior conjunction, whichever comes first. The search starts
+from the mean instant of conjunction of Meeus's table 36.A, as in
+[opposition()](./fn.opposition.html).
+
+# Returns
+
+* `JD`: Julian (Ephemeris) day of the conjunction
+
+# Arguments
+
+* `planet`  : Any [Planet](./enum.Planet.html) other than `Planet::Earth`
+* `after_JD`: Julian (Ephemeris) day after which to search
+**/
+pub fn conjunction<'a>(planet: &Planet, after_JD: f64) -> Result<f64, &'a str> {
+    let mean_events: &[(f64, f64)] = match *planet {
+        Planet::Mercury => &[(2451612.023, 115.8774771), (2451554.084, 115.8774771)],
+        Planet::Venus => &[(2451996.706, 583.921361), (2451704.746, 583.921361)],
+        Planet::Mars => &[(2451707.414, 779.936104)],
+        Planet::Jupiter => &[(2451671.186, 398.884046)],
+        Planet::Saturn => &[(2451681.124, 378.091904)],
+        Planet::Uranus => &[(2451579.489, 369.656035)],
+        Planet::Neptune => &[(2451569.379, 367.486703)],
+        Planet::Earth => {
+            return Err("Planet::Earth was passed to planet::conjunction()");
+        }
+    };
+
+    let long_diff = |JD: f64| apprnt_long_diff(planet, JD, 0.0);
+    let max_rate = max_long_rate(planet) + SUN_LONG_RATE;
+
+    let mut nearest: Option<f64> = None;
+    for &(A, B) in mean_events.iter() {
+        let (start, end) = next_mean_event(A, B, after_JD);
+        if let Some(JD) = zero_of_long_diff(&long_diff, max_rate, start, end) {
+            nearest = Some(nearest.map_or(JD, |JD_prev: f64| JD_prev.min(JD)));
+        }
+    }
+
+    nearest.ok_or("No conjunction was found by planet::conjunction()")
+}
+
+/**
+Computes the instant of the next conjunction in longitude of two
+planets
+
+The difference between the geocentric apparent longitudes of the
+planets is followed from `after_JD` on, with steps short enough for
+it not to pass through zero unseen, even when one of the planets
+moves fast near opposition, or a conjunction is one of a triple
+conjunction. It's then refined by bisection. A conjunction of any two
+planets occurs within 200 years.
+
+# Returns
+
+* `JD`: Julian (Ephemeris) day of the conjunction
+
+# Arguments
+
+* `planet1` : Any [Planet](./enum.Planet.html) other than `Planet::Earth`
+* `planet2` : Any other [Planet](./enum.Planet.html) than `planet1` and
+  `Planet::Earth`
+* `after_JD`: Julian (Ephemeris) day after which to search
+**/
+pub fn mutual_conjunction<'a>(
+    planet1: &Planet,
+    planet2: &Planet,
+    after_JD: f64,
+) -> Result<f64, &'a str> {
+    if let (&Planet::Earth, _) | (_, &Planet::Earth) = (planet1, planet2) {
+        return Err("Planet::Earth was passed to planet::mutual_conjunction()");
+    }
+    if std::mem::discriminant(planet1) == std::mem::discriminant(planet2) {
+        return Err("The same planet was passed twice to planet::mutual_conjunction()");
+    }
+
+    let long_diff = |JD: f64| {
+        let (ecl_point1, _) = geocent_apprnt_ecl_coords(planet1, JD);
+        let (ecl_point2, _) = geocent_apprnt_ecl_coords(planet2, JD);
+
+        angle::limit_to_pm_PI(ecl_point1.long - ecl_point2.long)
+    };
+    let max_rate = max_long_rate(planet1) + max_long_rate(planet2);
+
+    zero_of_long_diff(&long_diff, max_rate, after_JD, after_JD + 200.0 * 365.25)
+        .ok_or("No conjunction was found by planet::mutual_conjunction()")
+}
+
+// Greatest rate of the Sun's geocentric longitude, in radians per day
+const SUN_LONG_RATE: f64 = 1.05 * std::f64::consts::PI / 180.0;
+
+// Computes a little more than the greatest rate of a planet's
+// geocentric longitude, in radians per day
+fn max_long_rate(planet: &Planet) -> f64 {
+    let deg_per_day: f64 = match *planet {
+        Planet::Mercury => 2.5,
+        Planet::Venus => 1.4,
+        Planet::Earth => 0.0,
+        Planet::Mars => 0.9,
+        Planet::Jupiter => 0.28,
+        Planet::Saturn => 0.15,
+        Planet::Uranus => 0.07,
+        Planet::Neptune => 0.045,
+    };
+
+    deg_per_day.to_radians()
+}
+
+// Computes the geocentric apparent longitude of a planet, less that of
+// the Sun and an offset, in the range (-π, π]
+fn apprnt_long_diff(planet: &Planet, JD: f64, offset: f64) -> f64 {
+    let (nut_in_long, nut_in_oblq) = nutation::nutation(JD);
+    let true_oblq = ecliptic::mn_oblq_laskar(JD) + nut_in_oblq;
+
+    let planet_eq_point = apparent_place(planet, JD);
+    let planet_long = coords::ecl_long_frm_eq(planet_eq_point.asc, planet_eq_point.dec, true_oblq);
+
+    let (sun_ecl_point, sun_earth_dist) = sun::geocent_ecl_pos(JD);
+    let sun_long = sun_ecl_point.long + nut_in_long + aberr::sol_aberr(sun_earth_dist);
+
+    angle::limit_to_pm_PI(planet_long - sun_long - offset)
+}
+
+// Computes the interval in which to search for the first event after a
+// Julian day, from the mean instants A + kB of Meeus's table 36.A. The
+// true instants differ from the mean ones by less than 40 days.
+fn next_mean_event(A: f64, B: f64, after_JD: f64) -> (f64, f64) {
+    let k = ((after_JD - 40.0 - A) / B).ceil();
+
+    (after_JD.max(A + k * B - 40.0), A + (k + 1.0) * B + 40.0)
+}
+
+// Computes the first instant between two Julian days at which a
+// difference of longitudes, in the range (-π, π], passes through zero,
+// leaving out a zero at the start itself. The steps are never long
+// enough for the difference to reach zero at its greatest rate, in
+// radians per day.
+fn zero_of_long_diff(
+    long_diff: &dyn Fn(f64) -> f64,
+    max_rate: f64,
+    start: f64,
+    end: f64,
+) -> Option<f64> {
+    let mut JD1 = start;
+    let mut d1 = long_diff(JD1);
+
+    while JD1 < end {
+        let JD2 = (JD1 + (d1.abs() / max_rate).max(0.5)).min(end);
+        let d2 = long_diff(JD2);
+
+        if d1 * d2 <= 0.0 && (d1 - d2).abs() < std::f64::consts::PI {
+            let JD = interpol::refine_zero(long_diff, JD1, JD2, d1, d2, 1e-6);
+
+            if JD > start + 1e-3 {
+                return Some(JD);
+            }
+        }
+
+        JD1 = JD2;
+        d1 = d2;
+    }
+
+    None
+}
+
+/// Represents a stationary point of a planet
+#[derive(Debug)]
+pub enum Stationary {
+    /// The planet's motion turns from direct to retrograde
+    First,
+    /// The planet's motion turns from retrograde to direct
+    Second,
+}
+
+/**
+Computes the instant of a planet's next stationary point
+
+At a stationary point, the geocentric apparent longitude of the planet
+stops changing, as its motion turns between direct and retrograde.
+
+For Mercury and Venus, the planet is retrograde around inferior
+conjunction, so the first stationary point comes a little after
+greatest eastern elongation, and the second a little before greatest
+western elongation. For the outer planets, the planet is retrograde
+around opposition, with the stationary points on either side of it.
+
+The search starts, as in [opposition()](./fn.opposition.html), from
+the mean instant of inferior conjunction or of opposition of Meeus's
+table 36.A, `A + kB`, shifted by the mean interval between it and the
+stationary point, with `k` the first integer whose mean instant can be
+after `after_JD`. The true instant differs from the mean by up to a
+few days, or about a month for Mars, and is found by following the
+rate of the longitude daily around the mean instant, and refining its
+change of sign by bisection, instead of from periodic terms.
+
+# Returns
+
+* `JD`: Julian (Ephemeris) day of the stationary point
+
+# Arguments
+
+* `planet`  : Any [Planet](./enum.Planet.html) other than `Planet::Earth`
+* `after_JD`: Julian (Ephemeris) day after which to search
+* `which`   : The [Stationary](./enum.Stationary.html) point to search for
+**/
+pub fn stationary_point<'a>(
+    planet: &Planet,
+    after_JD: f64,
+    which: &Stationary,
+) -> Result<f64, &'a str> {
+    // A and B of Meeus's table 36.A, the mean intervals from A + kB to
+    // the first and second stationary points, and the greatest
+    // difference between the true and the mean instants, all in days
+    let (A, B, to_first, to_second, max_dev) = match *planet {
+        Planet::Mercury => (2451612.023, 115.8774771, -11.11, 11.12, 12.0),
+        Planet::Venus => (2451996.706, 583.921361, -21.09, 21.09, 6.0),
+        Planet::Mars => (2452097.382, 779.936104, -38.93, 35.51, 40.0),
+        Planet::Jupiter => (2451870.628, 398.884046, -60.54, 60.34, 15.0),
+        Planet::Saturn => (2451870.17, 378.091904, -69.47, 68.77, 15.0),
+        Planet::Uranus => (2451764.317, 369.656035, -76.43, 76.64, 15.0),
+        Planet::Neptune => (2451753.122, 367.486703, -79.93, 79.7, 10.0),
+        Planet::Earth => {
+            return Err("Planet::Earth was passed to the function planet::stationary_point()")
+        }
+    };
+    let (first, A) = match *which {
+        Stationary::First => (true, A + to_first),
+        Stationary::Second => (false, A + to_second),
+    };
+
+    let k = ((after_JD - max_dev - A) / B).ceil();
+
+    for n in 0..3 {
+        let JD_mean = A + (k + n as f64) * B;
+
+        let mut JD1 = after_JD.max(JD_mean - max_dev);
+        let mut r1 = long_rate(planet, JD1);
+        while JD1 < JD_mean + max_dev {
+            let JD2 = JD1 + 1.0;
+            let r2 = long_rate(planet, JD2);
+
+            if r1 * r2 <= 0.0 && r1 != r2 && (r1 > r2) == first {
+                let JD = interpol::refine_zero(&|JD| long_rate(planet, JD), JD1, JD2, r1, r2, 1e-6);
+
+                if JD > after_JD + 1e-3 {
+                    return Ok(JD);
+                }
+            }
+
+            JD1 = JD2;
+            r1 = r2;
+        }
+    }
+
+    Err("No stationary point was found by the function planet::stationary_point()")
+}
+
+// Computes the change of a planet's geocentric apparent longitude over
+// a day centered on a Julian day, in radians
+fn long_rate(planet: &Planet, JD: f64) -> f64 {
+    let (ecl_point1, _) = geocent_apprnt_ecl_coords(planet, JD - 0.5);
+    let (ecl_point2, _) = geocent_apprnt_ecl_coords(planet, JD + 0.5);
+
+    angle::limit_to_pm_PI(ecl_point2.long - ecl_point1.long)
+}
+
+/**
+Computes the instant at which a planet is brightest in a year
+
+The apparent magnitude of
+[apparent_magnitude()](./fn.apparent_magnitude.html) is computed for
+each day of the year, and its least value is refined by interpolating
+a parabola through it and the values of the days around it. For the
+outer planets, this is near opposition, and for Venus, near its
+greatest brilliancy. If the planet is brightening at the end of the
+year, the last day of the year is returned.
+
+# Returns
+
+`(JD, mag)`
+
+* `JD` : Julian (Ephemeris) day at which the planet is brightest
+* `mag`: Apparent magnitude of the planet on `JD`
+
+# Arguments
+
+* `planet`: Any [Planet](./enum.Planet.html) other than `Planet::Earth`
+* `year`  : The year
+**/
+pub fn brightest_date<'a>(planet: &Planet, year: i32) -> Result<(f64, f64), &'a str> {
+    if let Planet::Earth = *planet {
+        return Err("Planet::Earth was passed to the function planet::brightest_date()");
+    }
+
+    let JD_start = time::julian_day(&time::Date {
+        year: year as i16,
+        month: time::Month::Jan,
+        decimal_day: 1.0,
+        cal_type: time::CalType::Gregorian,
+    });
+    let n_days = if time::is_leap_year(year as i16, &time::CalType::Gregorian) {
+        366
+    } else {
+        365
+    };
+
+    let mut mags = Vec::with_capacity(n_days);
+    for i in 0..n_days {
+        mags.push(apparent_magnitude(planet, JD_start + (i as f64))?);
+    }
+
+    let mut i_min = 0;
+    for (i, mag) in mags.iter().enumerate() {
+        if *mag < mags[i_min] {
+            i_min = i;
+        }
+    }
+
+    let mut JD = JD_start + (i_min as f64);
+    if i_min > 0 && i_min < n_days - 1 {
+        let a = mags[i_min] - mags[i_min - 1];
+        let b = mags[i_min + 1] - mags[i_min];
+        let c = b - a;
+        if c > 0.0 {
+            JD -= (a + b) / (2.0 * c);
+        }
+    }
+
+    Ok((JD, apparent_magnitude(planet, JD)?))
+}
+
+/**
+Computes the apparent path of a planet across the sky, as a polyline
+
+The positions are computed at equal steps of time, and aren't
+subdivided around the stationary points of the planet, where the
+path turns sharply. The right ascension (or longitude) is unwrapped,
+ie, the first point lies in the range [0, 2π), and a multiple of 2π is
+added to each later point so that it differs from the previous point
+by less than π. The path can so be drawn without a seam, but later
+values of `asc` may lie outside the range [0, 2π).
+
+# Returns
+
+* `path`: Positions of the planet *| in radians*. For
+          `Frame::MeanEclOfDate` and `Frame::Galactic`, `asc` holds
+          the longitude, and `dec` the latitude.
+
+# Arguments
+
+* `planet`   : The `Planet`. `Planet::Earth` can't be passed.
+* `JD_start` : Julian (Ephemeris) day of the first position
+* `step_days`: Interval between positions *| in days*
+* `count`    : Number of positions
+* `frame`    : The [Frame](../coords/enum.Frame.html) of the positions
+**/
+pub fn path<'a>(
+    planet: &Planet,
+    JD_start: f64,
+    step_days: f64,
+    count: usize,
+    frame: &coords::Frame,
+) -> Result<Vec<coords::EqPoint>, &'a str> {
+    if let Planet::Earth = *planet {
+        return Err("Planet::Earth was passed to the function planet::path()");
+    }
+
+    let mut path: Vec<coords::EqPoint> = Vec::with_capacity(count);
+    for i in 0..count {
+        let JD = JD_start + (i as f64) * step_days;
+        let (planet_ecl_point, _) = geocent_apprnt_ecl_coords(planet, JD);
+        let mut point = ephem::ecl_point_to_frame(&planet_ecl_point, JD, frame);
+
+        point.asc = match path.last() {
+            Some(prev) => {
+                point.asc + ((prev.asc - point.asc) / angle::TWO_PI).round() * angle::TWO_PI
+            }
+            None => angle::limit_to_two_PI(point.asc),
+        };
+        path.push(point);
+    }
+
+    Ok(path)
+}
+
+#[inline]
+fn sun_planet_earth_dists(planet: &Planet, JD: f64) -> (f64, f64, f64) {
+    let (L0, B0, R0) = heliocent_coords(&Planet::Earth, JD);
+    let (L, B, R) = heliocent_coords(planet, JD);
+    let (_, _, _, t) = geocent_geomet_ecl_coords(L0, B0, R0, L, B, R);
+
+    let (L, B, r) = heliocent_coords(planet, JD - t);
+    let (_, _, planet_earth_dist, _) = geocent_geomet_ecl_coords(L0, B0, R0, L, B, r);
+
+    (r, planet_earth_dist, R0)
+}
+
+#[inline]
+fn cos_phase_angl(planet: &Planet, JD: f64) -> f64 {
+    let (r, delta, R) = sun_planet_earth_dists(planet, JD);
+
+    phase_angl(r, delta, R)
+}
+
 /**
 Computes a planet's apparent magnitude using G. Muller's formulae
 
@@ -660,3 +2061,46 @@ pub fn apprnt_mag_84<'a>(planet: &Planet, i: f64, delta: f64, r: f64) -> Result<
         Planet::Neptune => Ok(x - 6.87),
     }
 }
+
+/**
+Computes a planet's apparent magnitude on a given day
+
+The Astronomical Almanac's formulae adopted in 1984 are used, with
+the distances and the phase angle found as in
+[phase_angle()](./fn.phase_angle.html). For Saturn, the tilt of the
+rings and the difference between the Saturnicentric longitudes of the
+Sun and the Earth are taken from
+[saturn::ring::elements()](./saturn/ring/fn.elements.html).
+
+# Returns
+
+* `app_mag`: Apparent magnitude of the planet
+
+# Arguments
+
+* `planet`: Any variant of [Planet](./enum.Planet.html) other than
+            `Planet::Earth`
+* `JD`    : Julian (Ephemeris) day
+**/
+pub fn apparent_magnitude<'a>(planet: &Planet, JD: f64) -> Result<f64, &'a str> {
+    if let Planet::Earth = *planet {
+        return Err("Planet::Earth was passed to the function planet::apparent_magnitude()");
+    }
+
+    let (r, delta, R) = sun_planet_earth_dists(planet, JD);
+
+    match *planet {
+        Planet::Saturn => {
+            let (nut_in_long, nut_in_oblq) = nutation::nutation(JD);
+            let true_oblq = ecliptic::mn_oblq_laskar(JD) + nut_in_oblq;
+            let ring = saturn::ring::elements(JD, nut_in_long, true_oblq);
+
+            Ok(saturn::apprnt_mag_84(delta, r, ring.deltaU.to_degrees(), ring.B))
+        }
+        _ => {
+            let i = phase_angl(r, delta, R).acos().to_degrees();
+
+            apprnt_mag_84(planet, i, delta, r)
+        }
+    }
+}
@@ -23,6 +23,11 @@ THE SOFTWARE.
 //! Saturn
 
 use angle;
+use coords;
+use ecliptic;
+use nutation;
+use planet;
+use star;
 
 pub mod moon;
 pub mod ring;
@@ -115,3 +120,133 @@ Computes Saturn's equatorial semidiameter
 pub fn eq_semidiameter(saturn_earth_dist: f64) -> f64 {
     equatorial_unit_semidiameter() / saturn_earth_dist
 }
+
+/// Holds the apparent geometry of Saturn's rings
+#[derive(Debug)]
+pub struct RingElements {
+    /// Saturnicentric latitude of the Earth, referred to the plane
+    /// of the ring. Positive when the northern face is seen.
+    pub B: f64,
+    /// Saturnicentric latitude of the Sun, referred to the plane of
+    /// the ring
+    pub B_prime: f64,
+    /// Geocentric position angle of the northern semiminor axis of
+    /// the apparent ellipse of the ring
+    pub P: f64,
+    /// Major axis of the outer edge of the outer ring *| in arcseconds*
+    pub major_axis: f64,
+    /// Minor axis of the outer edge of the outer ring *| in arcseconds*
+    pub minor_axis: f64,
+}
+
+/**
+Computes the apparent geometry of Saturn's rings
+
+This is a convenience over [ring::elements()](./ring/fn.elements.html),
+with nutation and the obliquity of the ecliptic computed internally.
+
+Near a ring-plane crossing, `B` passes through zero and changes sign;
+the rings are then seen edge-on, and the minor axis vanishes. When `B`
+and `B_prime` have opposite signs, the Earth sees the unlit face of
+the rings.
+
+# Returns
+
+* `ring_elements`: Geometry of the rings. *Angles are in radians,
+                   and axes in arcseconds*
+
+# Arguments
+
+* `JD`: Julian (Ephemeris) day
+**/
+pub fn ring_elements(JD: f64) -> RingElements {
+    let (nut_in_long, nut_in_oblq) = nutation::nutation(JD);
+    let true_oblq = ecliptic::mn_oblq_laskar(JD) + nut_in_oblq;
+
+    let elements = ring::elements(JD, nut_in_long, true_oblq);
+
+    RingElements {
+        B: elements.B,
+        B_prime: elements.B1,
+        P: elements.P,
+        major_axis: elements.a.to_degrees() * 3600.0,
+        minor_axis: elements.b.to_degrees() * 3600.0,
+    }
+}
+
+/**
+Computes the apparent positions of the ansae of Saturn's rings
+
+The ansae are the eastern and western tips of the major axis of the
+outer edge of the outer ring. They lie half the
+[major axis](./struct.RingElements.html) away from Saturn's
+[apparent place](../fn.apparent_place.html), at position angles 90
+degrees greater and smaller than that of the northern semiminor
+axis.
+
+# Returns
+
+`(east_ansa, west_ansa)`
+
+* `east_ansa`: Apparent equatorial point of the eastern ansa *| in radians*
+* `west_ansa`: Apparent equatorial point of the western ansa *| in radians*
+
+# Arguments
+
+* `JD`: Julian (Ephemeris) day
+**/
+pub fn ring_ansae(JD: f64) -> (coords::EqPoint, coords::EqPoint) {
+    let centre = planet::apparent_place(&planet::Planet::Saturn, JD);
+    let ring = ring_elements(JD);
+    let dist = angle::deg_frm_dms(0, 0, ring.major_axis / 2.0).to_radians();
+    let quarter = 90_f64.to_radians();
+
+    (
+        offset_point(&centre, dist, ring.P + quarter),
+        offset_point(&centre, dist, ring.P - quarter),
+    )
+}
+
+// Computes the point at an angular distance and position angle from
+// another point on the sky
+fn offset_point(eq_point: &coords::EqPoint, dist: f64, pos_angl: f64) -> coords::EqPoint {
+    let (sin_dec, cos_dec) = eq_point.dec.sin_cos();
+    let (sin_dist, cos_dist) = dist.sin_cos();
+
+    let dec = (sin_dec * cos_dist + cos_dec * sin_dist * pos_angl.cos()).asin();
+    let d_asc = (pos_angl.sin() * sin_dist * cos_dec).atan2(cos_dist - sin_dec * dec.sin());
+
+    coords::EqPoint {
+        asc: angle::limit_to_two_PI(eq_point.asc + d_asc),
+        dec,
+    }
+}
+
+/**
+Computes the combined apparent magnitude of Saturn and some of its
+moons
+
+Saturn's magnitude is computed with
+[apprnt_mag_84()](./fn.apprnt_mag_84.html), and that of each moon
+with [moon::apparent_magnitude()](./moon/fn.apparent_magnitude.html).
+
+# Returns
+
+* `app_mag`: Combined apparent magnitude
+
+# Arguments
+
+* `JD`   : Julian (Ephemeris) day
+* `moons`: The moons whose light is to be added to Saturn's
+**/
+pub fn combined_magnitude_with_moons(JD: f64, moons: &[moon::Moon]) -> f64 {
+    let (r, delta, _) = planet::sun_planet_earth_dists(&planet::Planet::Saturn, JD);
+    let (nut_in_long, nut_in_oblq) = nutation::nutation(JD);
+    let true_oblq = ecliptic::mn_oblq_laskar(JD) + nut_in_oblq;
+    let ring = ring::elements(JD, nut_in_long, true_oblq);
+
+    let mut mags = vec![apprnt_mag_84(delta, r, ring.deltaU.to_degrees(), ring.B)];
+    mags.extend(moons.iter().map(|m| moon::apparent_magnitude(JD, m)));
+
+    star::combined_mag_of_many(&mags)
+}
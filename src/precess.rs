@@ -23,6 +23,7 @@ THE SOFTWARE.
 //! Corrections for precession
 
 use angle;
+use coords;
 use std;
 use time;
 
@@ -67,38 +68,39 @@ pub fn annual_precess(asc: f64, dec: f64, JD: f64) -> (f64, f64) {
 }
 
 /**
-Computes equatorial coordinates reduced to a different epoch
+Computes the angles for reducing equatorial coordinates to a
+different epoch
+
+The angles are those of the IAU 1976 precession, as used by
+[precess_eq_coords()](./fn.precess_eq_coords.html).
 
 # Returns
 
-`(new_asc, new_dec)`
+`(zeta, z, theta)`
 
-* `new_asc`: Right ascension in the new epoch *| in radians*
-* `new_dec`: Declination in the new epoch *| in radians*
+* `zeta` : First rotation about the pole of the old epoch *| in radians*
+* `z`    : Last rotation about the pole of the new epoch *| in radians*
+* `theta`: Rotation between the two poles *| in radians*
 
 # Arguments
 
-* `old_asc`: Right ascension in the old epoch *| in radians*,
-             referred to the FK5 system
-* `old_dec`: Declination in the old epoch *| in radians*,
-             referred to the FK5 system
-* `JD1`    : Julian (Ephemeris) day corresponding to the old epoch
-* `JD2`    : Julian (Ephemeris) day corresponding to the new epoch
+* `JD1`: Julian (Ephemeris) day corresponding to the old epoch
+* `JD2`: Julian (Ephemeris) day corresponding to the new epoch
 **/
-pub fn precess_eq_coords(old_asc: f64, old_dec: f64, JD1: f64, JD2: f64) -> (f64, f64) {
+pub fn eq_precess_angls(JD1: f64, JD2: f64) -> (f64, f64, f64) {
     let T = time::julian_cent(JD1);
     let t = (JD2 - JD1) / 36525.0;
 
     let x = t
         * (angle::deg_frm_dms(0, 0, 2306.2181)
             + T * (angle::deg_frm_dms(0, 0, 1.39656) - T * angle::deg_frm_dms(0, 0, 0.000139)));
-    let xi = (x
+    let zeta = (x
         + t * t
             * ((angle::deg_frm_dms(0, 0, 0.30188) - T * angle::deg_frm_dms(0, 0, 0.000344))
                 + t * angle::deg_frm_dms(0, 0, 0.017998)))
         .to_radians();
 
-    let zeta = (x
+    let z = (x
         + t * t
             * ((angle::deg_frm_dms(0, 0, 1.09468) - T * angle::deg_frm_dms(0, 0, 0.000066))
                 + t * angle::deg_frm_dms(0, 0, 0.018203)))
@@ -112,13 +114,136 @@ pub fn precess_eq_coords(old_asc: f64, old_dec: f64, JD1: f64, JD2: f64) -> (f64
                     + t * angle::deg_frm_dms(0, 0, 0.041833))))
         .to_radians();
 
-    let A = old_dec.cos() * (old_asc + xi).sin();
+    (zeta, z, theta)
+}
 
-    let B = theta.cos() * old_dec.cos() * (old_asc + xi).cos() - theta.sin() * old_dec.sin();
+/**
+Computes equatorial coordinates reduced to a different epoch
 
-    let C = theta.sin() * old_dec.cos() * (old_asc + xi).cos() + theta.cos() * old_dec.sin();
+# Returns
 
-    (A.atan2(B) + zeta, C.asin())
+`(new_asc, new_dec)`
+
+* `new_asc`: Right ascension in the new epoch *| in radians*
+* `new_dec`: Declination in the new epoch *| in radians*
+
+# Arguments
+
+* `old_asc`: Right ascension in the old epoch *| in radians*,
+             referred to the FK5 system
+* `old_dec`: Declination in the old epoch *| in radians*,
+             referred to the FK5 system
+* `JD1`    : Julian (Ephemeris) day corresponding to the old epoch
+* `JD2`    : Julian (Ephemeris) day corresponding to the new epoch
+**/
+pub fn precess_eq_coords(old_asc: f64, old_dec: f64, JD1: f64, JD2: f64) -> (f64, f64) {
+    let (zeta, z, theta) = eq_precess_angls(JD1, JD2);
+
+    let A = old_dec.cos() * (old_asc + zeta).sin();
+
+    let B = theta.cos() * old_dec.cos() * (old_asc + zeta).cos() - theta.sin() * old_dec.sin();
+
+    let C = theta.sin() * old_dec.cos() * (old_asc + zeta).cos() + theta.cos() * old_dec.sin();
+
+    (A.atan2(B) + z, C.asin())
+}
+
+/**
+Computes equatorial coordinates reduced to a different epoch, with the
+IAU 2006 precession model
+
+This is the same as [precess_eq_coords()](./fn.precess_eq_coords.html),
+but uses the P03 precession angles of Capitaine et al. (2003), adopted
+by the IAU in 2006, instead of those of the IAU 1976 model. The two
+agree at J2000.0, and differ by a fraction of an arcsecond over a
+century. The coordinates are reduced from the old epoch to J2000.0,
+and then to the new epoch.
+
+# Returns
+
+`(new_asc, new_dec)`
+
+* `new_asc`: Right ascension in the new epoch *| in radians*
+* `new_dec`: Declination in the new epoch *| in radians*
+
+# Arguments
+
+* `old_asc`: Right ascension in the old epoch *| in radians*
+* `old_dec`: Declination in the old epoch *| in radians*
+* `JD1`    : Julian (Ephemeris) day corresponding to the old epoch
+* `JD2`    : Julian (Ephemeris) day corresponding to the new epoch
+**/
+pub fn precess_eq_coords_2006(old_asc: f64, old_dec: f64, JD1: f64, JD2: f64) -> (f64, f64) {
+    let to_J2000 = p03_matrix(JD1);
+    let frm_J2000 = p03_matrix(JD2);
+    let to_J2000 = [
+        [to_J2000[0][0], to_J2000[1][0], to_J2000[2][0]],
+        [to_J2000[0][1], to_J2000[1][1], to_J2000[2][1]],
+        [to_J2000[0][2], to_J2000[1][2], to_J2000[2][2]],
+    ];
+
+    let vector = [
+        old_dec.cos() * old_asc.cos(),
+        old_dec.cos() * old_asc.sin(),
+        old_dec.sin(),
+    ];
+    let v = coords::apply_matrix(&mat_mul(&frm_J2000, &to_J2000), &vector);
+
+    (
+        angle::limit_to_two_PI(v[1].atan2(v[0])),
+        v[2].atan2((v[0] * v[0] + v[1] * v[1]).sqrt()),
+    )
+}
+
+// Computes the IAU 2006 (P03) precession matrix from J2000.0 to the
+// mean equator and equinox of a date
+fn p03_matrix(JD: f64) -> [[f64; 3]; 3] {
+    let t = time::julian_cent(JD);
+
+    let zeta = angle::deg_frm_dms(
+        0,
+        0,
+        2.650545
+            + t * (2306.083227
+                + t * (0.2988499 + t * (0.01801828 - t * (0.000005971 + t * 0.0000003173)))),
+    ).to_radians();
+    let z = angle::deg_frm_dms(
+        0,
+        0,
+        -2.650545
+            + t * (2306.077181
+                + t * (1.0927348 + t * (0.01826837 - t * (0.000028596 + t * 0.0000002904)))),
+    ).to_radians();
+    let theta = angle::deg_frm_dms(
+        0,
+        0,
+        t * (2004.191903
+            - t * (0.4294934 + t * (0.04182264 + t * (0.000007089 + t * 0.0000001274)))),
+    ).to_radians();
+
+    eq_rotation_matrix(zeta, z, theta)
+}
+
+// Computes the rotation matrix of equatorial rectangular coordinates
+// for the precession angles ζ, z and θ
+fn eq_rotation_matrix(zeta: f64, z: f64, theta: f64) -> [[f64; 3]; 3] {
+    let (s_zeta, c_zeta) = zeta.sin_cos();
+    let (s_z, c_z) = z.sin_cos();
+    let (s_theta, c_theta) = theta.sin_cos();
+
+    [
+        [
+            c_zeta * c_theta * c_z - s_zeta * s_z,
+            -s_zeta * c_theta * c_z - c_zeta * s_z,
+            -s_theta * c_z,
+        ],
+        [
+            c_zeta * c_theta * s_z + s_zeta * c_z,
+            -s_zeta * c_theta * s_z + c_zeta * c_z,
+            -s_theta * s_z,
+        ],
+        [c_zeta * s_theta, -s_zeta * s_theta, c_theta],
+    ]
 }
 
 /**
@@ -199,6 +324,122 @@ pub fn precess_ecl_coords(old_long: f64, old_lat: f64, JD_old: f64, JD_new: f64)
     (new_long, new_lat)
 }
 
+/**
+Computes the matrix that reduces equatorial rectangular coordinates
+to a different epoch
+
+The matrix is built from the IAU 1976 angles of
+[eq_precess_angls()](./fn.eq_precess_angls.html), and so agrees with
+[precess_eq_coords()](./fn.precess_eq_coords.html). When many points
+are reduced between the same two epochs, the matrix can be computed
+once and applied to each with
+[apply_matrix()](./fn.apply_matrix.html), or to rectangular
+coordinates with [coords::apply_matrix()](../coords/fn.apply_matrix.html).
+
+# Returns
+
+* `matrix`: Rotation matrix, as an array of rows
+
+# Arguments
+
+* `JD1`: Julian (Ephemeris) day corresponding to the old epoch
+* `JD2`: Julian (Ephemeris) day corresponding to the new epoch
+**/
+pub fn precession_matrix_eq(JD1: f64, JD2: f64) -> [[f64; 3]; 3] {
+    let (zeta, z, theta) = eq_precess_angls(JD1, JD2);
+
+    eq_rotation_matrix(zeta, z, theta)
+}
+
+/**
+Computes the matrix that reduces ecliptic rectangular coordinates to a
+different epoch
+
+The matrix is built from the same IAU 1976 angles as
+[precess_ecl_coords()](./fn.precess_ecl_coords.html), and agrees
+with it. It acts on rectangular coordinates, to which it can be
+applied with [coords::apply_matrix()](../coords/fn.apply_matrix.html).
+
+# Returns
+
+* `matrix`: Rotation matrix, as an array of rows
+
+# Arguments
+
+* `JD_old`: Julian (Ephemeris) day corresponding to the old epoch
+* `JD_new`: Julian (Ephemeris) day corresponding to the new epoch
+**/
+pub fn precession_matrix_ecl(JD_old: f64, JD_new: f64) -> [[f64; 3]; 3] {
+    let T = time::julian_cent(JD_old);
+    let t = (JD_new - JD_old) / 36525.0;
+
+    let (nu, Pi, rho) = angles_for_ecl_change(t, T);
+    let (s_nu, c_nu) = nu.sin_cos();
+    let (s_Pi, c_Pi) = Pi.sin_cos();
+    let (s_a, c_a) = (rho + Pi).sin_cos();
+
+    let to_node = [[c_Pi, s_Pi, 0.0], [-s_Pi, c_Pi, 0.0], [0.0, 0.0, 1.0]];
+    let tilt = [[1.0, 0.0, 0.0], [0.0, c_nu, s_nu], [0.0, -s_nu, c_nu]];
+    let frm_node = [[c_a, -s_a, 0.0], [s_a, c_a, 0.0], [0.0, 0.0, 1.0]];
+
+    mat_mul(&frm_node, &mat_mul(&tilt, &to_node))
+}
+
+/**
+Applies a precession matrix to an equatorial point
+
+# Returns
+
+* `eq_point`: Equatorial point in the new epoch *| in radians*
+
+# Arguments
+
+* `matrix`  : Rotation matrix, as an array of rows
+* `eq_point`: Equatorial point in the old epoch *| in radians*
+
+The matrix is usually that of
+[precession_matrix_eq()](./fn.precession_matrix_eq.html).
+**/
+pub fn apply_matrix(matrix: &[[f64; 3]; 3], eq_point: &coords::EqPoint) -> coords::EqPoint {
+    let vector = [
+        eq_point.dec.cos() * eq_point.asc.cos(),
+        eq_point.dec.cos() * eq_point.asc.sin(),
+        eq_point.dec.sin(),
+    ];
+    let v = coords::apply_matrix(matrix, &vector);
+
+    coords::EqPoint {
+        asc: angle::limit_to_two_PI(v[1].atan2(v[0])),
+        dec: v[2].atan2((v[0] * v[0] + v[1] * v[1]).sqrt()),
+    }
+}
+
+/**
+Applies a precession matrix to an equatorial point
+
+This is the same as [apply_matrix()](./fn.apply_matrix.html), under a
+name that sets it apart from
+[coords::apply_matrix()](../coords/fn.apply_matrix.html), which
+applies a matrix to a rectangular vector.
+**/
+#[inline]
+pub fn apply_matrix_eq(matrix: &[[f64; 3]; 3], eq_point: &coords::EqPoint) -> coords::EqPoint {
+    apply_matrix(matrix, eq_point)
+}
+
+// Computes the product of two 3x3 matrices
+fn mat_mul(a: &[[f64; 3]; 3], b: &[[f64; 3]; 3]) -> [[f64; 3]; 3] {
+    let mut product = [[0.0; 3]; 3];
+
+    for (i, row) in product.iter_mut().enumerate() {
+        for (j, x) in row.iter_mut().enumerate() {
+            *x = a[i][0] * b[0][j] + a[i][1] * b[1][j] + a[i][2] * b[2][j];
+        }
+    }
+
+    product
+}
+
 #[inline]
 fn angles_for_ecl_change(t: f64, T: f64) -> (f64, f64, f64) {
     let x = T * angle::deg_frm_dms(0, 0, 0.000598);
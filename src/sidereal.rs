@@ -0,0 +1,95 @@
+/*
+Copyright (c) 2015, 2016 Saurav Sachidanand
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+//! Sidereal time
+
+use angle;
+use ecliptic;
+use nutation;
+use time;
+
+/**
+Computes the mean sidereal time at Greenwich
+
+# Returns
+
+* `mn_sidr`: Mean sidereal time at Greenwich *| in radians*
+
+# Arguments
+
+* `JD`: Julian (Universal) day
+**/
+#[inline]
+pub fn mean_sidereal_greenwich(JD: f64) -> f64 {
+    angle::limit_to_two_PI(time::mn_sidr(JD))
+}
+
+/**
+Computes the apparent sidereal time at Greenwich
+
+The mean sidereal time is corrected by the equation of the equinoxes,
+`Δψ cos ε`, where `Δψ` is the [nutation](../nutation/fn.nutation.html)
+in longitude and `ε` the true obliquity of the ecliptic, computed with
+[J. Laskar's formula](../ecliptic/fn.mn_oblq_laskar.html) and the
+nutation in obliquity. The difference between Universal and Ephemeris
+time is neglected in computing the nutation, which changes the result
+by far less than a millisecond.
+
+This is the sidereal time that
+[transit::time()](../transit/fn.time.html) takes.
+
+# Returns
+
+* `apprnt_sidr`: Apparent sidereal time at Greenwich *| in radians*
+
+# Arguments
+
+* `JD`: Julian (Universal) day
+**/
+pub fn apparent_sidereal_greenwich(JD: f64) -> f64 {
+    let (nut_in_long, nut_in_oblq) = nutation::nutation(JD);
+    let true_oblq = ecliptic::mn_oblq_laskar(JD) + nut_in_oblq;
+
+    angle::limit_to_two_PI(time::apprnt_sidr(time::mn_sidr(JD), nut_in_long, true_oblq))
+}
+
+/**
+Computes the local sidereal time from the sidereal time at Greenwich
+
+The local time is mean or apparent, as the time at Greenwich is.
+
+# Returns
+
+* `loc_sidr`: Local sidereal time *| in radians*
+
+# Arguments
+
+* `greenw_sidr`: Sidereal time at Greenwich *| in radians*
+* `long`       : The observer's geographical longitude *| in radians*
+
+`long` is positive west of Greenwich, as in
+[GeographPoint](../coords/struct.GeographPoint.html).
+**/
+#[inline]
+pub fn local_sidereal(greenw_sidr: f64, long: f64) -> f64 {
+    angle::limit_to_two_PI(greenw_sidr - long)
+}
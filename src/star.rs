@@ -22,6 +22,9 @@ THE SOFTWARE.
 
 //! Stars
 
+use angle;
+use coords;
+
 /**
 Computes the combined magnitude of two stars
 
@@ -32,24 +35,37 @@ Computes the combined magnitude of two stars
 **/
 #[inline]
 pub fn combined_mag(m1: f64, m2: f64) -> f64 {
-    m2 - 2.5 * (brightness_ratio(m1, m2) + 1.0)
+    m2 - 2.5 * (brightness_ratio(m1, m2) + 1.0).log10()
 }
 
 /**
 Computes the combined magnitude of two or more stars
 
+The brightnesses are summed relative to the first star, which keeps
+the sum close to unity.
+
+# Returns
+
+* `m`: The combined magnitude. For an empty array, which gives no
+  light, this is `f64::INFINITY`.
+
 # Arguments
 
 * `m`: Array of magnitudes of stars
 **/
 pub fn combined_mag_of_many(m: &[f64]) -> f64 {
+    let m1 = match m.first() {
+        Some(&m1) => m1,
+        None => return f64::INFINITY,
+    };
+
     let mut sum = 0.0;
 
     for i in m.iter() {
-        sum += 10_f64.powf(-0.4 * i);
+        sum += 10_f64.powf(-0.4 * (i - m1));
     }
 
-    -2.5 * sum.log10()
+    m1 - 2.5 * sum.log10()
 }
 
 /**
@@ -80,30 +96,104 @@ pub fn mag_diff(br: f64) -> f64 {
 /**
 Computes the absolute magnitude of a star from its parallax
 
+For a parallax that isn't positive, the magnitude is infinite or
+`NaN`; [absolute_magnitude()](./fn.absolute_magnitude.html) returns
+an error instead.
+
 # Arguments
 
-* `par`: Parallax of the star
-* `am`: Apparent magnitude of the star
+* `par`: Parallax of the star *| in radians*
+* `am` : Apparent magnitude of the star
 **/
 #[inline]
 pub fn abs_mag_frm_parallax(par: f64, am: f64) -> f64 {
     am + 5.0 + 5.0 * (par.to_degrees() * 3600.0).log10()
 }
 
+/**
+Computes the absolute magnitude of a star from its parallax, checking
+the parallax
+
+This is the same as
+[abs_mag_frm_parallax()](./fn.abs_mag_frm_parallax.html), but returns
+an error if the parallax isn't positive.
+
+# Arguments
+
+* `am` : Apparent magnitude of the star
+* `par`: Parallax of the star *| in radians*
+**/
+pub fn absolute_magnitude<'a>(am: f64, par: f64) -> Result<f64, &'a str> {
+    if par > 0.0 {
+        Ok(abs_mag_frm_parallax(par, am))
+    } else {
+        Err("A parallax that isn't positive was passed to the function star::absolute_magnitude()")
+    }
+}
+
 /**
 Computes the absolute magnitude of a star from its distance from earth
 
 # Arguments
 
-* `d`: The star's to earth *(parsecs)*
+* `d` : The star's distance from the Earth *| in parsecs*
 * `am`: Apparent magnitude of the star
 **/
-
 #[inline]
 pub fn abs_mag_frm_dist(d: f64, am: f64) -> f64 {
     am + 5.0 - 5.0 * d.log10()
 }
 
+/**
+Computes the distance of a star from its parallax
+
+# Returns
+
+* `d`: The star's distance from the Earth *| in parsecs*
+
+An error is returned if the parallax isn't positive.
+
+# Arguments
+
+* `par`: Parallax of the star *| in radians*
+**/
+pub fn dist_frm_parallax<'a>(par: f64) -> Result<f64, &'a str> {
+    if par > 0.0 {
+        Ok(1.0 / (par.to_degrees() * 3600.0))
+    } else {
+        Err("A parallax that isn't positive was passed to the function star::dist_frm_parallax()")
+    }
+}
+
+/**
+Computes the distance modulus of a star
+
+# Arguments
+
+* `am`     : Apparent magnitude of the star
+* `abs_mag`: Absolute magnitude of the star
+**/
+#[inline]
+pub fn dist_modulus(am: f64, abs_mag: f64) -> f64 {
+    am - abs_mag
+}
+
+/**
+Computes the distance of a star from its distance modulus
+
+# Returns
+
+* `d`: The star's distance from the Earth *| in parsecs*
+
+# Arguments
+
+* `dist_mod`: Distance modulus of the star
+**/
+#[inline]
+pub fn dist_frm_dist_modulus(dist_mod: f64) -> f64 {
+    10_f64.powf((dist_mod + 5.0) / 5.0)
+}
+
 /**
 Computes the angle between a vector from a star to the
 north celestial pole of the Earth and a vector from the
@@ -190,6 +280,73 @@ pub fn eq_coords_frm_motion(
     (asc, dec)
 }
 
+/**
+Computes the equatorial coordinates of a star at a different epoch,
+taking into account its space motion
+
+The star's position and velocity are propagated as rectangular
+vectors, which accounts for the change of its proper motion caused by
+its radial velocity (perspective acceleration). If `parallax` isn't
+positive, the distance is unknown and the proper motion is applied
+linearly instead.
+
+# Returns
+
+* `eq_point`: Equatorial point of the star at `to_epoch` *| in radians*
+
+# Arguments
+
+* `eq_point`       : Equatorial point at `from_epoch` *| in radians*
+* `pm_asc`         : Annual proper motion in right ascension *| in radians*
+* `pm_dec`         : Annual proper motion in declination *| in radians*
+* `parallax`       : Annual parallax of the star *| in radians*
+* `radial_velocity`: Radial velocity of the star *| in km/s*
+* `from_epoch`     : Julian (Ephemeris) day of `eq_point`
+* `to_epoch`       : Julian (Ephemeris) day to compute the position for
+
+`pm_asc` is the rate of change of right ascension itself, not multiplied
+by cos(dec). `radial_velocity` is positive when the star is receding.
+**/
+pub fn apply_space_motion(
+    eq_point: &coords::EqPoint,
+    pm_asc: f64,
+    pm_dec: f64,
+    parallax: f64,
+    radial_velocity: f64,
+    from_epoch: f64,
+    to_epoch: f64,
+) -> coords::EqPoint {
+    let t = (to_epoch - from_epoch) / 365.25;
+
+    if parallax <= 0.0 {
+        return coords::EqPoint {
+            asc: angle::limit_to_two_PI(eq_point.asc + pm_asc * t),
+            dec: eq_point.dec + pm_dec * t,
+        };
+    }
+
+    let (sin_asc, cos_asc) = eq_point.asc.sin_cos();
+    let (sin_dec, cos_dec) = eq_point.dec.sin_cos();
+
+    // Distance in AU, and radial velocity in AU per year
+    let r = 1.0 / parallax;
+    let v_r = radial_velocity * 86400.0 * 365.25 / 149597870.7;
+
+    let v_asc = r * pm_asc * cos_dec;
+    let v_dec = r * pm_dec;
+
+    let x = r * cos_dec * cos_asc
+        + t * (v_r * cos_dec * cos_asc - v_asc * sin_asc - v_dec * sin_dec * cos_asc);
+    let y = r * cos_dec * sin_asc
+        + t * (v_r * cos_dec * sin_asc + v_asc * cos_asc - v_dec * sin_dec * sin_asc);
+    let z = r * sin_dec + t * (v_r * sin_dec + v_dec * cos_dec);
+
+    coords::EqPoint {
+        asc: angle::limit_to_two_PI(y.atan2(x)),
+        dec: z.atan2((x * x + y * y).sqrt()),
+    }
+}
+
 pub fn proper_motion_in_eq_coords(
     asc: f64,
     dec: f64,
@@ -22,11 +22,17 @@ THE SOFTWARE.
 
 //! The Sun
 
+use aberr;
 use angle;
 use time;
 use std;
 use planet;
+use precess;
 use coords;
+use ecliptic;
+use nutation;
+use sidereal;
+use transit;
 
 /**
 Computes the Sun's equatorial semidiameter
@@ -40,6 +46,23 @@ pub fn semidiameter(sun_earth_dist: f64) -> f64 {
     angle::deg_frm_dms(0, 0, 959.63) / sun_earth_dist
 }
 
+/**
+Computes the Sun's apparent diameter on a given day
+
+# Returns
+
+* `diameter`: Apparent diameter of the Sun *| in radians*
+
+# Arguments
+
+* `JD`: Julian (Ephemeris) day
+**/
+pub fn apparent_diameter(JD: f64) -> f64 {
+    let (_, sun_earth_dist) = geocent_ecl_pos(JD);
+
+    2.0 * semidiameter(sun_earth_dist).to_radians()
+}
+
 /**
 Computes the Sun's geocentric ecliptic position, referred to the mean
 equinox of the date
@@ -148,6 +171,56 @@ pub fn geocent_rect_coords(
     (x, y, z)
 }
 
+/**
+Computes the Sun's geocentric equatorial rectangular coordinates,
+referred to the mean equinox of the date
+
+The coordinates are built from the Sun's geometric longitude and
+latitude, converted to the FK5 system, and the mean obliquity of the
+ecliptic.
+
+# Returns
+
+`(x, y, z)`
+
+* `x`: The X coordinate *| in AU*
+* `y`: The Y coordinate *| in AU*
+* `z`: The Z coordinate *| in AU*
+
+# Arguments
+
+* `JD`: Julian (Ephemeris) day
+**/
+pub fn geocent_rect_coords_of_date(JD: f64) -> (f64, f64, f64) {
+    let (sun_ecl_point, sun_earth_dist) = geocent_ecl_pos(JD);
+    let (long, lat) = ecl_coords_to_FK5(JD, sun_ecl_point.long, sun_ecl_point.lat);
+
+    geocent_rect_coords(long, lat, sun_earth_dist, ecliptic::mn_oblq_laskar(JD))
+}
+
+/**
+Computes the Sun's geocentric equatorial rectangular coordinates,
+referred to the standard equinox of J2000.0
+
+# Returns
+
+`(x, y, z)`
+
+* `x`: The X coordinate *| in AU*
+* `y`: The Y coordinate *| in AU*
+* `z`: The Z coordinate *| in AU*
+
+# Arguments
+
+* `JD`: Julian (Ephemeris) day
+**/
+pub fn geocent_rect_coords_J2000(JD: f64) -> (f64, f64, f64) {
+    let (x, y, z) = geocent_rect_coords_of_date(JD);
+    let rect = coords::apply_matrix(&precess::precession_matrix_eq(JD, 2451545.0), &[x, y, z]);
+
+    (rect[0], rect[1], rect[2])
+}
+
 /**
 Return quantites used in the ephemeris for physical observations of
 the Sun
@@ -203,6 +276,142 @@ pub fn ephemeris(
     (P, B_0, L_0)
 }
 
+/**
+Computes the position angle of the Sun's axis of rotation
+
+This is the `P` of the [ephemeris](./fn.ephemeris.html), with the
+Sun's apparent longitude and the true obliquity of the ecliptic
+computed internally. It swings between about -26° and +26° over the
+year, and is useful for orienting a sketch of the solar disk.
+
+# Returns
+
+* `P`: Position angle of the axis of rotation *| in radians*
+
+`P` is the position angle of the northern extremity of the axis,
+measured eastwards from the North point of the solar disk.
+
+# Arguments
+
+* `JD`: Julian (Ephemeris) day
+**/
+pub fn axis_position_angle(JD: f64) -> f64 {
+    let (_, _, P) = heliographic_disk_center(JD);
+
+    P
+}
+
+/**
+Computes the heliographic coordinates of the center of the solar disk,
+and the position angle of the Sun's axis of rotation
+
+These are the quantities of the [ephemeris](./fn.ephemeris.html), with
+the Sun's apparent longitude and the true obliquity of the ecliptic
+computed internally.
+
+# Returns
+
+`(B0, L0, P)`
+
+* `B0`: Heliographic latitude of the center of the solar
+  disk *| in radians*
+* `L0`: Heliographic longitude of the center of the solar
+  disk *| in radians*
+* `P` : Position angle of the northern extremity of the axis of
+  rotation, measured eastwards from the North point of the solar
+  disk *| in radians*
+
+`L0` decreases by 360° over each synodic rotation, and is kept in the
+range [0, 2π), so it jumps from 0 to 2π as each new
+[Carrington rotation](./fn.carrington_rotation.html) begins.
+
+# Arguments
+
+* `JD`: Julian (Ephemeris) day
+**/
+pub fn heliographic_disk_center(JD: f64) -> (f64, f64, f64) {
+    let (apprnt_long, _, _) = apprnt_ecl_pos(JD);
+
+    let (nut_in_long, nut_in_oblq) = nutation::nutation(JD);
+    let true_oblq = ecliptic::mn_oblq_laskar(JD) + nut_in_oblq;
+
+    let (P, B0, L0) = ephemeris(JD, apprnt_long - nut_in_long, apprnt_long, true_oblq);
+
+    (B0, L0, P)
+}
+
+/**
+Computes the Carrington rotation number, with its fraction
+
+Rotation number 1 began on 1853 November 9. Each rotation begins
+when the heliographic longitude `L0` of the center of the solar disk
+passes through 0°, so the fraction of the rotation elapsed is
+`1 - L0/360°`, and the integer part is the number of the rotation
+under way, that began at [synodic_rot()](./fn.synodic_rot.html).
+
+# Returns
+
+* `rot`: Carrington rotation number, with its fraction
+
+# Arguments
+
+* `JD`: Julian (Ephemeris) day
+**/
+pub fn carrington_rotation(JD: f64) -> f64 {
+    let (_, L0, _) = heliographic_disk_center(JD);
+
+    // The mean rotation number, within a few hundredths of a rotation
+    // of the true one, settles the integer part near the wrap of L0
+    let mn_rot = (JD - 2398140.227) / 27.2752316;
+    let rot = mn_rot.floor() + 1.0 - L0 / angle::TWO_PI;
+
+    rot + (mn_rot - rot).round()
+}
+
+/**
+Computes the heliographic coordinates of a sunspot from its position
+measured on the solar disk
+
+# Returns
+
+`(lat, long)`
+
+* `lat` : Heliographic latitude of the sunspot *| in radians*
+* `long`: Heliographic longitude of the sunspot *| in radians*
+
+# Arguments
+
+* `JD`          : Julian (Ephemeris) day
+* `measured_rho`: Angular distance of the sunspot from the center of
+  the solar disk *| in radians*
+* `measured_pa` : Position angle of the sunspot, measured eastwards
+  from the North point of the solar disk *| in radians*
+
+A `measured_rho` beyond the Sun's semidiameter, as from seeing at the
+limb, is taken as on the limb.
+
+Near the limb the sunspot is seen foreshortened, and an error in
+`measured_rho` grows by `1/√(1 - (ρ/R)²)` in the heliographic
+coordinates, where `ρ/R` is the fraction of the semidiameter the
+sunspot is from the center; it's about 3 times larger at 95% of the
+way to the limb than at the center, and 7 times at 99%.
+**/
+pub fn sunspot_heliographic(JD: f64, measured_rho: f64, measured_pa: f64) -> (f64, f64) {
+    let (B0, L0, P) = heliographic_disk_center(JD);
+    let semidia = apparent_diameter(JD) / 2.0;
+
+    // Heliocentric angle between the sunspot and the center of the disk
+    let measured_rho = measured_rho.min(semidia);
+    let rho = (measured_rho.sin() / semidia.sin()).asin() - measured_rho;
+
+    let lat = (B0.sin() * rho.cos() + B0.cos() * rho.sin() * (P - measured_pa).cos()).asin();
+    let long = L0
+        + (rho.sin() * (P - measured_pa).sin())
+            .atan2(B0.cos() * rho.cos() - B0.sin() * rho.sin() * (P - measured_pa).cos());
+
+    (lat, angle::limit_to_two_PI(long))
+}
+
 #[inline]
 fn magnitude_limited_to_less_than_PI(a: f64) -> f64 {
     let PI_INTO_THREE_BY_TWO = std::f64::consts::PI * 3.0 / 2.0;
@@ -236,3 +445,561 @@ pub fn synodic_rot(C: i64) -> f64 {
     2398140.227 + 27.2752316 * (C as f64) + 0.1454 * M.sin() - 0.0085 * (2.0 * M).sin()
         - 0.0141 * (2.0 * M).cos()
 }
+
+/**
+Computes the equation of time
+
+The equation of time is the difference between apparent and mean
+solar time. It's reduced to the interval (-180, 180] degrees, so that
+it stays within about 16 minutes of time even when the Sun's right
+ascension and mean longitude lie on either side of 0h.
+
+# Returns
+
+* `eq_of_time`: Equation of time *| in radians*. Multiply it's value
+                in degrees by 4 to get minutes of time.
+
+# Arguments
+
+* `JD`: Julian (Ephemeris) day
+**/
+pub fn equation_of_time(JD: f64) -> f64 {
+    let t = time::julian_cent(JD) / 10.0;
+    let L0 = 280.4664567
+        + t * (360007.6982779
+            + t * (0.03032028 + t * (1.0 / 49931.0 - t * (1.0 / 15300.0 + t / 2000000.0))));
+
+    let (sun_eq_point, _) = apprnt_eq_pos(JD);
+    let (nut_in_long, nut_in_oblq) = nutation::nutation(JD);
+    let true_oblq = ecliptic::mn_oblq_laskar(JD) + nut_in_oblq;
+
+    let E = L0.to_radians() - 0.0057183_f64.to_radians() - sun_eq_point.asc
+        + nut_in_long * true_oblq.cos();
+
+    angle::limit_to_pm_PI(E)
+}
+
+/**
+Computes the rates of change of the Sun's apparent right ascension and
+declination
+
+The rates are found by central differences of the apparent equatorial
+coordinates over an hour. Near the solstices, the rate in declination
+vanishes while that in right ascension is greatest.
+
+# Returns
+
+`(asc_rate, dec_rate)`
+
+* `asc_rate`: Rate of change of the right ascension *| in radians per day*
+* `dec_rate`: Rate of change of the declination *| in radians per day*
+
+# Arguments
+
+* `JD`: Julian (Ephemeris) day
+**/
+pub fn eq_coords_rate(JD: f64) -> (f64, f64) {
+    let h = 1.0 / 48.0;
+    let (sun_eq_point1, _) = apprnt_eq_pos(JD - h);
+    let (sun_eq_point2, _) = apprnt_eq_pos(JD + h);
+
+    let d_asc = angle::limit_to_pm_PI(sun_eq_point2.asc - sun_eq_point1.asc);
+
+    (d_asc / (2.0 * h), (sun_eq_point2.dec - sun_eq_point1.dec) / (2.0 * h))
+}
+
+/**
+Computes the azimuths of the Sun at sunrise and sunset
+
+The Sun's declination is taken at the time of each event, rather than
+once for the day, so the two azimuths are not exactly symmetric about
+the meridian; the asymmetry is largest near the equinoxes, when the
+declination changes fastest.
+
+# Returns
+
+`(sunrise_az, sunset_az)`
+
+* `sunrise_az`: Azimuth of the Sun at sunrise, measured eastwards from
+                the North *| in radians*
+* `sunset_az` : Azimuth of the Sun at sunset, measured eastwards from
+                the North *| in radians*
+
+Each is `None` if the Sun doesn't rise or set on the day.
+
+# Arguments
+
+* `date`          : The day of interest. Its decimal day is truncated
+                    to 0h UT.
+* `geograph_point`: Geographic point of the observer *| in radians*
+**/
+pub fn sunrise_sunset_azimuths(
+    date: &time::Date,
+    geograph_point: &coords::GeographPoint,
+) -> (Option<f64>, Option<f64>) {
+    let JD = time::julian_day(&time::Date {
+        year: date.year,
+        month: date.month,
+        decimal_day: date.decimal_day.floor(),
+        cal_type: date.cal_type,
+    });
+    let delta_t = time::delta_t(date.year as i32, date.month as u8);
+    let JDE = JD + delta_t / 86400.0;
+
+    let (sun_eq_point1, _) = apprnt_eq_pos(JDE - 1.0);
+    let (sun_eq_point2, _) = apprnt_eq_pos(JDE);
+    let (sun_eq_point3, _) = apprnt_eq_pos(JDE + 1.0);
+
+    let apprnt_greenwhich_sidr = sidereal::apparent_sidereal_greenwich(JD);
+
+    let az = |transit_type: &transit::TransitType| {
+        let (h, m, s) = transit::time(
+            transit_type,
+            &transit::TransitBody::Sun,
+            geograph_point,
+            &sun_eq_point1,
+            &sun_eq_point2,
+            &sun_eq_point3,
+            apprnt_greenwhich_sidr,
+            delta_t,
+            0.0,
+        );
+        let JD_event = JD + ((h as f64) + (m as f64) / 60.0 + s / 3600.0) / 24.0;
+        let (sun_eq_point, _) = apprnt_eq_pos(JD_event + delta_t / 86400.0);
+
+        transit::rise_set_azimuth(
+            &transit::TransitBody::Sun,
+            geograph_point,
+            &sun_eq_point,
+            0.0,
+        )
+        .ok()
+    };
+
+    if transit::rise_set_azimuth(
+        &transit::TransitBody::Sun,
+        geograph_point,
+        &sun_eq_point2,
+        0.0,
+    )
+    .is_err()
+    {
+        return (None, None);
+    }
+
+    (
+        az(&transit::TransitType::Rise).map(|(rise_az, _)| rise_az),
+        az(&transit::TransitType::Set).map(|(_, set_az)| set_az),
+    )
+}
+
+/**
+Computes the local horizontal coordinates of the antisolar point
+
+The antisolar point is the point of the sky opposite the apparent
+Sun. When it lies just above the eastern horizon, around sunset, the
+Earth's shadow can be seen rising there, and around midnight it marks
+the position of the gegenschein.
+
+# Returns
+
+* `horiz_point`: Horizontal point of the antisolar point
+                 *| in radians*
+
+# Arguments
+
+* `JD`            : Julian (Ephemeris) day
+* `geograph_point`: Geographic point of the observer *| in radians*
+* `local_sidr`    : Local sidereal time *| in radians*
+**/
+pub fn antisolar_horiz(
+    JD: f64,
+    geograph_point: &coords::GeographPoint,
+    local_sidr: f64,
+) -> coords::HorizPoint {
+    let (sun_eq_point, _) = apprnt_eq_pos(JD);
+    let asc = angle::limit_to_two_PI(sun_eq_point.asc + std::f64::consts::PI);
+    let dec = -sun_eq_point.dec;
+    let hour_angle = coords::hr_angl_frm_loc_sidr(local_sidr, asc);
+
+    coords::HorizPoint {
+        az: coords::az_frm_eq(hour_angle, dec, geograph_point.lat),
+        alt: coords::alt_frm_eq(hour_angle, dec, geograph_point.lat),
+    }
+}
+
+/// Represents the state of the sky, as set by the altitude of the Sun
+pub enum SkyState {
+    /// The Sun's upper limb is above the horizon
+    Day,
+    /// The Sun's center is less than 6 degrees below the horizon
+    CivilTwilight,
+    /// The Sun's center is between 6 and 12 degrees below the horizon
+    NauticalTwilight,
+    /// The Sun's center is between 12 and 18 degrees below the horizon
+    AstronomicalTwilight,
+    /// The Sun's center is more than 18 degrees below the horizon
+    Night,
+}
+
+/**
+Computes the state of the sky at an instant, from the altitude of
+the Sun
+
+It is day while the Sun's center is higher than -0°50', ie, while its
+upper limb is above the horizon, taking atmospheric refraction into
+account.
+
+# Returns
+
+* `sky_state`: The [SkyState](./enum.SkyState.html)
+
+# Arguments
+
+* `JD`            : Julian day
+* `geograph_point`: Geographic point of the observer *| in radians*
+**/
+pub fn sky_state(JD: f64, geograph_point: &coords::GeographPoint) -> SkyState {
+    let (sun_eq_point, _) = apprnt_eq_pos(JD);
+
+    let apprnt_greenwhich_sidr = sidereal::apparent_sidereal_greenwich(JD);
+
+    let hour_angle = coords::hr_angl_frm_observer_long(
+        apprnt_greenwhich_sidr,
+        geograph_point.long,
+        sun_eq_point.asc,
+    );
+    let alt = coords::alt_frm_eq(hour_angle, sun_eq_point.dec, geograph_point.lat).to_degrees();
+
+    if alt > -0.8333 {
+        SkyState::Day
+    } else if alt > -6.0 {
+        SkyState::CivilTwilight
+    } else if alt > -12.0 {
+        SkyState::NauticalTwilight
+    } else if alt > -18.0 {
+        SkyState::AstronomicalTwilight
+    } else {
+        SkyState::Night
+    }
+}
+
+/// Represents the point of the Sun's disk whose rising and setting is
+/// considered
+pub enum SunDisk {
+    /// The center of the disk
+    Center,
+    /// The upper limb of the disk
+    UpperLimb,
+}
+
+/**
+Computes the times of sunrise and sunset, for a chosen point of the
+Sun's disk
+
+The Sun rises or sets when the chosen point of it's disk is on the
+horizon, after allowing 34 arcminutes for refraction. For the upper
+limb, the Sun's center is then lower by it's semidiameter, about 16
+arcminutes, which amounts to the usual standard altitude of -0°50'.
+The times are found by iterating on the Sun's hour angle.
+
+# Returns
+
+`(sunrise, sunset)`
+
+* `sunrise`: Julian day of sunrise, in UT
+* `sunset` : Julian day of sunset, in UT
+
+Each is `None` if the Sun doesn't rise or set on the day.
+
+# Arguments
+
+* `date`          : The day of interest. Its decimal day is truncated
+                    to 0h UT.
+* `geograph_point`: Geographic point of the observer *| in radians*
+* `disk`          : The [SunDisk](./enum.SunDisk.html) point whose
+                    rising and setting is considered
+**/
+pub fn rise_set_with_definition(
+    date: &time::Date,
+    geograph_point: &coords::GeographPoint,
+    disk: &SunDisk,
+) -> (Option<f64>, Option<f64>) {
+    let JD = time::julian_day(&time::Date {
+        year: date.year,
+        month: date.month,
+        decimal_day: date.decimal_day.floor(),
+        cal_type: date.cal_type,
+    });
+    let delta_t = time::delta_t(date.year as i32, date.month as u8);
+
+    let event = |sign: f64| -> Option<f64> {
+        let mut JD_event = JD + 0.5 + geograph_point.long / angle::TWO_PI;
+
+        for _ in 0..10 {
+            let (sun_eq_point, sun_earth_dist) = apprnt_eq_pos(JD_event + delta_t / 86400.0);
+
+            let refrac = angle::deg_frm_dms(0, 34, 0.0);
+            let h0 = match *disk {
+                SunDisk::Center => -refrac,
+                SunDisk::UpperLimb => -refrac - semidiameter(sun_earth_dist),
+            }
+            .to_radians();
+
+            let cos_H0 = (h0.sin() - geograph_point.lat.sin() * sun_eq_point.dec.sin())
+                / (geograph_point.lat.cos() * sun_eq_point.dec.cos());
+            if !(-1.0..=1.0).contains(&cos_H0) {
+                return None;
+            }
+
+            let sidr = sidereal::apparent_sidereal_greenwich(JD_event);
+            let H = coords::hr_angl_frm_observer_long(sidr, geograph_point.long, sun_eq_point.asc);
+
+            let d_H = angle::limit_to_pm_PI(sign * cos_H0.acos() - H);
+            let correction = d_H / (angle::TWO_PI * 1.00273790935);
+            JD_event += correction;
+
+            if correction.abs() < 1e-7 {
+                break;
+            }
+        }
+
+        Some(JD_event)
+    };
+
+    (event(-1.0), event(1.0))
+}
+
+/**
+Computes the times of sunrise and sunset in local time, for a chosen
+point of the Sun's disk
+
+This is the same as
+[rise_set_with_definition()](./fn.rise_set_with_definition.html),
+with each Julian day converted to a date and shifted from UT by
+`utc_offset_hours` with
+[Date::to_local()](../time/struct.Date.html#method.to_local). The
+local date may be the day before or after `date`.
+
+# Returns
+
+`(sunrise, sunset)`
+
+* `sunrise`: Date of sunrise, in local time
+* `sunset` : Date of sunset, in local time
+
+Each is `None` if the Sun doesn't rise or set on the day, or if the
+local date is out of range.
+
+# Arguments
+
+* `date`            : The day of interest, as in
+                      [rise_set_with_definition()](./fn.rise_set_with_definition.html)
+* `geograph_point`  : Geographic point of the observer *| in radians*
+* `disk`            : The [SunDisk](./enum.SunDisk.html) point whose
+                      rising and setting is considered
+* `utc_offset_hours`: Offset of the local time from UTC *| in hours*.
+                      Eg: 5.5 for Indian Standard Time.
+**/
+pub fn rise_set_local(
+    date: &time::Date,
+    geograph_point: &coords::GeographPoint,
+    disk: &SunDisk,
+    utc_offset_hours: f64,
+) -> (Option<time::Date>, Option<time::Date>) {
+    let (sunrise, sunset) = rise_set_with_definition(date, geograph_point, disk);
+
+    let local = |JD: f64| {
+        time::Date::from_julian_day(JD)
+            .and_then(|date| date.to_local(utc_offset_hours))
+            .ok()
+    };
+
+    (sunrise.and_then(local), sunset.and_then(local))
+}
+
+/// Represents an equinox or a solstice
+pub enum EquinoxSolstice {
+    /// The March equinox
+    MarchEquinox,
+    /// The June solstice
+    JuneSolstice,
+    /// The September equinox
+    SeptemberEquinox,
+    /// The December solstice
+    DecemberSolstice,
+}
+
+/**
+Computes the instant of an equinox or a solstice
+
+The mean instant is first found from Meeus's polynomials, and
+corrected for the periodic terms of chapter 27. It is then refined
+until the Sun's apparent longitude is the required multiple of 90
+degrees.
+
+Meeus gives the polynomials of table 27.A for the years -1000 to 1000,
+and those of table 27.B for the years 1000 to 3000, and these are used
+in their ranges. Outside -1000 to 3000, the nearer table is
+extrapolated; the mean instant is then less accurate, but the
+refinement on the Sun's longitude still gives the event, to the
+accuracy of [geocent_ecl_pos()](./fn.geocent_ecl_pos.html) so far
+from J2000.0.
+
+# Returns
+
+* `JD`: Julian (Ephemeris) day of the event
+
+# Arguments
+
+* `year` : Year
+* `event`: The `EquinoxSolstice`
+**/
+pub fn equinox_solstice(year: i32, event: &EquinoxSolstice) -> f64 {
+    let (mut JD, k) = if year < 1000 {
+        mn_equinox_solstice_27A((year as f64) / 1000.0, event)
+    } else {
+        mn_equinox_solstice_27B(((year as f64) - 2000.0) / 1000.0, event)
+    };
+
+    struct terms(f64, f64, f64);
+    let periodic_terms = [
+        terms(485.0, 324.96, 1934.136),
+        terms(203.0, 337.23, 32964.467),
+        terms(199.0, 342.08, 20.186),
+        terms(182.0, 27.85, 445267.112),
+        terms(156.0, 73.14, 45036.886),
+        terms(136.0, 171.52, 22518.443),
+        terms(77.0, 222.54, 65928.934),
+        terms(74.0, 296.72, 3034.906),
+        terms(70.0, 243.58, 9037.513),
+        terms(58.0, 119.81, 33718.147),
+        terms(52.0, 297.17, 150.678),
+        terms(50.0, 21.02, 2281.226),
+        terms(45.0, 247.54, 29929.562),
+        terms(44.0, 325.15, 31555.956),
+        terms(29.0, 60.93, 4443.417),
+        terms(18.0, 155.12, 67555.328),
+        terms(17.0, 288.79, 4562.452),
+        terms(16.0, 198.04, 62894.029),
+        terms(14.0, 199.76, 31436.921),
+        terms(12.0, 95.39, 14577.848),
+        terms(12.0, 287.11, 31931.756),
+        terms(12.0, 320.81, 34777.259),
+        terms(9.0, 227.73, 1222.114),
+        terms(8.0, 15.45, 16859.074),
+    ];
+
+    let T = time::julian_cent(JD);
+    let W = (35999.373 * T - 2.47).to_radians();
+    let delta_lambda = 1.0 + 0.0334 * W.cos() + 0.0007 * (2.0 * W).cos();
+    let S: f64 = periodic_terms
+        .iter()
+        .map(|x| x.0 * (x.1 + x.2 * T).to_radians().cos())
+        .sum();
+    JD += 0.00001 * S / delta_lambda;
+
+    for _ in 0..5 {
+        let (apprnt_long, _, _) = apprnt_ecl_pos(JD);
+        let correction = 58.0 * (k * std::f64::consts::FRAC_PI_2 - apprnt_long).sin();
+        JD += correction;
+        if correction.abs() < 1e-6 {
+            break;
+        }
+    }
+
+    JD
+}
+
+// Computes the mean instant of an equinox or solstice from Meeus's
+// table 27.A, for Y = year / 1000, and the number of quarters of the
+// Sun's longitude at it
+fn mn_equinox_solstice_27A(Y: f64, event: &EquinoxSolstice) -> (f64, f64) {
+    match *event {
+        EquinoxSolstice::MarchEquinox => (
+            1721139.29189 + Y * (365242.13740 + Y * (0.06134 + Y * (0.00111 - Y * 0.00071))),
+            0.0,
+        ),
+        EquinoxSolstice::JuneSolstice => (
+            1721233.25401 + Y * (365241.72562 - Y * (0.05323 - Y * (0.00907 + Y * 0.00025))),
+            1.0,
+        ),
+        EquinoxSolstice::SeptemberEquinox => (
+            1721325.70455 + Y * (365242.49558 - Y * (0.11677 + Y * (0.00297 - Y * 0.00074))),
+            2.0,
+        ),
+        EquinoxSolstice::DecemberSolstice => (
+            1721414.39987 + Y * (365242.88257 - Y * (0.00769 + Y * (0.00933 + Y * 0.00006))),
+            3.0,
+        ),
+    }
+}
+
+// Computes the mean instant of an equinox or solstice from Meeus's
+// table 27.B, for Y = (year - 2000) / 1000, and the number of quarters
+// of the Sun's longitude at it
+fn mn_equinox_solstice_27B(Y: f64, event: &EquinoxSolstice) -> (f64, f64) {
+    match *event {
+        EquinoxSolstice::MarchEquinox => (
+            2451623.80984 + Y * (365242.37404 + Y * (0.05169 - Y * (0.00411 + Y * 0.00057))),
+            0.0,
+        ),
+        EquinoxSolstice::JuneSolstice => (
+            2451716.56767 + Y * (365241.62603 + Y * (0.00325 + Y * (0.00888 - Y * 0.00030))),
+            1.0,
+        ),
+        EquinoxSolstice::SeptemberEquinox => (
+            2451810.21715 + Y * (365242.01767 - Y * (0.11575 - Y * (0.00337 + Y * 0.00078))),
+            2.0,
+        ),
+        EquinoxSolstice::DecemberSolstice => (
+            2451900.05952 + Y * (365242.74049 - Y * (0.06223 + Y * (0.00823 - Y * 0.00032))),
+            3.0,
+        ),
+    }
+}
+
+/**
+Computes the Sun's apparent geocentric equatorial position
+
+The position is converted to the FK5 system, and corrected for
+nutation and aberration.
+
+# Returns
+
+`(sun_eq_point, sun_earth_dist)`
+
+* `sun_eq_point`  : Apparent equatorial point of the Sun *| in radians*
+* `sun_earth_dist`: Sun-Earth distance *| in AU*
+
+The equatorial point is referred to the true equinox of the date.
+
+# Arguments
+
+* `JD`: Julian (Ephemeris) day
+**/
+pub fn apprnt_eq_pos(JD: f64) -> (coords::EqPoint, f64) {
+    let (apprnt_long, lat, sun_earth_dist) = apprnt_ecl_pos(JD);
+
+    let (_, nut_in_oblq) = nutation::nutation(JD);
+    let true_oblq = ecliptic::mn_oblq_laskar(JD) + nut_in_oblq;
+
+    let sun_eq_point = coords::EqPoint {
+        asc: coords::asc_frm_ecl(apprnt_long, lat, true_oblq),
+        dec: coords::dec_frm_ecl(apprnt_long, lat, true_oblq),
+    };
+
+    (sun_eq_point, sun_earth_dist)
+}
+
+// Computes the Sun's apparent geocentric longitude and latitude, and
+// its distance to the Earth, in AU
+fn apprnt_ecl_pos(JD: f64) -> (f64, f64, f64) {
+    let (sun_ecl_point, sun_earth_dist) = geocent_ecl_pos(JD);
+    let (long, lat) = ecl_coords_to_FK5(JD, sun_ecl_point.long, sun_ecl_point.lat);
+
+    let (nut_in_long, _) = nutation::nutation(JD);
+    let apprnt_long = long + nut_in_long + aberr::sol_aberr(sun_earth_dist);
+
+    (apprnt_long, lat, sun_earth_dist)
+}
@@ -0,0 +1,1271 @@
+/*
+Copyright (c) 2015, 2016 Saurav Sachidanand
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+//! Time for astronomy
+
+pub mod scales;
+
+use angle;
+use std::convert::TryFrom;
+use std::error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Represents a calendar type
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CalType {
+    /// Gregorian calendar
+    Gregorian,
+    /// Julian calendar
+    Julian,
+}
+
+/// Represents a month in the Gregorian and Julian calendars
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Month {
+    /// January
+    Jan = 1,
+    /// February
+    Feb = 2,
+    /// March
+    Mar = 3,
+    /// April
+    Apr = 4,
+    /// May
+    May = 5,
+    /// June
+    June = 6,
+    /// July
+    July = 7,
+    /// August
+    Aug = 8,
+    /// September
+    Sept = 9,
+    /// October
+    Oct = 10,
+    /// November
+    Nov = 11,
+    /// December
+    Dec = 12,
+}
+
+/// Represents a date with year, month, decimal day and calendar type
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Date {
+    /// Year
+    pub year: i16,
+    /// Month
+    pub month: Month,
+    /// Decimal day
+    ///
+    /// range: *1.0 - 31.0*
+    pub decimal_day: f64,
+    /// Calendar type
+    pub cal_type: CalType,
+}
+
+impl Date {
+    /**
+    Converts a date in UTC to local time
+
+    The decimal day is shifted by the offset, and the month and year
+    are carried over when the shift crosses midnight at the start or
+    end of a month.
+
+    # Returns
+
+    * `date`: The date in local time
+
+    A shift that carries the year out of the range of an `i16` gives
+    an error.
+
+    # Arguments
+
+    * `utc_offset_hours`: Offset of the local time from UTC *| in hours*
+
+    Example: Indian Standard Time is 5.5, and Pacific Standard Time
+    is -8.0
+
+    The rise, set, transit and phase functions give their results in
+    UTC, or in Ephemeris time for those returning a Julian Ephemeris
+    day. [transit::time_local()](../transit/fn.time_local.html),
+    [sun::rise_set_local()](../sun/fn.rise_set_local.html) and
+    [lunar::time_of_phase_local()](../lunar/fn.time_of_phase_local.html)
+    give theirs in local time. For the others, build a `Date` from the
+    result, with [from_julian_day()](#method.from_julian_day) for a
+    Julian day, and convert that with this method.
+    **/
+    pub fn to_local<'a>(&self, utc_offset_hours: f64) -> Result<Date, &'a str> {
+        let mut year = self.year;
+        let mut month = self.month as u8;
+        let mut decimal_day = self.decimal_day + utc_offset_hours / 24.0;
+
+        while decimal_day < 1.0 {
+            if month == 1 {
+                year = year.checked_sub(1)
+                    .ok_or("The year is out of range in time::Date::to_local()")?;
+                month = 12;
+            } else {
+                month -= 1;
+            }
+            decimal_day += days_in_month(year, month, &self.cal_type);
+        }
+
+        while decimal_day >= days_in_month(year, month, &self.cal_type) + 1.0 {
+            decimal_day -= days_in_month(year, month, &self.cal_type);
+            if month == 12 {
+                year = year.checked_add(1)
+                    .ok_or("The year is out of range in time::Date::to_local()")?;
+                month = 1;
+            } else {
+                month += 1;
+            }
+        }
+
+        Ok(Date {
+            year,
+            month: month_frm_num(month),
+            decimal_day,
+            cal_type: self.cal_type,
+        })
+    }
+
+    /**
+    Computes the date corresponding to a Julian day
+
+    This is the inverse of [julian_day()](./fn.julian_day.html), with
+    the calendar date computed by
+    [date_frm_julian_day()](./fn.date_frm_julian_day.html). The
+    calendar is Julian before 1582 October 15, which is Julian day
+    2299160.5, and Gregorian from then on. Years before 1 AD are
+    counted astronomically, so that 1 BC is the year 0.
+
+    The fraction of the day is kept in `decimal_day`; see
+    [day_of_month()](#method.day_of_month) for it in hours, minutes
+    and seconds.
+
+    # Returns
+
+    * `date`: The date
+
+    A Julian day after the year 32767, the largest that fits in the
+    `i16` year of a `Date`, gives an error.
+
+    # Arguments
+
+    * `JD`: Julian day. **Can't be a negative value.**
+    **/
+    pub fn from_julian_day<'a>(JD: f64) -> Result<Date, &'a str> {
+        let (year, month, decimal_day) = date_frm_julian_day(JD)?;
+
+        Ok(Date {
+            year,
+            month: month_frm_num(month),
+            decimal_day,
+            cal_type: if JD < 2299160.5 {
+                CalType::Julian
+            } else {
+                CalType::Gregorian
+            },
+        })
+    }
+
+    /**
+    Splits the decimal day of a date into hours, minutes and seconds
+
+    # Returns
+
+    * `day_of_month`: The day of the month, with the time of day. The
+      time zone is UTC.
+    **/
+    pub fn day_of_month(&self) -> DayOfMonth {
+        let day = self.decimal_day.floor();
+        let secs = (self.decimal_day - day) * 86400.0;
+        let hr = (secs / 3600.0).floor();
+        let min = ((secs - hr * 3600.0) / 60.0).floor();
+
+        DayOfMonth {
+            day: day as u8,
+            hr: hr as u8,
+            min: min as u8,
+            sec: secs - hr * 3600.0 - min * 60.0,
+            time_zone: 0.0,
+        }
+    }
+
+    /**
+    Computes the date in UTC corresponding to a Unix timestamp
+
+    Unix time counts the seconds since 1970 January 1, 0h UTC, which
+    is Julian day 2440587.5, with every day taken to be 86400 seconds
+    long. Leap seconds are thus ignored, and a timestamp within a leap
+    second can't be told apart from one in the following second.
+    Negative timestamps are before 1970. The calendar is Julian before
+    1582 October 15 and Gregorian from then on.
+
+    # Returns
+
+    * `date`: The date in UTC
+
+    # Arguments
+
+    * `secs`: Unix timestamp *| in seconds*
+
+    Julian days before zero, that is timestamps before about
+    -210866760000, give an error, as do timestamps after the end of
+    the year 32767, which is the largest that fits in the `i16` year
+    of a `Date`.
+    **/
+    pub fn from_unix_timestamp<'a>(secs: i64) -> Result<Date, &'a str> {
+        let days = secs.div_euclid(86400);
+        let day_secs = secs.rem_euclid(86400);
+
+        let mut date = Date::from_julian_day(2440587.5 + days as f64)?;
+        date.decimal_day += day_secs as f64 / 86400.0;
+
+        Ok(date)
+    }
+
+    /**
+    Computes the Unix timestamp of a date in UTC
+
+    This is the inverse of
+    [from_unix_timestamp()](#method.from_unix_timestamp), and ignores
+    leap seconds in the same way. The timestamp is rounded to the
+    nearest second.
+
+    # Returns
+
+    * `secs`: Unix timestamp *| in seconds*
+    **/
+    pub fn to_unix_timestamp(&self) -> i64 {
+        ((julian_day(self) - 2440587.5) * 86400.0).round() as i64
+    }
+}
+
+/// Represents an error in parsing a `Date` from an ISO 8601 string
+#[derive(Debug, PartialEq)]
+pub enum ParseDateError {
+    /// The string isn't of the form `YYYY-MM-DD[THH:MM[:SS[.S...]]][Z|±HH:MM]`
+    Format,
+    /// The month isn't between 1 and 12
+    Month,
+    /// The day doesn't exist in the month
+    Day,
+    /// The hour, minute, second or time zone is out of range
+    Time,
+}
+
+impl fmt::Display for ParseDateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let desc = match *self {
+            ParseDateError::Format => "date isn't in the ISO 8601 format YYYY-MM-DDTHH:MM:SSZ",
+            ParseDateError::Month => "month isn't between 1 and 12",
+            ParseDateError::Day => "day doesn't exist in the month",
+            ParseDateError::Time => "hour, minute, second or time zone is out of range",
+        };
+
+        f.write_str(desc)
+    }
+}
+
+impl error::Error for ParseDateError {}
+
+/**
+Parses a `Date` from an ISO 8601 string
+
+The string is of the form `YYYY-MM-DDTHH:MM:SS.SSSZ`, in which the
+fractional seconds, the seconds, or the whole time of day may be left
+out. The time zone is either `Z` or an offset like `+05:30`, and is
+taken as UTC if left out; the `Date` is always in UTC. The year may
+be negative, and the calendar is Julian before 1582 October 15 and
+Gregorian from then on.
+**/
+impl FromStr for Date {
+    type Err = ParseDateError;
+
+    fn from_str(s: &str) -> Result<Date, ParseDateError> {
+        let (date, time) = match s.find('T') {
+            Some(i) => (&s[..i], Some(&s[i + 1..])),
+            None => (s, None),
+        };
+
+        let (negative, date) = if let Some(stripped) = date.strip_prefix('-') {
+            (true, stripped)
+        } else {
+            (false, date)
+        };
+        let fields: Vec<&str> = date.split('-').collect();
+        if fields.len() != 3 || fields[0].len() < 4 || fields[1].len() != 2 || fields[2].len() != 2
+        {
+            return Err(ParseDateError::Format);
+        }
+        let mut year =
+            i16::try_from(parse_digits(fields[0])?).map_err(|_| ParseDateError::Format)?;
+        if negative {
+            year = -year;
+        }
+        let month = parse_digits(fields[1])? as u8;
+        let day = parse_digits(fields[2])? as u8;
+
+        if !(1..=12).contains(&month) {
+            return Err(ParseDateError::Month);
+        }
+        let cal_type = if (year, month, day) < (1582, 10, 15) {
+            CalType::Julian
+        } else {
+            CalType::Gregorian
+        };
+        if day < 1 || day as f64 > days_in_month(year, month, &cal_type) {
+            return Err(ParseDateError::Day);
+        }
+
+        let (day_frac, utc_offset_hours) = match time {
+            Some(time) => parse_time_of_day(time)?,
+            None => (0.0, 0.0),
+        };
+
+        let date = Date {
+            year,
+            month: month_frm_num(month),
+            decimal_day: day as f64 + day_frac,
+            cal_type,
+        };
+
+        date.to_local(-utc_offset_hours)
+            .map_err(|_| ParseDateError::Time)
+    }
+}
+
+/**
+Formats a `Date` as an ISO 8601 string in UTC
+
+The time of day is rounded to the millisecond, and the fractional
+seconds are written only when they're not zero. Eg:
+`2024-03-20T12:34:56Z` or `2024-03-20T12:34:56.250Z`.
+**/
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let day = self.decimal_day.floor();
+        let millis = ((self.decimal_day - day) * 86400000.0).round().min(86399999.0) as u32;
+        let (hr, min) = (millis / 3600000, millis / 60000 % 60);
+        let (sec, milli) = (millis / 1000 % 60, millis % 1000);
+
+        if self.year < 0 {
+            write!(f, "-{:04}", -(self.year as i32))?;
+        } else {
+            write!(f, "{:04}", self.year)?;
+        }
+        write!(f, "-{:02}-{:02}T{:02}:{:02}:{:02}", self.month as u8, day, hr, min, sec)?;
+        if milli != 0 {
+            write!(f, ".{:03}", milli)?;
+        }
+
+        f.write_str("Z")
+    }
+}
+
+// Parses a string of ASCII digits
+fn parse_digits(s: &str) -> Result<u32, ParseDateError> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(ParseDateError::Format);
+    }
+
+    s.parse().map_err(|_| ParseDateError::Format)
+}
+
+// Parses the time of day and time zone of an ISO 8601 string, into
+// the fraction of the day and the offset from UTC in hours
+fn parse_time_of_day(s: &str) -> Result<(f64, f64), ParseDateError> {
+    let (time, utc_offset_hours) = if let Some(time) = s.strip_suffix('Z') {
+        (time, 0.0)
+    } else if let Some(i) = s.rfind(['+', '-']) {
+        let sign = if &s[i..=i] == "-" { -1.0 } else { 1.0 };
+        let zone: Vec<&str> = s[i + 1..].split(':').collect();
+        if zone.len() != 2 || zone[0].len() != 2 || zone[1].len() != 2 {
+            return Err(ParseDateError::Format);
+        }
+        let (zone_hr, zone_min) = (parse_digits(zone[0])?, parse_digits(zone[1])?);
+        if zone_hr > 14 || zone_min > 59 {
+            return Err(ParseDateError::Time);
+        }
+        (&s[..i], sign * (zone_hr as f64 + zone_min as f64 / 60.0))
+    } else {
+        (s, 0.0)
+    };
+
+    let fields: Vec<&str> = time.split(':').collect();
+    if fields.len() < 2 || fields.len() > 3 || fields[0].len() != 2 || fields[1].len() != 2 {
+        return Err(ParseDateError::Format);
+    }
+    let hr = parse_digits(fields[0])?;
+    let min = parse_digits(fields[1])?;
+    let sec = match fields.get(2) {
+        Some(sec) => {
+            let (whole, frac) = match sec.find('.') {
+                Some(i) => (&sec[..i], &sec[i + 1..]),
+                None => (&sec[..], "0"),
+            };
+            if whole.len() != 2 {
+                return Err(ParseDateError::Format);
+            }
+            parse_digits(whole)? as f64
+                + parse_digits(frac)? as f64 / 10_f64.powi(frac.len() as i32)
+        }
+        None => 0.0,
+    };
+
+    if hr > 23 || min > 59 || sec >= 60.0 {
+        return Err(ParseDateError::Time);
+    }
+
+    Ok(((hr as f64 + min as f64 / 60.0 + sec / 3600.0) / 24.0, utc_offset_hours))
+}
+
+// Computes the number of days in a month of a year
+fn days_in_month(year: i16, month: u8, cal_type: &CalType) -> f64 {
+    match month {
+        2 => {
+            if is_leap_year(year, cal_type) {
+                29.0
+            } else {
+                28.0
+            }
+        }
+        4 | 6 | 9 | 11 => 30.0,
+        _ => 31.0,
+    }
+}
+
+// Computes the Month corresponding to its number, from 1 to 12
+fn month_frm_num(month: u8) -> Month {
+    match month {
+        1 => Month::Jan,
+        2 => Month::Feb,
+        3 => Month::Mar,
+        4 => Month::Apr,
+        5 => Month::May,
+        6 => Month::June,
+        7 => Month::July,
+        8 => Month::Aug,
+        9 => Month::Sept,
+        10 => Month::Oct,
+        11 => Month::Nov,
+        _ => Month::Dec,
+    }
+}
+
+/// Represents a day of a month with hours, minutes and seconds
+#[derive(Debug)]
+pub struct DayOfMonth {
+    /// Day of month
+    ///
+    /// range: *1 - 31*
+    pub day: u8,
+    /// Hour of day
+    ///
+    /// range: *0 - 60*
+    pub hr: u8,
+    /// Minute of hour
+    ///
+    /// range: *0 - 60*
+    pub min: u8,
+    /// Second of minute
+    ///
+    /// range: *0.0 - 60.0*
+    pub sec: f64,
+    /// Time zone *| in decimal hours*
+    ///
+    /// Example: Pacific Time Zone is -8.0
+    pub time_zone: f64,
+}
+
+/// Represents a day of the week
+#[derive(Debug)]
+pub enum Weekday {
+    /// Sunday
+    Sunday,
+    /// Monday
+    Monday,
+    /// Tuesday
+    Tuesday,
+    /// Wednesday
+    Wednesday,
+    /// Thursday
+    Thursday,
+    /// Friday
+    Friday,
+    /// Saturday
+    Saturday,
+}
+
+pub fn weekday_frm_date(date: &Date) -> Weekday {
+    let date_0UT = Date {
+        year: date.year,
+        month: date.month,
+        decimal_day: date.decimal_day.floor() as f64,
+        cal_type: date.cal_type,
+    };
+    let JD = julian_day(&date_0UT);
+    let wd = (JD + 1.5).rem_euclid(7.0) as i64;
+
+    match wd {
+        0 => Weekday::Sunday,
+        1 => Weekday::Monday,
+        2 => Weekday::Tuesday,
+        3 => Weekday::Wednesday,
+        4 => Weekday::Thursday,
+        5 => Weekday::Friday,
+        6 => Weekday::Saturday,
+        _ => panic!("Internal error in time::weekday_frm_date"),
+    }
+}
+
+/**
+Computes decimal day for a `DayOfMonth`
+
+* `day_of_month`: A `DayOfMonth` struct
+**/
+pub fn decimal_day(day: &DayOfMonth) -> f64 {
+    (day.day as f64) + (day.hr as f64) / 24.0 + (day.min as f64) / (60.0 * 24.0)
+        + day.sec / (60.0 * 60.0 * 24.0) - day.time_zone / 24.0
+}
+
+/**
+Computes decimal year for a `Date`
+
+* `date`: A `Date` struct
+**/
+pub fn decimal_year(date: &Date) -> f64 {
+    let mut y = 0;
+    let mut days = 365.0;
+    let month = date.month as u8;
+
+    if month > 1 {
+        y += 31;
+    }
+    if month > 2 {
+        y += 28;
+        if is_leap_year(date.year, &date.cal_type) {
+            y += 1;
+            days += 1.0;
+        }
+    }
+    if month > 3 {
+        y += 31;
+    }
+    if month > 4 {
+        y += 30;
+    }
+    if month > 5 {
+        y += 31;
+    }
+    if month > 6 {
+        y += 30;
+    }
+    if month > 7 {
+        y += 31;
+    }
+    if month > 8 {
+        y += 31;
+    }
+    if month > 9 {
+        y += 30;
+    }
+    if month > 10 {
+        y += 31;
+    }
+    if month > 11 {
+        y += 30;
+    }
+
+    (date.year as f64) + ((y as f64) + date.decimal_day) / days
+}
+
+/**
+Checks if a year is a leap year
+
+# Arguments
+
+* `year`    : Year
+* `cal_type`: `CalType` enum
+**/
+pub fn is_leap_year(year: i16, cal_type: &CalType) -> (bool) {
+    match cal_type {
+        &CalType::Julian => year % 4 == 0,
+        &CalType::Gregorian => {
+            if year % 100 == 0 {
+                year % 400 == 0
+            } else {
+                year % 4 == 0
+            }
+        }
+    }
+}
+
+/**
+Computes Julian century for a Julian day
+
+# Arguments
+
+* `JD`: Julian (Ephemeris) day
+**/
+#[inline]
+pub fn julian_cent(JD: f64) -> f64 {
+    (JD - 2451545.0) / 36525.0
+}
+
+/**
+Computes Julian millennium for a Julian day
+
+# Arguments
+
+* `JD`: Julian (Ephemeris) day
+**/
+#[inline]
+pub fn julian_mill(JD: f64) -> f64 {
+    (JD - 2451545.0) / 365250.0
+}
+
+/**
+Computes the day of the week of a `Date`
+
+This is [weekday_frm_date()](./fn.weekday_frm_date.html), named to go
+with [day_of_year()](./fn.day_of_year.html). The calendar type of
+`date` is honoured.
+
+# Returns
+
+* `weekday`: Day of the week
+
+# Arguments
+
+* `date`: A `Date`
+**/
+#[inline]
+pub fn day_of_week(date: &Date) -> Weekday {
+    weekday_frm_date(date)
+}
+
+/**
+Computes the day of the year of a `Date`
+
+# Returns
+
+* `day_of_year`: Day of the year, from 1 on January 1 *| in days*
+
+December 31 is day 365, or 366 in leap years.
+
+# Arguments
+
+* `date`: A `Date`
+**/
+pub fn day_of_year(date: &Date) -> u16 {
+    let month = date.month as u16;
+    let k = if is_leap_year(date.year, &date.cal_type) {
+        1
+    } else {
+        2
+    };
+
+    275 * month / 9 - k * ((month + 9) / 12) + date.decimal_day as u16 - 30
+}
+
+/**
+Computes the date of Easter Sunday in a year
+
+For years from 1583 on, the Gregorian Easter is computed with the
+algorithm given by Meeus, and for earlier years the Julian Easter, with
+the algorithm for the Julian calendar. Both are from chapter 8 of
+Meeus's Astronomical Algorithms.
+
+# Returns
+
+* `date`: Date of Easter Sunday, at 0h
+
+# Arguments
+
+* `year`: Year
+**/
+pub fn easter(year: i16) -> Date {
+    let (month, day, cal_type) = if year >= 1583 {
+        let year = year as i32;
+        let a = year % 19;
+        let (b, c) = (year / 100, year % 100);
+        let (d, e) = (b / 4, b % 4);
+        let f = (b + 8) / 25;
+        let g = (b - f + 1) / 3;
+        let h = (19 * a + b - d - g + 15) % 30;
+        let (i, k) = (c / 4, c % 4);
+        let l = (32 + 2 * e + 2 * i - h - k) % 7;
+        let m = (a + 11 * h + 22 * l) / 451;
+        let n = h + l - 7 * m + 114;
+
+        (n / 31, n % 31 + 1, CalType::Gregorian)
+    } else {
+        let year = year as i32;
+        let a = year.rem_euclid(4);
+        let b = year.rem_euclid(7);
+        let c = year.rem_euclid(19);
+        let d = (19 * c + 15) % 30;
+        let e = (2 * a + 4 * b - d + 34) % 7;
+        let f = d + e + 114;
+
+        (f / 31, f % 31 + 1, CalType::Julian)
+    };
+
+    Date {
+        year,
+        month: month_frm_num(month as u8),
+        decimal_day: day as f64,
+        cal_type,
+    }
+}
+
+/// Represents a month in the Islamic calendar
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum IslamicMonth {
+    /// Muharram
+    Muharram = 1,
+    /// Safar
+    Safar = 2,
+    /// Rabi' al-awwal
+    RabiAlAwwal = 3,
+    /// Rabi' al-thani
+    RabiAlThani = 4,
+    /// Jumada al-awwal
+    JumadaAlAwwal = 5,
+    /// Jumada al-thani
+    JumadaAlThani = 6,
+    /// Rajab
+    Rajab = 7,
+    /// Sha'ban
+    Shaban = 8,
+    /// Ramadan
+    Ramadan = 9,
+    /// Shawwal
+    Shawwal = 10,
+    /// Dhu al-Qi'dah
+    DhuAlQidah = 11,
+    /// Dhu al-Hijjah
+    DhuAlHijjah = 12,
+}
+
+/// Represents a date in the tabular Islamic calendar
+#[derive(Debug, PartialEq)]
+pub struct IslamicDate {
+    /// Year of the Hijra
+    pub year: i32,
+    /// Month
+    pub month: IslamicMonth,
+    /// Day of the month
+    ///
+    /// range: *1 - 30*
+    pub day: u8,
+}
+
+/**
+Computes the date in the Islamic calendar corresponding to a `Date`
+
+This is the tabular Islamic calendar of Meeus's chapter 9, in which
+the months alternately have 30 and 29 days, and 11 years in a cycle
+of 30 have a 30th day added to the last month. It's an arithmetic
+approximation to the religious calendar, whose months begin with the
+first sighting of the lunar crescent, and may differ from it by a day
+or two. A day of the Islamic calendar is taken to begin at midnight,
+and not at the preceding sunset.
+
+# Returns
+
+* `islamic_date`: Date in the Islamic calendar
+
+# Arguments
+
+* `date`: A `Date`, in the Gregorian or Julian calendar
+**/
+pub fn gregorian_to_islamic(date: &Date) -> IslamicDate {
+    let JD = (julian_day(date) - 0.5).floor() + 0.5;
+
+    let year = ((30.0 * (JD - 1948439.5) + 10646.0) / 10631.0).floor() as i32;
+    let month = (((JD - 29.0 - islamic_julian_day(year, 1, 1)) / 29.5).ceil() + 1.0)
+        .clamp(1.0, 12.0) as u8;
+    let day = JD - islamic_julian_day(year, month, 1) + 1.0;
+
+    IslamicDate {
+        year,
+        month: islamic_month_frm_num(month),
+        day: day as u8,
+    }
+}
+
+/**
+Computes the `Date` corresponding to a date in the Islamic calendar
+
+This is the inverse of
+[gregorian_to_islamic()](./fn.gregorian_to_islamic.html), for the
+same tabular Islamic calendar. The `Date` is in the Julian calendar
+before 1582 October 15, and in the Gregorian calendar from then on.
+
+# Returns
+
+* `date`: The date, at 0h
+
+# Arguments
+
+* `islamic_date`: Date in the Islamic calendar
+**/
+pub fn islamic_to_gregorian<'a>(islamic_date: &IslamicDate) -> Result<Date, &'a str> {
+    Date::from_julian_day(islamic_julian_day(
+        islamic_date.year,
+        islamic_date.month as u8,
+        islamic_date.day,
+    ))
+}
+
+// Computes the Julian day at 0h of a date in the tabular Islamic
+// calendar, whose epoch is 622 July 16 in the Julian calendar
+fn islamic_julian_day(year: i32, month: u8, day: u8) -> f64 {
+    day as f64
+        + (29.5 * (month as f64 - 1.0)).ceil()
+        + 354.0 * (year as f64 - 1.0)
+        + ((3.0 + 11.0 * year as f64) / 30.0).floor()
+        + 1948439.5
+        - 1.0
+}
+
+// Computes the IslamicMonth corresponding to its number, from 1 to 12
+fn islamic_month_frm_num(month: u8) -> IslamicMonth {
+    match month {
+        1 => IslamicMonth::Muharram,
+        2 => IslamicMonth::Safar,
+        3 => IslamicMonth::RabiAlAwwal,
+        4 => IslamicMonth::RabiAlThani,
+        5 => IslamicMonth::JumadaAlAwwal,
+        6 => IslamicMonth::JumadaAlThani,
+        7 => IslamicMonth::Rajab,
+        8 => IslamicMonth::Shaban,
+        9 => IslamicMonth::Ramadan,
+        10 => IslamicMonth::Shawwal,
+        11 => IslamicMonth::DhuAlQidah,
+        _ => IslamicMonth::DhuAlHijjah,
+    }
+}
+
+/**
+Computes the Modified Julian day from a `Date`
+
+The Modified Julian day is the Julian day less 2400000.5, and so
+begins at midnight.
+
+# Arguments
+
+* `date`: A `Date`
+**/
+#[inline]
+pub fn modified_julian_day(date: &Date) -> f64 {
+    mjd_frm_julian_day(julian_day(date))
+}
+
+/**
+Computes the `Date` corresponding to a Modified Julian day
+
+The calendar is Julian before 1582 October 15, and Gregorian from
+then on.
+
+# Arguments
+
+* `MJD`: Modified Julian day. **Can't be less than -2400000.5**
+**/
+pub fn date_from_mjd<'a>(MJD: f64) -> Result<Date, &'a str> {
+    Date::from_julian_day(julian_day_frm_mjd(MJD))
+}
+
+/**
+Computes the Julian day from a Modified Julian day
+
+# Arguments
+
+* `MJD`: Modified Julian day
+**/
+#[inline]
+pub fn julian_day_frm_mjd(MJD: f64) -> f64 {
+    MJD + 2400000.5
+}
+
+/**
+Computes the Modified Julian day from a Julian day
+
+# Arguments
+
+* `JD`: Julian day
+**/
+#[inline]
+pub fn mjd_frm_julian_day(JD: f64) -> f64 {
+    JD - 2400000.5
+}
+
+/**
+Computes the Julian day of a Besselian epoch
+
+A Besselian epoch is counted in tropical years of 365.242198781 days
+from B1900.0, which is JD 2415020.31352. Eg: B1950.0 is
+JD 2433282.4235.
+
+# Arguments
+
+* `B`: Besselian epoch. Eg: `1950.0` for B1950.0.
+**/
+#[inline]
+pub fn julian_day_frm_besselian_epoch(B: f64) -> f64 {
+    2415020.31352 + (B - 1900.0) * 365.242198781
+}
+
+/**
+Computes the Besselian epoch of a Julian day
+
+This is the inverse of
+[julian_day_frm_besselian_epoch()](./fn.julian_day_frm_besselian_epoch.html).
+
+# Arguments
+
+* `JD`: Julian (Ephemeris) day
+**/
+#[inline]
+pub fn besselian_epoch_frm_julian_day(JD: f64) -> f64 {
+    1900.0 + (JD - 2415020.31352) / 365.242198781
+}
+
+/**
+Computes the Julian day of a Julian epoch
+
+A Julian epoch is counted in Julian years of exactly 365.25 days
+from J2000.0, which is JD 2451545.0.
+
+# Arguments
+
+* `J`: Julian epoch. Eg: `2000.0` for J2000.0.
+**/
+#[inline]
+pub fn julian_day_frm_julian_epoch(J: f64) -> f64 {
+    2451545.0 + (J - 2000.0) * 365.25
+}
+
+/**
+Computes the Julian epoch of a Julian day
+
+This is the inverse of
+[julian_day_frm_julian_epoch()](./fn.julian_day_frm_julian_epoch.html).
+
+# Arguments
+
+* `JD`: Julian (Ephemeris) day
+**/
+#[inline]
+pub fn julian_epoch_frm_julian_day(JD: f64) -> f64 {
+    2000.0 + (JD - 2451545.0) / 365.25
+}
+
+/**
+Computes Julian day from a `Date`
+
+# Arguments
+
+`date`: A `Date`
+**/
+pub fn julian_day(date: &Date) -> f64 {
+    let month = date.month as u8;
+    let (y, m) = if month == 1 || month == 2 {
+        ((date.year - 1) as f64, (month + 12) as f64)
+    } else {
+        (date.year as f64, month as f64)
+    };
+
+    let a = (y / 100.0).floor();
+    let b = match date.cal_type {
+        CalType::Gregorian => 2.0 - a + (a / 4.0).floor(),
+        CalType::Julian => 0.0,
+    };
+
+    (365.25 * (y + 4716.0)).floor() + (30.6001 * (m + 1.0)).floor() + date.decimal_day + b - 1524.5
+}
+
+/**
+Computes the Julian Ephemeris day
+
+# Arguments
+
+* `JD`     : Julian day
+* `delta_t`: Delta T
+**/
+#[inline]
+pub fn julian_ephemeris_day(JD: f64, delta_t: f64) -> f64 {
+    delta_t / 86400.0 + JD
+}
+
+/**
+Computes a year, month and decimal day equivalent to a given Julian day
+
+# Returns
+
+`(year, month, decimal_day)`
+
+* `year`       : Year
+* `month`      : Month
+* `decimal_day`: Decimal day
+
+A Julian day whose year doesn't fit in an `i16`, that is one after
+the year 32767, gives an error.
+
+# Arguments
+
+`JD`: Julian Day. **Can't be a negative value.**
+**/
+pub fn date_frm_julian_day<'a>(mut JD: f64) -> Result<(i16, u8, f64), &'a str> {
+    if JD < 0.0 {
+        return Err("A negative value for JD was passed to time::date_frm_julian_day()");
+    }
+
+    JD += 0.5;
+    let Z = JD as i64;
+    let F = JD - (Z as f64);
+
+    let A = if Z < 2299161 {
+        Z
+    } else {
+        let alpha = (((Z as f64) - 1867216.25) / 36524.25).floor() as i64;
+        Z + 1 + alpha - (((alpha as f64) / 4.0).floor() as i64)
+    };
+
+    let B = A + 1524;
+    let C = (((B as f64) - 122.1) / 365.25).floor() as i64;
+    let D = (365.25 * (C as f64)).floor() as i64;
+    let E = (((B - D) as f64) / 30.6001).floor() as i64;
+
+    let day = ((B - D) as f64) - (30.6001 * (E as f64)).floor() + F;
+
+    let month = if E < 14 {
+        E - 1
+    } else if E == 14 || E == 15 {
+        E - 13
+    } else {
+        return Err("Internal error in time::date_frm_julian_day()");
+    };
+
+    let year = if month > 2 {
+        C - 4716
+    } else if month == 1 || month == 2 {
+        C - 4715
+    } else {
+        return Err("Internal error in time::date_frm_julian_day()");
+    };
+
+    let year = i16::try_from(year)
+        .map_err(|_| "The year is out of range in time::date_frm_julian_day()")?;
+
+    Ok((year, month as u8, day))
+}
+
+/**
+Computes apparent sidereal time from the mean sidereal time
+
+# Returns
+
+* `apprnt_sidr`: Apparent sidereal time *| in radians*
+
+# Arguments
+
+* `mn_sidr`    : Mean sidereal time *| in radians*
+* `nut_in_long`: Nutatation in longitude *| in radians*
+* `true_oblq`  : True obliquity of the ecliptic *| in radians*
+**/
+pub fn apprnt_sidr(mn_sidr: f64, nut_in_long: f64, true_oblq: f64) -> f64 {
+    mn_sidr + nut_in_long * true_oblq.cos()
+}
+
+/**
+Computes apparent sidereal time for a Julian day
+
+This functions uses internally J. Laskar's formula for
+computing the obliquity of the ecliptic.
+
+# Returns
+
+* `apprnt_sidr`: Apparent sidereal time *| in radians*
+
+# Arguments
+
+* `$JD`: Julian day
+**/
+#[macro_export]
+macro_rules! apprnt_sidr {
+    ($JD: expr) => {{
+        let (nut_in_long, nut_in_oblq) = astro::nutation::nutation($JD);
+        let eclip_oblq = astro::ecliptic::mn_oblq_laskar($JD);
+        astro::time::apprnt_sidr(
+            astro::time::mn_sidr($JD),
+            nut_in_long,
+            eclip_oblq + nut_in_oblq,
+        )
+    }};
+}
+
+/**
+Computes mean sidereal time for a Julian day
+
+# Returns
+
+* `mn_sidr`: Mean sidereal time *| in radians*
+
+# Arguments
+
+* `JD`: Julian day
+**/
+pub fn mn_sidr(JD: f64) -> f64 {
+    let JC = julian_cent(JD);
+
+    angle::limit_to_360(
+        280.46061837 + 360.98564736629 * (JD - 2451545.0)
+            + JC * JC * (0.000387933 - JC / 38710000.0),
+    ).to_radians()
+}
+
+/**
+Computes an approximate value of ΔT for a given year and month
+
+This is [delta_t_frm_decimal_year()](./fn.delta_t_frm_decimal_year.html)
+for the middle of the month.
+
+# Arguments
+
+* `year` : Year
+* `month`: Month *range: 1 - 12*
+**/
+#[inline]
+pub fn delta_t(year: i32, month: u8) -> f64 {
+    delta_t_frm_decimal_year((year as f64) + ((month as f64) - 0.5) / 12.0)
+}
+
+/**
+Computes an approximate value of ΔT for a decimal year
+
+This function approximates ΔT from the polynomial expressions of
+F. Espenak and J. Meeus, a method different from that given in the
+*Meeus* book. The method is given
+[here](http://eclipse.gsfc.nasa.gov/SEcat5/deltatpoly.html); it covers
+a far wider time range, and is more accurate.
+
+The polynomials cover the years -500 to +2150. Outside those years,
+ΔT is extrapolated by the parabola `-20 + 32u²` of L. V. Morrison and
+F. R. Stephenson, where `u` is the number of centuries since 1820.
+From 2005 on, ΔT is a prediction.
+
+# Returns
+
+* `delta_t`: ΔT *| in seconds*
+
+# Arguments
+
+* `y`: Decimal year, as from [decimal_year()](./fn.decimal_year.html).
+  Eg: `2000.5` for the middle of 2000.
+**/
+pub fn delta_t_frm_decimal_year(y: f64) -> f64 {
+    if y < -500.0 {
+        let u = (y - 1820.0) / 100.0;
+        return 32.0 * u * u - 20.0;
+    } else if y < 500.0 {
+        let u = y / 100.0;
+        return 10583.6
+            - u
+                * (1014.41
+                    - u
+                        * (33.78311
+                            - u
+                                * (5.952053
+                                    + u * (0.1798452 - u * (0.022174192 + u * 0.0090316521)))));
+    } else if y < 1600.0 {
+        let u = (y - 1000.0) / 100.0;
+        return 1574.2
+            - u
+                * (556.01
+                    - u
+                        * (71.23472
+                            + u
+                                * (0.319781
+                                    - u * (0.8503463 + u * (0.005050998 - u * 0.0083572073)))));
+    } else if y < 1700.0 {
+        let u = y - 1600.0;
+        return 120.0 - u * (0.9808 + u * (0.01532 - u / 7129.0));
+    } else if y < 1800.0 {
+        let u = y - 1700.0;
+        return 8.83 + u * (0.1603 - u * (0.0059285 - u * (0.00013336 - u / 1174000.0)));
+    } else if y < 1860.0 {
+        let u = y - 1800.0;
+        return 13.72
+            - u
+                * (0.332447
+                    - u
+                        * (0.0068612
+                            + u
+                                * (0.0041116
+                                    - u
+                                        * (0.00037436
+                                            - u
+                                                * (0.0000121272
+                                                    - u * (0.0000001699 - u * 0.000000000875))))));
+    } else if y < 1900.0 {
+        let u = y - 1860.0;
+        return 7.62
+            + u * (0.5737 - u * (0.251754 - u * (0.01680668 - u * (0.0004473624 - u / 233174.0))));
+    } else if y < 1920.0 {
+        let u = y - 1900.0;
+        return -2.79 + u * (1.494119 - u * (0.0598939 - u * (0.0061966 - u * 0.000197)));
+    } else if y < 1941.0 {
+        let u = y - 1920.0;
+        return 21.20 + u * (0.84493 - u * (0.076100 - u * 0.0020936));
+    } else if y < 1961.0 {
+        let u = y - 1950.0;
+        return 29.07 + u * (0.407 - u * ((1.0 / 233.0) - u / 2547.0));
+    } else if y < 1986.0 {
+        let u = y - 1975.0;
+        return 45.45 + u * (1.067 - u * ((1.0 / 260.0) + u / 718.0));
+    } else if y < 2005.0 {
+        let u = y - 2000.0;
+        return 63.86
+            + u
+                * (0.3345
+                    - u * (0.060374 - u * (0.0017275 + u * (0.000651814 + u * 0.00002373599))));
+    } else if y < 2050.0 {
+        let u = y - 2000.0;
+        return 62.92 + u * (0.32217 + u * 0.005589);
+    } else if y <= 2150.0 {
+        let u = (y - 1820.0) / 100.0;
+        return 32.0 * u * u - 20.0 - 0.5628 * (2150.0 - y);
+    } else if y > 2150.0 {
+        let u = (y - 1820.0) / 100.0;
+        return 32.0 * u * u - 20.0;
+    }
+
+    0.0
+}
@@ -0,0 +1,181 @@
+/*
+Copyright (c) 2015, 2016 Saurav Sachidanand
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+//! Conversions between the scales of time
+//!
+//! The functions here convert Julian days between Terrestrial Time
+//! (TT), International Atomic Time (TAI), Universal Time (UT1) and
+//! Coordinated Universal Time (UTC). Throughout the crate, a "Julian
+//! (Ephemeris) day" is in TT, and a plain "Julian day" is in UT1.
+//!
+//! TT runs ahead of TAI by exactly 32.184 seconds. UTC keeps to TAI
+//! but for whole leap seconds, so that it stays within 0.9 seconds of
+//! UT1, which follows the irregular rotation of the Earth. Neither
+//! TAI - UTC nor ΔT = TT - UT1 can be computed exactly, so they're
+//! taken from the caller: TAI - UTC from the IERS's table of leap
+//! seconds, and ΔT from the IERS for the past, or from a model such
+//! as [time::delta_t()](../fn.delta_t.html) otherwise. A Julian day
+//! can't represent an instant inside a leap second.
+
+/// TT - TAI *| in seconds*
+pub const TT_MINUS_TAI: f64 = 32.184;
+
+/**
+Computes the Julian day in TT from the Julian day in TAI
+
+# Arguments
+
+* `JD_TAI`: Julian day in TAI
+**/
+#[inline]
+pub fn tt_frm_tai(JD_TAI: f64) -> f64 {
+    JD_TAI + TT_MINUS_TAI / 86400.0
+}
+
+/**
+Computes the Julian day in TAI from the Julian (Ephemeris) day in TT
+
+# Arguments
+
+* `JD_TT`: Julian (Ephemeris) day in TT
+**/
+#[inline]
+pub fn tai_frm_tt(JD_TT: f64) -> f64 {
+    JD_TT - TT_MINUS_TAI / 86400.0
+}
+
+/**
+Computes the Julian day in TAI from the Julian day in UTC
+
+# Arguments
+
+* `JD_UTC`       : Julian day in UTC
+* `tai_minus_utc`: TAI - UTC, the number of leap seconds *| in seconds*.
+  Eg: `37.0` from 2017 on.
+**/
+#[inline]
+pub fn tai_frm_utc(JD_UTC: f64, tai_minus_utc: f64) -> f64 {
+    JD_UTC + tai_minus_utc / 86400.0
+}
+
+/**
+Computes the Julian day in UTC from the Julian day in TAI
+
+# Arguments
+
+* `JD_TAI`       : Julian day in TAI
+* `tai_minus_utc`: TAI - UTC, the number of leap seconds *| in seconds*
+**/
+#[inline]
+pub fn utc_frm_tai(JD_TAI: f64, tai_minus_utc: f64) -> f64 {
+    JD_TAI - tai_minus_utc / 86400.0
+}
+
+/**
+Computes the Julian (Ephemeris) day in TT from the Julian day in UT1
+
+This is the same as
+[time::julian_ephemeris_day()](../fn.julian_ephemeris_day.html).
+
+# Arguments
+
+* `JD_UT1` : Julian day in UT1
+* `delta_t`: ΔT = TT - UT1 *| in seconds*
+**/
+#[inline]
+pub fn tt_frm_ut1(JD_UT1: f64, delta_t: f64) -> f64 {
+    JD_UT1 + delta_t / 86400.0
+}
+
+/**
+Computes the Julian day in UT1 from the Julian (Ephemeris) day in TT
+
+# Arguments
+
+* `JD_TT`  : Julian (Ephemeris) day in TT
+* `delta_t`: ΔT = TT - UT1 *| in seconds*
+**/
+#[inline]
+pub fn ut1_frm_tt(JD_TT: f64, delta_t: f64) -> f64 {
+    JD_TT - delta_t / 86400.0
+}
+
+/**
+Computes the Julian (Ephemeris) day in TT from the Julian day in UTC,
+through ΔT
+
+UTC is taken to be UT1, which is off by at most 0.9 seconds. For TT
+exact to the leap second, use [tai_frm_utc()](./fn.tai_frm_utc.html)
+and [tt_frm_tai()](./fn.tt_frm_tai.html) instead.
+
+# Arguments
+
+* `JD_UTC` : Julian day in UTC
+* `delta_t`: ΔT = TT - UT1 *| in seconds*
+**/
+#[inline]
+pub fn tt_frm_utc(JD_UTC: f64, delta_t: f64) -> f64 {
+    tt_frm_ut1(JD_UTC, delta_t)
+}
+
+/**
+Computes the Julian day in UTC from the Julian (Ephemeris) day in TT,
+through ΔT
+
+This is the inverse of [tt_frm_utc()](./fn.tt_frm_utc.html), and also
+takes UTC to be UT1.
+
+# Arguments
+
+* `JD_TT`  : Julian (Ephemeris) day in TT
+* `delta_t`: ΔT = TT - UT1 *| in seconds*
+**/
+#[inline]
+pub fn utc_frm_tt(JD_TT: f64, delta_t: f64) -> f64 {
+    ut1_frm_tt(JD_TT, delta_t)
+}
+
+/**
+Computes the Julian day in UT1 from the Julian day in UTC
+
+# Arguments
+
+* `JD_UTC`: Julian day in UTC
+* `dut1`  : DUT1 = UT1 - UTC, as published by the IERS *| in seconds*
+**/
+#[inline]
+pub fn ut1_frm_utc(JD_UTC: f64, dut1: f64) -> f64 {
+    JD_UTC + dut1 / 86400.0
+}
+
+/**
+Computes the Julian day in UTC from the Julian day in UT1
+
+# Arguments
+
+* `JD_UT1`: Julian day in UT1
+* `dut1`  : DUT1 = UT1 - UTC, as published by the IERS *| in seconds*
+**/
+#[inline]
+pub fn utc_frm_ut1(JD_UT1: f64, dut1: f64) -> f64 {
+    JD_UT1 - dut1 / 86400.0
+}
@@ -24,7 +24,13 @@ THE SOFTWARE.
 
 use angle;
 use coords;
+use ecliptic;
 use interpol;
+use lunar;
+use nutation;
+use parallax;
+use sidereal;
+use time;
 
 /// Represents a celestial body in transit
 pub enum TransitBody {
@@ -82,11 +88,7 @@ pub fn time(
     delta_t: f64,
     moon_eq_hz_parallax: f64,
 ) -> (i64, i64, f64) {
-    let h0 = match transit_body {
-        &TransitBody::StarOrPlanet => -0.5667_f64.to_radians(),
-        &TransitBody::Sun => -0.8333_f64.to_radians(),
-        &TransitBody::Moon => 0.7275 * moon_eq_hz_parallax - 0.5667_f64.to_radians(),
-    };
+    let h0 = std_alt(transit_body, moon_eq_hz_parallax);
 
     let mut H0 = ((h0.sin() - geograph_point.lat.sin() * eq_point2.dec.sin())
         / (geograph_point.lat.cos() * eq_point2.dec.cos()))
@@ -116,12 +118,11 @@ pub fn time(
         &TransitType::Set => interpol::three_values(eq_point1.dec, eq_point2.dec, eq_point3.dec, d),
     };
 
-    let mut H = coords::hr_angl_frm_observer_long(theta0, geograph_point.long, asc).to_degrees();
-    H = angle::limit_to_360(H);
-    if H > 180.0 {
-        H -= 360.0;
-    }
-    H = H.to_radians();
+    let H = angle::limit_to_pm_PI(coords::hr_angl_frm_observer_long(
+        theta0,
+        geograph_point.long,
+        asc,
+    ));
 
     let h = match transit_type {
         &TransitType::Transit => 0.0,
@@ -148,6 +149,340 @@ pub fn time(
     (hour, minute, second)
 }
 
+/**
+Computes the time of transit for a celestial body, in local time
+
+This is the same as [time()](./fn.time.html), with the time of
+transit shifted from UTC by `utc_offset_hours`. The shift may carry
+the time over midnight, into the day before or after the day of
+interest.
+
+# Returns
+
+`(day_shift, hour, min, sec)`
+
+* `day_shift`       : Day of the local time of transit, relative to
+                      the day of interest: `-1`, `0` or `1`
+* `(hour, min, sec)`: Time of transit on that day, in local time
+
+# Arguments
+
+* `utc_offset_hours`: Offset of the local time from UTC *| in hours*.
+                      Eg: 5.5 for Indian Standard Time.
+
+The other arguments are those of [time()](./fn.time.html).
+**/
+pub fn time_local(
+    transit_type: &TransitType,
+    transit_body: &TransitBody,
+    geograph_point: &coords::GeographPoint,
+    eq_point1: &coords::EqPoint,
+    eq_point2: &coords::EqPoint,
+    eq_point3: &coords::EqPoint,
+    apprnt_greenwhich_sidr: f64,
+    delta_t: f64,
+    moon_eq_hz_parallax: f64,
+    utc_offset_hours: f64,
+) -> (i64, i64, i64, f64) {
+    let (hour, minute, second) = time(
+        transit_type,
+        transit_body,
+        geograph_point,
+        eq_point1,
+        eq_point2,
+        eq_point3,
+        apprnt_greenwhich_sidr,
+        delta_t,
+        moon_eq_hz_parallax,
+    );
+
+    let mut h = hour as f64 + minute as f64 / 60.0 + second / 3600.0 + utc_offset_hours;
+    let day_shift = (h / 24.0).floor();
+    h -= day_shift * 24.0;
+
+    let hour = h as i64;
+    let m = (h - (hour as f64)) * 60.0;
+    let minute = m as i64;
+    let second = (m - (minute as f64)) * 60.0;
+
+    (day_shift as i64, hour, minute, second)
+}
+
+/**
+Computes the azimuths at which a celestial body rises and sets
+
+# Returns
+
+`(rise_az, set_az)`
+
+* `rise_az`: Azimuth of the point of rising, measured eastwards
+             from the North *| in radians*
+* `set_az` : Azimuth of the point of setting, measured eastwards
+             from the North *| in radians*
+
+An `Err` is returned if the body is circumpolar or never rises
+for the observer.
+
+# Arguments
+
+* `transit_body`  : The `TransitBody`
+* `geograph_point`: Geographic point of the observer *| in radians*
+* `eq_point2`     : Equatorial point of the transit body on the day of
+                    interest *| in radians*
+* `moon_eq_hz_parallax`: Equatorial horizontal parallax of the Moon
+                         *| in radians*. *Pass a meaningfull value here only when*
+                         `TransitBody::Moon` *is passed for* `transit_body`.
+**/
+pub fn rise_set_azimuth<'a>(
+    transit_body: &TransitBody,
+    geograph_point: &coords::GeographPoint,
+    eq_point2: &coords::EqPoint,
+    moon_eq_hz_parallax: f64,
+) -> Result<(f64, f64), &'a str> {
+    let h0 = std_alt(transit_body, moon_eq_hz_parallax);
+
+    let cos_A = (eq_point2.dec.sin() - h0.sin() * geograph_point.lat.sin())
+        / (h0.cos() * geograph_point.lat.cos());
+    if !(-1.0..=1.0).contains(&cos_A) {
+        return Err("The body is circumpolar or never rises for the observer");
+    }
+
+    let A = cos_A.acos();
+
+    Ok((A, angle::TWO_PI - A))
+}
+
+/**
+Computes the time at which a celestial body reaches an hour angle
+
+The body's equatorial coordinates are taken to be constant over the
+day, which is fine for stars, and for planets to within a few
+seconds.
+
+# Returns
+
+* `time`: Time on the day of interest at which the body reaches
+          the hour angle `target_hr_angl` *| in hours, UTC*
+
+# Arguments
+
+* `eq_point`      : Equatorial point of the body *| in radians*
+* `geograph_point`: Geographic point of the observer *| in radians*
+* `apprnt_greenwhich_sidr`: Apparent sidereal time at Greenwhich at 0h
+                            UT on the day of interest *| in radians*
+* `target_hr_angl`: Hour angle of interest, measured westwards
+                    from the meridian *| in radians*
+**/
+pub fn time_at_hour_angle(
+    eq_point: &coords::EqPoint,
+    geograph_point: &coords::GeographPoint,
+    apprnt_greenwhich_sidr: f64,
+    target_hr_angl: f64,
+) -> f64 {
+    let sidr_diff = angle::limit_to_two_PI(
+        target_hr_angl + geograph_point.long + eq_point.asc - apprnt_greenwhich_sidr,
+    );
+
+    24.0 * sidr_diff / 360.985647_f64.to_radians()
+}
+
+/**
+Computes the time of transit for a celestial body, for each day
+in a range of days
+
+The equatorial coordinates of the body are taken from `ephemeris`
+at 0h dynamical time on the day before, the day of, and the day
+after each day of interest, and right ascensions are unwrapped
+across 0h before interpolating. The apparent sidereal time at
+Greenwhich is computed internally.
+
+# Returns
+
+A `Vec` with one entry for each day. Each entry is either
+
+* `(hour, min, sec)`: Time of transit on that day, in UTC
+
+or an `Err` if the body does not rise or set on that day.
+
+# Arguments
+
+* `transit_type`  : A `TransitType`
+* `transit_body`  : The `TransitBody`. For `TransitBody::Moon`, the
+                    mean equatorial horizontal parallax of the Moon
+                    is used.
+* `geograph_point`: Geographic point of the observer *| in radians*
+* `ephemeris`     : Function returning the equatorial point of the
+                    body for a Julian (Ephemeris) day *| in radians*
+* `start_JD`      : Julian day at 0h UT of the first day
+* `n_days`        : Number of days
+* `delta_t`       : Function returning ΔT (seconds) for a Julian day
+**/
+pub fn series<'a>(
+    transit_type: &TransitType,
+    transit_body: &TransitBody,
+    geograph_point: &coords::GeographPoint,
+    ephemeris: &dyn Fn(f64) -> coords::EqPoint,
+    start_JD: f64,
+    n_days: usize,
+    delta_t: &dyn Fn(f64) -> f64,
+) -> Vec<Result<(i64, i64, f64), &'a str>> {
+    let moon_eq_hz_parallax = 0.9507_f64.to_radians();
+    let h0 = std_alt(transit_body, moon_eq_hz_parallax);
+
+    let mut times = Vec::with_capacity(n_days);
+
+    for day in 0..n_days {
+        let JD = start_JD + day as f64;
+        let dt = delta_t(JD);
+        let JDE = JD + dt / 86400.0;
+
+        let mut eq_point1 = ephemeris(JDE - 1.0);
+        let eq_point2 = ephemeris(JDE);
+        let mut eq_point3 = ephemeris(JDE + 1.0);
+        eq_point1.asc = unwrap_asc(eq_point1.asc, eq_point2.asc);
+        eq_point3.asc = unwrap_asc(eq_point3.asc, eq_point2.asc);
+
+        match *transit_type {
+            TransitType::Transit => {}
+            _ => {
+                let cos_H0 = (h0.sin() - geograph_point.lat.sin() * eq_point2.dec.sin())
+                    / (geograph_point.lat.cos() * eq_point2.dec.cos());
+                if !(-1.0..=1.0).contains(&cos_H0) {
+                    times.push(Err("The body is circumpolar or never rises for the observer"));
+                    continue;
+                }
+            }
+        }
+
+        let apprnt_greenwhich_sidr = sidereal::apparent_sidereal_greenwich(JD);
+
+        times.push(Ok(time(
+            transit_type,
+            transit_body,
+            geograph_point,
+            &eq_point1,
+            &eq_point2,
+            &eq_point3,
+            apprnt_greenwhich_sidr,
+            dt,
+            moon_eq_hz_parallax,
+        )));
+    }
+
+    times
+}
+
+/**
+Computes the time of the Moon's meridian transit as seen by an
+observer on the Earth's surface
+
+The transit is found by iterating on the topocentric hour angle of
+the Moon until it vanishes, with the Moon's position recomputed at
+each step. Since the parallax in right ascension vanishes on the
+meridian, the instant returned agrees with a rigorous geocentric
+transit to about a second; it can however differ by several seconds,
+and sometimes by over a minute, from the one given by
+[time()](./fn.time.html), which interpolates the Moon's fast motion
+from three daily positions.
+
+As the Moon transits about 50 minutes later each day, on one day of
+each lunation there is no transit, and the time returned then exceeds
+24 hours.
+
+# Returns
+
+`(hour, min, sec)`
+
+* `hour`: Hour of transit, in UTC
+* `min` : Minute of transit
+* `sec` : Second of transit
+
+# Arguments
+
+* `date`          : The date of interest, in UTC. The time of day
+                    is ignored.
+* `geograph_point`: Geographic point of the observer *| in radians*
+* `observer_ht`   : Height of the observer above sea level
+                    *| in meters*
+**/
+pub fn moon_topocentric_transit(
+    date: &time::Date,
+    geograph_point: &coords::GeographPoint,
+    observer_ht: f64,
+) -> (i64, i64, f64) {
+    let JD = time::julian_day(&time::Date {
+        year: date.year,
+        month: date.month,
+        decimal_day: date.decimal_day.floor(),
+        cal_type: date.cal_type,
+    });
+    let delta_t = time::delta_t(date.year as i32, date.month as u8);
+
+    let topocent_hr_angl = |m: f64| -> f64 {
+        let JD_m = JD + m;
+        let (nut_in_long, nut_in_oblq) = nutation::nutation(JD_m);
+        let true_oblq = ecliptic::mn_oblq_laskar(JD_m) + nut_in_oblq;
+        let apprnt_greenwhich_sidr = sidereal::apparent_sidereal_greenwich(JD_m);
+
+        let (moon_ecl_point, earth_moon_dist) = lunar::geocent_ecl_pos(JD_m + delta_t / 86400.0);
+        let long = moon_ecl_point.long + nut_in_long;
+        let eq_point = coords::EqPoint {
+            asc: coords::asc_frm_ecl(long, moon_ecl_point.lat, true_oblq),
+            dec: coords::dec_frm_ecl(long, moon_ecl_point.lat, true_oblq),
+        };
+        let topocent_eq_point = parallax::topocent_eq_coords(
+            &eq_point,
+            lunar::eq_hz_parllx(earth_moon_dist),
+            geograph_point,
+            observer_ht,
+            apprnt_greenwhich_sidr,
+        );
+
+        angle::limit_to_pm_PI(coords::hr_angl_frm_observer_long(
+            apprnt_greenwhich_sidr,
+            geograph_point.long,
+            topocent_eq_point.asc,
+        ))
+    };
+
+    // The Moon's hour angle increases by about 347.8 degrees a day
+    let hr_angl_rate = 347.8_f64.to_radians();
+
+    let mut m = -topocent_hr_angl(0.0) / hr_angl_rate;
+    if m < 0.0 {
+        m += angle::TWO_PI / hr_angl_rate;
+    }
+    for _ in 0..10 {
+        let dm = -topocent_hr_angl(m) / hr_angl_rate;
+        m += dm;
+        if dm.abs() < 1e-7 {
+            break;
+        }
+    }
+
+    let h = 24.0 * m;
+    let hour = h as i64;
+    let m = (h - (hour as f64)) * 60.0;
+    let minute = m as i64;
+    let second = (m - (minute as f64)) * 60.0;
+
+    (hour, minute, second)
+}
+
+#[inline]
+fn unwrap_asc(asc: f64, ref_asc: f64) -> f64 {
+    ref_asc + angle::limit_to_pm_PI(asc - ref_asc)
+}
+
+#[inline]
+fn std_alt(transit_body: &TransitBody, moon_eq_hz_parallax: f64) -> f64 {
+    match *transit_body {
+        TransitBody::StarOrPlanet => -0.5667_f64.to_radians(),
+        TransitBody::Sun => -0.8333_f64.to_radians(),
+        TransitBody::Moon => 0.7275 * moon_eq_hz_parallax - 0.5667_f64.to_radians(),
+    }
+}
+
 #[inline]
 fn m(transit_type: &TransitType, H0: f64, asc: f64, L: f64, Theta0: f64) -> f64 {
     let mut m = (asc + L - Theta0) / angle::TWO_PI;
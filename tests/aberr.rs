@@ -43,3 +43,45 @@ fn stell_aberr_in_eq_coords() {
     assert_eq!(util::round_upto_digits(a.to_degrees(), 7), 0.0083223);
     assert_eq!(util::round_upto_digits(b.to_degrees(), 7), 0.0018749);
 }
+
+#[test]
+fn annual_aberr() {
+    // Theta Persei, from Meeus's example 23.a, with its mean
+    // position of the date
+    let d = time::Date {
+        year: 2028,
+        month: time::Month::Nov,
+        decimal_day: 13.19,
+        cal_type: time::CalType::Gregorian,
+    };
+    let stell_eq_point = coords::EqPoint {
+        asc: 41.5599646_f64.to_radians(),
+        dec: 49.3520685_f64.to_radians(),
+    };
+
+    let (a, b) = aberr::annual_aberr(&stell_eq_point, time::julian_day(&d));
+    assert_eq!(util::round_upto_digits(a.to_degrees() * 3600.0, 1), 30.0);
+    assert_eq!(util::round_upto_digits(b.to_degrees() * 3600.0, 1), 6.7);
+
+    // Near the poles the aberration in right ascension grows, but the
+    // displacement on the sky stays under 20.5 arcseconds
+    for &dec in [89.9_f64, 89.9999, -89.99999].iter() {
+        for i in 0..12 {
+            let near_pole = coords::EqPoint {
+                asc: (30.0 * i as f64).to_radians(),
+                dec: dec.to_radians(),
+            };
+            let (a, b) = aberr::annual_aberr(&near_pole, time::julian_day(&d));
+            assert!((a * near_pole.dec.cos()).abs().to_degrees() * 3600.0 <= 20.5);
+            assert!(b.to_degrees().abs() * 3600.0 <= 20.5);
+        }
+    }
+
+    // and at the poles themselves, it's zero
+    for &dec in [std::f64::consts::FRAC_PI_2, -std::f64::consts::FRAC_PI_2].iter() {
+        let pole = coords::EqPoint { asc: 0.0, dec };
+        let (a, b) = aberr::annual_aberr(&pole, time::julian_day(&d));
+        assert_eq!(a, 0.0);
+        assert!(b.to_degrees().abs() * 3600.0 <= 20.5);
+    }
+}
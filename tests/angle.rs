@@ -0,0 +1,150 @@
+/*
+Copyright (c) 2015, 2016 Saurav Sachidanand
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+#![allow(non_snake_case)]
+
+extern crate astro;
+
+use astro::*;
+
+#[test]
+fn sexagesimal() {
+    let dec = angle::deg_frm_dms(41, 16, 9.0).to_radians();
+    let (deg, min, sec) = angle::to_dms(dec);
+    assert_eq!((deg, min, util::round_upto_digits(sec, 6)), (41, 16, 9.0));
+    assert_eq!(angle::fmt_dms(dec), "+41°16'09.0\"");
+
+    let dec = angle::deg_frm_dms(-11, 9, 41.0).to_radians();
+    let (deg, min, sec) = angle::to_dms(dec);
+    assert_eq!((deg, min, util::round_upto_digits(sec, 6)), (-11, 9, 41.0));
+    assert_eq!(angle::fmt_dms(dec), "-11°09'41.0\"");
+    assert_eq!(angle::fmt_dms((-0.5_f64).to_radians()), "-00°30'00.0\"");
+    assert_eq!(
+        angle::fmt_dms(angle::deg_frm_dms(10, 15, 59.97).to_radians()),
+        "+10°16'00.0\""
+    );
+
+    let asc = angle::deg_frm_hms(14, 15, 39.7).to_radians();
+    let (hour, min, sec) = angle::to_hms(asc);
+    assert_eq!((hour, min, util::round_upto_digits(sec, 6)), (14, 15, 39.7));
+    assert_eq!(angle::fmt_hms(asc), "14h15m39.7s");
+    assert_eq!(angle::fmt_hms(-asc), "09h44m20.3s");
+    assert_eq!(
+        angle::fmt_hms(angle::deg_frm_hms(23, 59, 59.97).to_radians()),
+        "00h00m00.0s"
+    );
+}
+
+#[test]
+fn parse_sexagesimal() {
+    let dec = angle::dms_to_radians(41, 16, 9.0);
+    assert_eq!(dec, angle::deg_frm_dms(41, 16, 9.0).to_radians());
+    assert_eq!(angle::parse_dms("+41:16:09"), Ok(dec));
+    assert_eq!(angle::parse_dms(" 41 16 09 "), Ok(dec));
+    assert_eq!(angle::parse_dms("41°16'09\""), Ok(dec));
+    assert_eq!(angle::parse_dms("41d16m09s"), Ok(dec));
+
+    // The sign applies to the whole angle, even with zero degrees
+    let dec = angle::dms_to_radians(0, -30, 0.0);
+    assert_eq!(dec, (-0.5_f64).to_radians());
+    assert_eq!(angle::parse_dms("-00:30:00"), Ok(dec));
+    assert_eq!(angle::parse_dms("-0 30"), Ok(dec));
+    assert_eq!(
+        angle::dms_to_radians(-11, 9, 41.0),
+        angle::deg_frm_dms(-11, 9, 41.0).to_radians()
+    );
+    assert_eq!(angle::parse_dms("-12.5"), Ok((-12.5_f64).to_radians()));
+
+    let asc = angle::hms_to_radians(14, 15, 39.7);
+    assert_eq!(asc, angle::deg_frm_hms(14, 15, 39.7).to_radians());
+    assert_eq!(angle::parse_hms("14h15m39.7s"), Ok(asc));
+    assert_eq!(angle::parse_hms("14:15:39.7"), Ok(asc));
+    assert_eq!(
+        angle::fmt_hms(angle::parse_hms("14h15m39.7s").unwrap()),
+        "14h15m39.7s"
+    );
+
+    assert_eq!(angle::parse_dms(""), Err(angle::ParseAngleError::Format));
+    assert_eq!(angle::parse_dms("-"), Err(angle::ParseAngleError::Format));
+    assert_eq!(
+        angle::parse_dms("41:16:09:01"),
+        Err(angle::ParseAngleError::Format)
+    );
+    assert_eq!(
+        angle::parse_dms("41.5:16"),
+        Err(angle::ParseAngleError::Format)
+    );
+    assert_eq!(
+        angle::parse_dms("41:1x:09"),
+        Err(angle::ParseAngleError::Format)
+    );
+    assert_eq!(
+        angle::parse_dms("41:16:-09"),
+        Err(angle::ParseAngleError::Format)
+    );
+    assert_eq!(
+        angle::parse_dms("41:16:0.9.1"),
+        Err(angle::ParseAngleError::Format)
+    );
+    assert_eq!(
+        angle::parse_dms("41:60:09"),
+        Err(angle::ParseAngleError::Range)
+    );
+    assert_eq!(
+        angle::parse_hms("14h15m60s"),
+        Err(angle::ParseAngleError::Range)
+    );
+}
+
+#[test]
+fn limit_to_pm() {
+    assert_eq!(
+        util::round_upto_digits(angle::limit_to_pm_180(3.0 * 360.0 + 190.0), 9),
+        -170.0
+    );
+    assert_eq!(
+        util::round_upto_digits(angle::limit_to_pm_180(-5.0 * 360.0 - 190.0), 9),
+        170.0
+    );
+    assert_eq!(
+        util::round_upto_digits(angle::limit_to_pm_180(-4.0 * 360.0 - 10.0), 9),
+        -10.0
+    );
+    assert_eq!(angle::limit_to_pm_180(180.0), 180.0);
+    assert_eq!(angle::limit_to_pm_180(-180.0), 180.0);
+
+    let PI = std::f64::consts::PI;
+    assert_eq!(
+        util::round_upto_digits(angle::limit_to_pm_PI(7.0 * PI + 0.5), 9),
+        util::round_upto_digits(-PI + 0.5, 9)
+    );
+    assert_eq!(
+        util::round_upto_digits(angle::limit_to_pm_PI(-6.0 * PI - 0.5), 9),
+        -0.5
+    );
+    assert_eq!(
+        util::round_upto_digits(angle::limit_to_pm_PI(-7.0 * PI + 0.5), 9),
+        util::round_upto_digits(-PI + 0.5, 9)
+    );
+    assert_eq!(angle::limit_to_pm_PI(PI), PI);
+    assert_eq!(angle::limit_to_pm_PI(-PI), PI);
+}
@@ -0,0 +1,85 @@
+/*
+Copyright (c) 2015, 2016 Saurav Sachidanand
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+extern crate astro;
+use astro::*;
+
+#[test]
+fn refraction() {
+    for &(pressure, temp) in [(1010.0, 10.0), (900.0, -10.0), (1030.0, 30.0)].iter() {
+        let true_alt = 45_f64.to_radians();
+        let apprnt_alt = true_alt + atmos::refraction_from_true(true_alt, pressure, temp);
+        let back = apprnt_alt - atmos::refraction_from_apparent(apprnt_alt, pressure, temp);
+
+        // The two formulas differ by about 1.1 arcseconds at 45 degrees
+        assert!((back - true_alt).to_degrees().abs() * 3600.0 < 1.5);
+    }
+
+    // About 34 arcminutes at the horizon, under standard conditions
+    let horizon = atmos::refraction_from_apparent(0.0, 1010.0, 10.0);
+    assert_eq!(util::round_upto_digits(horizon.to_degrees() * 60.0, 0), 34.0);
+    assert_eq!(
+        atmos::refraction_from_apparent(0.0, 1010.0, 10.0),
+        atmos::refrac_frm_apprnt_alt(0.0)
+    );
+
+    // Clamped below the horizon
+    assert_eq!(
+        atmos::refraction_from_apparent(-10_f64.to_radians(), 1010.0, 10.0),
+        atmos::refraction_from_apparent(-1_f64.to_radians(), 1010.0, 10.0)
+    );
+    assert!(atmos::refraction_from_true(-10_f64.to_radians(), 1010.0, 10.0).is_finite());
+}
+
+#[test]
+fn airmass_and_extinction() {
+    assert_eq!(
+        util::round_upto_digits(atmos::airmass(90_f64.to_radians()), 3),
+        1.0
+    );
+    assert_eq!(
+        util::round_upto_digits(atmos::airmass(30_f64.to_radians()), 2),
+        1.99
+    );
+    assert_eq!(util::round_upto_digits(atmos::airmass(0.0), 1), 37.9);
+
+    // Grows smoothly towards the horizon, and stays below the secant
+    // of the zenith distance
+    let mut last = atmos::airmass(90_f64.to_radians());
+    for i in 1..90 {
+        let alt = (90.0 - i as f64).to_radians();
+        let airmass = atmos::airmass(alt);
+        assert!(airmass > last && airmass < 1.5 * last);
+        assert!(airmass <= 1.0 / alt.sin());
+        last = airmass;
+    }
+
+    // and saturates below it
+    assert_eq!(atmos::airmass(-5_f64.to_radians()), atmos::airmass(0.0));
+
+    let k = 0.2;
+    assert_eq!(atmos::extinction(1.0, k), 0.2);
+    assert_eq!(
+        util::round_upto_digits(atmos::extinction(atmos::airmass(30_f64.to_radians()), k), 2),
+        0.4
+    );
+}
@@ -0,0 +1,64 @@
+/*
+Copyright (c) 2015, 2016 Saurav Sachidanand
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+#![allow(non_snake_case)]
+
+extern crate astro;
+
+use astro::*;
+
+#[test]
+fn apparent_position() {
+    // Meeus's example 57.a, η Coronae Borealis
+    let elements = binary_star::BinaryElements {
+        P: 41.623,
+        T: 1934.008,
+        a: 0.907,
+        e: 0.2763,
+        i: 59.025_f64.to_radians(),
+        asc_node: 23.717_f64.to_radians(),
+        w: 219.907_f64.to_radians(),
+    };
+    let (pos_angl, sepr) =
+        binary_star::apparent_position(&elements, time::julian_day_frm_julian_epoch(1980.0));
+
+    assert_eq!(util::round_upto_digits(pos_angl.to_degrees(), 1), 318.4);
+    assert_eq!(util::round_upto_digits(sepr, 3), 0.411);
+
+    // A highly eccentric orbit, seen face-on, at apastron and periastron
+    let elements = binary_star::BinaryElements {
+        P: 100.0,
+        T: 2000.0,
+        a: 1.0,
+        e: 0.995,
+        i: 0.0,
+        asc_node: 0.0,
+        w: 0.0,
+    };
+    let (_, sepr) =
+        binary_star::apparent_position(&elements, time::julian_day_frm_julian_epoch(2050.0));
+    assert_eq!(util::round_upto_digits(sepr, 9), 1.995);
+    let (pos_angl, sepr) =
+        binary_star::apparent_position(&elements, time::julian_day_frm_julian_epoch(2100.0));
+    assert_eq!(util::round_upto_digits(sepr, 9), 0.005);
+    assert_eq!(util::round_upto_digits(pos_angl, 9), 0.0);
+}
@@ -0,0 +1,362 @@
+/*
+Copyright (c) 2015, 2016 Saurav Sachidanand
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+#![allow(non_snake_case)]
+
+extern crate astro;
+use astro::*;
+
+#[test]
+fn field_rotation_angle() {
+    let eq_point = coords::EqPoint {
+        asc: 90_f64.to_radians(),
+        dec: -10_f64.to_radians(),
+    };
+    let geograph_point = coords::GeographPoint {
+        long: 0.0,
+        lat: 45_f64.to_radians(),
+    };
+
+    let on_meridian = coords::field_rotation_angle(&eq_point, &geograph_point, eq_point.asc);
+    assert_eq!(util::round_upto_digits(on_meridian, 10), 0.0);
+
+    let east = coords::field_rotation_angle(&eq_point, &geograph_point, 60_f64.to_radians());
+    let west = coords::field_rotation_angle(&eq_point, &geograph_point, 120_f64.to_radians());
+    assert!(east < 0.0);
+    assert!(west > 0.0);
+    assert_eq!(util::round_upto_digits(east + west, 10), 0.0);
+}
+
+#[test]
+fn field_rotation_rate() {
+    let geograph_point = coords::GeographPoint {
+        long: 0.0,
+        lat: 45_f64.to_radians(),
+    };
+    let eq_point = coords::EqPoint {
+        asc: 0.0,
+        dec: 43_f64.to_radians(),
+    };
+
+    let near_transit = coords::field_rotation_rate(&eq_point, &geograph_point, 0.0);
+    let near_hz = coords::field_rotation_rate(&eq_point, &geograph_point, 150_f64.to_radians());
+    assert!(near_transit.abs() > near_hz.abs());
+
+    // Compare with a numerical derivative of the field rotation angle
+    let dt = 1.0;
+    let dH = planet::earth::rot_angular_velocity() * dt;
+    let sidr = 30_f64.to_radians();
+    let q1 = coords::field_rotation_angle(&eq_point, &geograph_point, sidr - dH);
+    let q2 = coords::field_rotation_angle(&eq_point, &geograph_point, sidr + dH);
+    let rate = coords::field_rotation_rate(&eq_point, &geograph_point, sidr);
+    assert_eq!(
+        util::round_upto_digits((q2 - q1) / (2.0 * dt) / rate, 6),
+        1.0
+    );
+}
+
+#[test]
+fn obliquity_rotation_matrix() {
+    let (ecl_long, ecl_lat) = (113.215630_f64.to_radians(), 6.684170_f64.to_radians());
+    let oblq_eclip = 23.4392911_f64.to_radians();
+
+    let ecl_vector = [
+        ecl_lat.cos() * ecl_long.cos(),
+        ecl_lat.cos() * ecl_long.sin(),
+        ecl_lat.sin(),
+    ];
+    let matrix = coords::obliquity_rotation_matrix(oblq_eclip);
+    let eq_vector = coords::apply_matrix(&matrix, &ecl_vector);
+
+    let asc = coords::asc_frm_ecl(ecl_long, ecl_lat, oblq_eclip);
+    let dec = coords::dec_frm_ecl(ecl_long, ecl_lat, oblq_eclip);
+
+    assert_eq!(
+        util::round_upto_digits(angle::limit_to_two_PI(eq_vector[1].atan2(eq_vector[0])), 10),
+        util::round_upto_digits(angle::limit_to_two_PI(asc), 10)
+    );
+    assert_eq!(
+        util::round_upto_digits(eq_vector[2].asin(), 10),
+        util::round_upto_digits(dec, 10)
+    );
+}
+
+#[test]
+fn precession_matrix() {
+    // Theta Persei (Meeus, example 21.b)
+    let (asc, dec) = (41.054063_f64.to_radians(), 49.227750_f64.to_radians());
+    let (JD1, JD2) = (2451545.0, 2462088.69);
+
+    let vector = [dec.cos() * asc.cos(), dec.cos() * asc.sin(), dec.sin()];
+    let matrix = precess::precession_matrix_eq(JD1, JD2);
+    let new_vector = coords::apply_matrix(&matrix, &vector);
+
+    let (new_asc, new_dec) = precess::precess_eq_coords(asc, dec, JD1, JD2);
+
+    assert_eq!(
+        util::round_upto_digits(new_vector[1].atan2(new_vector[0]), 10),
+        util::round_upto_digits(new_asc, 10)
+    );
+    assert_eq!(
+        util::round_upto_digits(new_vector[2].asin(), 10),
+        util::round_upto_digits(new_dec, 10)
+    );
+}
+
+#[test]
+fn galactic_center_horiz() {
+    let geograph_point = coords::GeographPoint {
+        long: 0.0,
+        lat: 40_f64.to_radians(),
+    };
+    let dec = angle::deg_frm_dms(-29, 0, 28.1);
+
+    let max_alt = (0..3600)
+        .map(|i| {
+            let local_sidr = (i as f64 / 10.0).to_radians();
+            coords::galactic_center_horiz(&geograph_point, local_sidr).alt
+        })
+        .fold(std::f64::MIN, f64::max);
+    assert_eq!(
+        util::round_upto_digits(max_alt.to_degrees(), 4),
+        util::round_upto_digits(90.0 - (40.0 - dec), 4)
+    );
+
+    // On the meridian, due South
+    let local_sidr = angle::deg_frm_hms(17, 45, 40.04).to_radians();
+    let horiz_point = coords::galactic_center_horiz(&geograph_point, local_sidr);
+    assert_eq!(util::round_upto_digits(horiz_point.az, 10), 0.0);
+}
+
+#[test]
+fn separation_rate() {
+    // A body moving along the equator passes a fixed one, and is
+    // nearest to it on day 5
+    let moving = |day: f64| coords::EqPoint {
+        asc: (0.01 * day).to_radians(),
+        dec: 0.0,
+    };
+    let fixed = coords::EqPoint {
+        asc: 0.05_f64.to_radians(),
+        dec: 0.001_f64.to_radians(),
+    };
+
+    let rate = |day: f64| {
+        coords::separation_rate(
+            &moving(day - 0.01),
+            &moving(day + 0.01),
+            &fixed,
+            &fixed,
+            0.02,
+        )
+    };
+
+    assert_eq!(util::round_upto_digits(rate(5.0).to_degrees(), 6), 0.0);
+    assert!(rate(2.0) < 0.0);
+    assert_eq!(util::round_upto_digits(rate(8.0).to_degrees(), 4), 0.01);
+}
+
+#[test]
+fn vernal_equinox_direction() {
+    let direction = coords::vernal_equinox_direction(2451545.0);
+    assert_eq!(util::round_upto_digits(direction[0], 12), 1.0);
+
+    // A century after J2000.0, the equinox has moved westwards by
+    // about 50.3 arcseconds a year, or 46.1 a year in right ascension
+    let direction = coords::vernal_equinox_direction(2451545.0 + 36525.0);
+    let drift = direction[0].acos().to_degrees() * 3600.0 / 100.0;
+    let asc_drift = direction[1].atan2(direction[0]).to_degrees() * 3600.0 / 100.0;
+    assert_eq!(util::round_upto_digits(drift, 1), 50.3);
+    assert_eq!(util::round_upto_digits(asc_drift, 1), -46.1);
+}
+
+#[test]
+fn galactic_eq() {
+    // The radio source Sgr A*, near the Galactic center
+    let eq_point = coords::EqPoint {
+        asc: angle::deg_frm_hms(17, 45, 40.04).to_radians(),
+        dec: angle::deg_frm_dms(-29, 0, 28.1).to_radians(),
+    };
+    let gal_point = coords::galactic_frm_eq(&eq_point, 2451545.0);
+    assert_eq!(util::round_upto_digits(gal_point.long.to_degrees(), 2), 359.94);
+    assert_eq!(util::round_upto_digits(gal_point.lat.to_degrees(), 2), -0.05);
+
+    // Precession to B1950.0 and back agrees to a fraction of an
+    // arcsecond
+    let back = coords::eq_frm_galactic(&gal_point, 2451545.0);
+    assert_eq!(
+        util::round_upto_digits(back.asc, 5),
+        util::round_upto_digits(eq_point.asc, 5)
+    );
+    assert_eq!(
+        util::round_upto_digits(back.dec, 5),
+        util::round_upto_digits(eq_point.dec, 5)
+    );
+}
+
+#[test]
+fn parallactic_angle() {
+    let lat = 45_f64.to_radians();
+
+    let q = coords::parallactic_angle(90_f64.to_radians(), 0.0, lat);
+    assert_eq!(util::round_upto_digits(q.to_degrees(), 6), 45.0);
+
+    let q = coords::parallactic_angle(-90_f64.to_radians(), 0.0, lat);
+    assert_eq!(util::round_upto_digits(q.to_degrees(), 6), -45.0);
+
+    assert_eq!(
+        coords::parallactic_angle(0.0, 10_f64.to_radians(), lat),
+        0.0
+    );
+    assert_eq!(coords::parallactic_angle(0.0, lat, lat), 0.0);
+
+    // The angle at the body between the directions to the celestial
+    // pole and to the zenith, worked out from the vectors of the three
+    // points in the observer's equatorial frame
+    let q = coords::parallactic_angle(
+        45_f64.to_radians(),
+        20_f64.to_radians(),
+        40_f64.to_radians(),
+    );
+    assert_eq!(util::round_upto_digits(q.to_degrees(), 6), 52.293064);
+
+    let q = coords::parallactic_angle(
+        -60_f64.to_radians(),
+        -30_f64.to_radians(),
+        -33_f64.to_radians(),
+    );
+    assert_eq!(util::round_upto_digits(q.to_degrees(), 6), -109.83602);
+}
+
+#[test]
+fn apparent_place() {
+    // Meeus, Astronomical Algorithms, example 23.a: Theta Persei
+    let catalog_eq = coords::EqPoint {
+        asc: angle::deg_frm_hms(2, 44, 11.986).to_radians(),
+        dec: angle::deg_frm_dms(49, 13, 42.48).to_radians(),
+    };
+    let pm_asc = angle::deg_frm_hms(0, 0, 0.03425).to_radians();
+    let pm_dec = -angle::deg_frm_dms(0, 0, 0.0895).to_radians();
+    let JD = 2462088.69;
+
+    let apprnt = coords::apparent_place(&catalog_eq, pm_asc, pm_dec, 0.0, 0.0, JD);
+
+    let (h, m, s) = angle::hms_frm_deg(apprnt.asc.to_degrees());
+    assert_eq!((h, m, util::round_upto_digits(s, 2)), (2, 46, 14.39));
+    let (d, m, s) = angle::dms_frm_deg(apprnt.dec.to_degrees());
+    assert_eq!((d, m, util::round_upto_digits(s, 2)), (49, 21, 7.45));
+
+    // Without its proper motion, the star lags by the 28.87 years of it
+    // since J2000.0, which round to 29
+    let no_motion = coords::apparent_place_no_motion(&catalog_eq, JD);
+    let lag_asc = (apprnt.asc - no_motion.asc) / pm_asc;
+    let lag_dec = (apprnt.dec - no_motion.dec) / pm_dec;
+    assert_eq!(util::round_upto_digits(lag_asc, 0), 29.0);
+    assert_eq!(util::round_upto_digits(lag_dec, 0), 29.0);
+}
+
+#[test]
+fn separation_and_position_angle() {
+    // Meeus, Astronomical Algorithms, example 17.a: Arcturus and Spica
+    let arcturus = coords::EqPoint {
+        asc: angle::deg_frm_hms(14, 15, 39.7).to_radians(),
+        dec: angle::deg_frm_dms(19, 10, 57.0).to_radians(),
+    };
+    let spica = coords::EqPoint {
+        asc: angle::deg_frm_hms(13, 25, 11.6).to_radians(),
+        dec: -angle::deg_frm_dms(11, 9, 41.0).to_radians(),
+    };
+    let sepr = coords::separation(&arcturus, &spica);
+    assert_eq!(util::round_upto_digits(sepr.to_degrees(), 4), 32.793);
+    assert_eq!(
+        util::round_upto_digits(sepr, 12),
+        util::round_upto_digits(arcturus.anglr_sepr(&spica), 12)
+    );
+
+    // Spica is south-west of Arcturus, and Arcturus north-east of Spica
+    let pos_angl = coords::position_angle(&arcturus, &spica).to_degrees();
+    assert!(pos_angl > 180.0 && pos_angl < 270.0);
+    let pos_angl = coords::position_angle(&spica, &arcturus).to_degrees();
+    assert!(pos_angl > 0.0 && pos_angl < 90.0);
+
+    // A close pair, a quarter of an arcsecond apart
+    let arcsec = angle::deg_frm_dms(0, 0, 1.0).to_radians();
+    let a = coords::EqPoint {
+        asc: 1.0,
+        dec: 60_f64.to_radians(),
+    };
+    let north = coords::EqPoint {
+        asc: a.asc,
+        dec: a.dec + 0.25 * arcsec,
+    };
+    let east = coords::EqPoint {
+        asc: a.asc + 0.25 * arcsec / a.dec.cos(),
+        dec: a.dec,
+    };
+    let sepr = |b: &coords::EqPoint| coords::separation(&a, b) / arcsec;
+    let pos_angl = |b: &coords::EqPoint| coords::position_angle(&a, b).to_degrees();
+    assert_eq!(util::round_upto_digits(sepr(&north), 6), 0.25);
+    assert_eq!(util::round_upto_digits(sepr(&east), 6), 0.25);
+    assert_eq!(util::round_upto_digits(pos_angl(&north), 6), 0.0);
+    assert_eq!(util::round_upto_digits(pos_angl(&east), 3), 90.0);
+    assert_eq!(coords::separation(&a, &a), 0.0);
+}
+
+#[test]
+fn fk4_fk5() {
+    // The test values of P. T. Wallace's SLALIB
+    let B1960 = time::julian_day_frm_besselian_epoch(1960.0);
+    let eq_point_FK4 = coords::EqPoint {
+        asc: 1.2,
+        dec: -0.3,
+    };
+    let eq_point_FK5 = coords::fk4_to_fk5(&eq_point_FK4, B1960);
+    assert_eq!(util::round_upto_digits(eq_point_FK5.asc, 11), 1.2097812229);
+    assert_eq!(
+        util::round_upto_digits(eq_point_FK5.dec, 12),
+        -0.298261117113
+    );
+
+    let eq_point = coords::fk5_to_fk4(&eq_point_FK5, B1960);
+    assert!((eq_point.asc - eq_point_FK4.asc).abs() < 1e-14);
+    assert!((eq_point.dec - eq_point_FK4.dec).abs() < 1e-14);
+
+    let (eq_point, pm_asc, pm_dec, parallax, radial_velocity) = coords::fk4_to_fk5_with_motion(
+        &coords::EqPoint {
+            asc: 1.234,
+            dec: -0.123,
+        },
+        -1e-5,
+        2e-6,
+        angle::deg_frm_dms(0, 0, 0.5).to_radians(),
+        20.0,
+    );
+    assert_eq!(util::round_upto_digits(eq_point.asc, 12), 1.244117554619);
+    assert_eq!(util::round_upto_digits(eq_point.dec, 12), -0.121316425446);
+    assert_eq!(util::round_upto_digits(pm_asc * 1e6, 9), -9.964265838);
+    assert_eq!(util::round_upto_digits(pm_dec * 1e6, 9), 2.038065266);
+    assert_eq!(
+        util::round_upto_digits(parallax.to_degrees() * 3600.0, 9),
+        0.499744381
+    );
+    assert_eq!(util::round_upto_digits(radial_velocity, 9), 20.010460915);
+}
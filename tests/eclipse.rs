@@ -0,0 +1,50 @@
+/*
+Copyright (c) 2015, 2016 Saurav Sachidanand
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+#![allow(non_snake_case)]
+
+extern crate astro;
+
+use astro::*;
+
+#[test]
+fn sun_moon_geometry() {
+    // Greatest eclipse of the total solar eclipse of 2017 August 21
+    let JD = 2457987.26773;
+    let (sepr, pos_angl) = eclipse::sun_moon_geometry(JD);
+    assert_eq!(util::round_upto_digits(sepr.to_degrees(), 2), 0.43);
+    assert_eq!(util::round_upto_digits(pos_angl.to_degrees(), 0), 13.0);
+
+    let (_, earth_sun_dist) = sun::geocent_ecl_pos(JD);
+    let (_, earth_moon_dist) = lunar::geocent_ecl_pos(JD);
+    let semidiameters =
+        sun::semidiameter(earth_sun_dist).to_radians() + lunar::semidiameter(earth_moon_dist);
+    assert!(sepr < semidiameters);
+
+    // The Moon approaches the Sun from the west
+    let (sepr_before, pos_angl_before) = eclipse::sun_moon_geometry(JD - 0.08);
+    assert!(sepr_before > 1.0_f64.to_radians());
+    assert_eq!(
+        util::round_upto_digits(pos_angl_before.to_degrees(), 0),
+        307.0
+    );
+}
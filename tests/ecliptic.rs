@@ -31,3 +31,41 @@ fn mn_oblq_Laskar() {
 
     assert_eq!((d, m, util::round_upto_digits(s, 3)), (23, 26, 27.407));
 }
+
+#[test]
+fn mn_oblq_IAU() {
+    // Meeus, example 22.a, with formula (22.2)
+    let (d, m, s) = angle::dms_frm_deg(ecliptic::mn_oblq_IAU(2446895.5).to_degrees());
+    assert_eq!((d, m, util::round_upto_digits(s, 3)), (23, 26, 27.407));
+
+    // Within an arcsecond of Laskar's formula a thousand years from
+    // J2000
+    for &JD in [2451545.0 - 365250.0, 2451545.0 + 365250.0].iter() {
+        let diff = ecliptic::mn_oblq_laskar(JD) - ecliptic::mn_oblq_IAU(JD);
+        assert!(diff.to_degrees().abs() * 3600.0 < 1.0);
+    }
+}
+
+#[test]
+fn mn_oblq_Laskar_range() {
+    let oblq = |JD: f64| -> (i64, i64, f64) {
+        let (d, m, s) = angle::dms_frm_deg(ecliptic::mn_oblq_laskar(JD).to_degrees());
+        (d, m, util::round_upto_digits(s, 2))
+    };
+    let J2000 = 2451545.0;
+    let millennium = 365250.0;
+
+    // Meeus's value at J2000.0
+    assert_eq!(oblq(J2000), (23, 26, 21.45));
+
+    // Over the 10000 years either side of J2000, the obliquity stays
+    // within its range of about 22.1 to 24.5 degrees
+    for i in -10..11 {
+        let oblq = ecliptic::mn_oblq_laskar(J2000 + i as f64 * millennium).to_degrees();
+        assert!(oblq > 22.1 && oblq < 24.5);
+    }
+
+    // and out of the range of the formula, it's that at the nearer end
+    assert_eq!(oblq(J2000 - 20.0 * millennium), oblq(J2000 - 10.0 * millennium));
+    assert_eq!(oblq(J2000 + 50.0 * millennium), oblq(J2000 + 10.0 * millennium));
+}
@@ -0,0 +1,77 @@
+/*
+Copyright (c) 2015, 2016 Saurav Sachidanand
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+#![allow(non_snake_case)]
+
+extern crate astro;
+use astro::*;
+
+#[test]
+fn position() {
+    let JD = 2457570.5;
+    let body = ephem::Body::Planet(planet::Planet::Mars);
+
+    let of_date = ephem::position(&body, JD, &coords::Frame::EqOfDate).unwrap();
+    let j2000 = ephem::position(&body, JD, &coords::Frame::EqJ2000).unwrap();
+
+    // About 16.5 years of general precession, of about 50.3 arcseconds
+    // a year, separate the two, give or take nutation
+    let (asc, dec) = precess::precess_eq_coords(j2000.asc, j2000.dec, 2451545.0, JD);
+    let precessed = coords::EqPoint { asc, dec };
+    let sepr = of_date.anglr_sepr(&j2000).to_degrees() * 3600.0;
+    assert!(sepr > 16.5 * 50.3 * 0.3 && sepr < 16.5 * 50.3 * 1.1);
+    assert!(of_date.anglr_sepr(&precessed).to_degrees() * 3600.0 < 20.0);
+
+    // The Sun on 1992 October 13.0 TD, from its rectangular coordinates
+    // referred to the mean equinox of the date and to the equinox of
+    // J2000.0 (Meeus, chapter 26)
+    let JD = 2448908.5;
+    let eq_point = |x: f64, y: f64, z: f64| coords::EqPoint {
+        asc: y.atan2(x),
+        dec: z.atan2((x * x + y * y).sqrt()),
+    };
+    let sun_of_date = eq_point(-0.9379952, -0.3116544, -0.1351215);
+    let sun_j2000 = eq_point(-0.93740485, -0.31316758, -0.13577924);
+
+    let mn_oblq = ecliptic::mn_oblq_laskar(JD);
+    let sun_ecl_point = coords::EclPoint {
+        long: coords::ecl_long_frm_eq(sun_of_date.asc, sun_of_date.dec, mn_oblq),
+        lat: coords::ecl_lat_frm_eq(sun_of_date.asc, sun_of_date.dec, mn_oblq),
+    };
+    let precessed = ephem::ecl_point_to_frame(&sun_ecl_point, JD, &coords::Frame::EqJ2000);
+    assert!(precessed.anglr_sepr(&sun_j2000).to_degrees() * 3600.0 < 1.0);
+
+    // The apparent Sun is displaced from that geometric one by the
+    // aberration of about 20.5 arcseconds
+    let sun = ephem::position(&ephem::Body::Sun, JD, &coords::Frame::EqJ2000).unwrap();
+    let sepr = sun.anglr_sepr(&sun_j2000).to_degrees() * 3600.0;
+    assert!(sepr > 19.5 && sepr < 21.5);
+
+    let ecl = ephem::position(&ephem::Body::Sun, JD, &coords::Frame::MeanEclOfDate).unwrap();
+    assert!(ecl.dec.abs().to_degrees() * 3600.0 < 2.0);
+
+    let gal = ephem::position(&ephem::Body::Moon, JD, &coords::Frame::Galactic).unwrap();
+    assert!(gal.dec.abs() <= std::f64::consts::FRAC_PI_2);
+
+    let earth = ephem::Body::Planet(planet::Planet::Earth);
+    assert!(ephem::position(&earth, JD, &coords::Frame::EqJ2000).is_err());
+}
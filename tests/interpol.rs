@@ -31,9 +31,84 @@ fn three_values() {
     assert_eq!(util::round_upto_digits(y, 6), 0.876125);
 }
 
+#[test]
+fn three_values_with_derivative() {
+    let (y1, y2, y3) = (0.884226, 0.877366, 0.870531);
+    let n = 0.18125;
+    let (y, deriv) = interpol::three_values_with_derivative(y1, y2, y3, n);
+
+    assert_eq!(y, interpol::three_values(y1, y2, y3, n));
+
+    let h = 1e-6;
+    let finite_diff = (interpol::three_values(y1, y2, y3, n + h)
+        - interpol::three_values(y1, y2, y3, n - h))
+        / (2.0 * h);
+
+    assert_eq!(
+        util::round_upto_digits(deriv, 8),
+        util::round_upto_digits(finite_diff, 8)
+    );
+    assert_eq!(util::round_upto_digits(deriv, 7), -0.006843);
+}
+
 #[test]
 fn five_values() {
     let y = interpol::five_values(36.125, 24.606, 15.486, 8.694, 4.133, 0.2777778);
 
     assert_eq!(util::round_upto_digits(y, 3), 13.369);
 }
+
+#[test]
+fn lagrange() {
+    let xs = [29.43, 30.97, 27.69, 28.11, 31.79, 32.93];
+    let ys: Vec<f64> = xs.iter().map(|x: &f64| x.to_radians().sin()).collect();
+    let y = interpol::lagrange(&xs, &ys, 30.0);
+
+    assert_eq!(util::round_upto_digits(y, 7), 0.5);
+
+    let xs = [-2.0, -1.0, 0.0, 1.0, 2.0];
+    let ys = [36.125, 24.606, 15.486, 8.694, 4.133];
+    let n = 0.2777778;
+
+    assert_eq!(
+        util::round_upto_digits(interpol::lagrange(&xs, &ys, n), 9),
+        util::round_upto_digits(
+            interpol::five_values(ys[0], ys[1], ys[2], ys[3], ys[4], n),
+            9
+        )
+    );
+}
+
+#[test]
+#[should_panic(expected = "was passed 3 arguments and 2 function values")]
+fn lagrange_mismatched_lengths() {
+    interpol::lagrange(&[1.0, 2.0, 3.0], &[1.0, 2.0], 1.5);
+}
+
+#[test]
+fn extremum() {
+    let (n_m, y_m) = interpol::extremum(1.3814294, 1.3812213, 1.3812453).unwrap();
+
+    assert_eq!(util::round_upto_digits(n_m, 4), 0.3966);
+    assert_eq!(util::round_upto_digits(y_m, 7), 1.381203);
+
+    assert!(interpol::extremum(1.0, 2.0, 3.0).is_none());
+}
+
+#[test]
+fn zero() {
+    let y1 = angle::deg_frm_dms(0, -28, -13.4);
+    let y2 = angle::deg_frm_dms(0, 6, 46.3);
+    let y3 = angle::deg_frm_dms(0, 38, 23.2);
+    let n_0 = interpol::zero(y1, y2, y3).unwrap();
+
+    assert_eq!(util::round_upto_digits(n_0, 5), -0.20127);
+
+    let n_0 = interpol::zero(-2.0, 3.0, 2.0).unwrap();
+
+    assert_eq!(util::round_upto_digits(n_0, 12), -0.720759220056);
+
+    assert!(interpol::zero(1.0, 2.0, 3.0).is_none());
+    assert!(interpol::zero(1.0, 0.5, 1.0).is_none());
+    assert_eq!(interpol::zero(-1.0, 0.0, 1.0), Some(0.0));
+}
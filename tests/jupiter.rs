@@ -47,6 +47,22 @@ fn ephemeris() {
     assert_eq!(ephemeris.w2, 72.74);
 }
 
+#[test]
+fn central_meridian() {
+    // Meeus, example 43.a
+    let (cm_system_I, cm_system_II) = planet::jupiter::central_meridian(2448972.50068);
+    assert_eq!(util::round_upto_digits(cm_system_I.to_degrees(), 0), 268.0);
+    assert_eq!(util::round_upto_digits(cm_system_II.to_degrees(), 2), 72.74);
+
+    // System II rotates by about 870.27 degrees a day
+    let (_, later_cm_system_II) = planet::jupiter::central_meridian(2448972.50068 + 0.25);
+    let rate = angle::limit_to_360((later_cm_system_II - cm_system_II).to_degrees()) * 4.0;
+    assert_eq!(util::round_upto_digits(rate, 0), 870.0);
+
+    let P = planet::jupiter::axis_position_angle(2448972.50068);
+    assert_eq!(util::round_upto_digits(P.to_degrees(), 2), 24.80);
+}
+
 #[test]
 fn moons() {
     let data = [
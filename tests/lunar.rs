@@ -112,3 +112,247 @@ fn phases() {
     let JD_new_moon = lunar::time_of_phase(&date_new_moon, &lunar::Phase::New);
     assert_eq!(util::round_upto_digits(JD_new_moon, 5), 2443192.65118);
 }
+
+#[test]
+fn topocentric_distance() {
+    let JD = 2448724.5;
+    let (moon_ecl_point, earth_moon_dist) = lunar::geocent_ecl_pos(JD);
+    let (nut_in_long, nut_in_oblq) = nutation::nutation(JD);
+    let true_oblq = ecliptic::mn_oblq_laskar(JD) + nut_in_oblq;
+    let (asc, dec) = eq_frm_ecl!(
+        moon_ecl_point.long + nut_in_long,
+        moon_ecl_point.lat,
+        true_oblq
+    );
+
+    // An observer with the Moon at the zenith
+    let geograph_point = coords::GeographPoint {
+        long: apprnt_sidr!(JD) - asc,
+        lat: dec,
+    };
+    let dist = lunar::topocentric_distance(JD, &geograph_point, 0.0);
+    let diff = earth_moon_dist - dist;
+    assert!(diff > 6350.0 && diff < 6378.14);
+
+    // An observer with the Moon at the nadir
+    let geograph_point = coords::GeographPoint {
+        long: geograph_point.long + std::f64::consts::PI,
+        lat: -dec,
+    };
+    let dist = lunar::topocentric_distance(JD, &geograph_point, 0.0);
+    let diff = dist - earth_moon_dist;
+    assert!(diff > 6350.0 && diff < 6378.14);
+}
+
+#[test]
+fn topocentric_eq() {
+    // The Moon of Meeus's example 47.a, seen from Palomar Observatory
+    // (example 40.a). The reference values come from Meeus's apparent
+    // geocentric α, δ and Δ of that example, reduced with formulae
+    // (40.2) and (40.3).
+    let JD = 2448724.5;
+    let geograph_point = coords::GeographPoint {
+        long: angle::deg_frm_hms(7, 47, 27.0).to_radians(),
+        lat: angle::deg_frm_dms(33, 21, 22.0).to_radians(),
+    };
+    let observer_ht = 1706.0;
+
+    let (topocent_eq_point, dist) = lunar::topocentric_eq(JD, &geograph_point, observer_ht);
+    assert_eq!(
+        dist,
+        lunar::topocentric_distance(JD, &geograph_point, observer_ht)
+    );
+
+    let ref_eq_point = coords::EqPoint {
+        asc: 135.359493_f64.to_radians(),
+        dec: 13.360084_f64.to_radians(),
+    };
+    let sepr = coords::separation(&topocent_eq_point, &ref_eq_point);
+    assert!(sepr.to_degrees() * 3600.0 < 0.1);
+    assert!((dist - 364359.3).abs() < 0.1);
+}
+
+#[test]
+fn visible_fraction_over_period() {
+    let one_day = lunar::visible_fraction_over_period(2448724.5, 1.0, 1);
+    assert_eq!(util::round_upto_digits(one_day, 2), 0.5);
+
+    let month = lunar::visible_fraction_over_period(2448724.5, 1.0, 30);
+    assert!(month > 0.5 && month < 0.6);
+}
+
+#[test]
+fn apparent_diameter() {
+    let JD = 2448724.5;
+    let (_, earth_moon_dist) = lunar::geocent_ecl_pos(JD);
+    let diameter = lunar::apparent_diameter(JD);
+
+    assert_eq!(
+        util::round_upto_digits(diameter, 12),
+        util::round_upto_digits(2.0 * lunar::semidiameter(earth_moon_dist), 12)
+    );
+    assert!(diameter.to_degrees() > 29.0 / 60.0 && diameter.to_degrees() < 34.0 / 60.0);
+}
+
+#[test]
+fn conjunction_with_planet() {
+    // The Moon occulted Venus on 2010 May 16, as seen from Asia
+    let (JD, sepr, occultation) =
+        lunar::conjunction_with_planet(&planet::Planet::Venus, 2455332.5, 2.0);
+    assert_eq!(util::round_upto_digits(JD, 1), 2455332.9);
+    assert!(sepr.to_degrees() < 0.1);
+    assert!(occultation);
+
+    // A wide conjunction on 2020 June 19, seen as an occultation only
+    // because of the lunar parallax
+    let (_, sepr, occultation) =
+        lunar::conjunction_with_planet(&planet::Planet::Venus, 2459019.5, 2.0);
+    assert!(sepr.to_degrees() > 0.5);
+    assert!(!occultation);
+}
+
+#[test]
+fn phase() {
+    // Meeus, example 48.a
+    let JD = 2448724.5;
+    assert_eq!(
+        util::round_upto_digits(lunar::illuminated_fraction(JD), 4),
+        0.6786
+    );
+    assert_eq!(
+        util::round_upto_digits(lunar::phase_angle(JD).to_degrees(), 2),
+        69.08
+    );
+    assert_eq!(
+        util::round_upto_digits(lunar::bright_limb_position_angle(JD).to_degrees(), 1),
+        285.0
+    );
+
+    let date = time::Date {
+        year: 2016,
+        month: time::Month::June,
+        decimal_day: 1.0,
+        cal_type: time::CalType::Gregorian,
+    };
+
+    let first_quarter = lunar::time_of_phase(&date, &lunar::Phase::First);
+    assert_eq!(
+        util::round_upto_digits(lunar::illuminated_fraction(first_quarter), 2),
+        0.5
+    );
+
+    let full = lunar::time_of_phase(&date, &lunar::Phase::Full);
+    assert!(lunar::illuminated_fraction(full) > 0.99);
+}
+
+#[test]
+fn time_of_phase_local() {
+    // Meeus, example 49.a: the New Moon of 1977 February 18 at
+    // 3h 37m 42s TD, which is 3h 36m 54s UT
+    let date = time::Date {
+        year: 1977,
+        month: time::Month::Feb,
+        decimal_day: 0.0,
+        cal_type: time::CalType::Gregorian,
+    };
+    let hm = |date: &time::Date| {
+        let day_of_month = date.day_of_month();
+        (
+            date.month as u8,
+            day_of_month.day,
+            day_of_month.hr,
+            day_of_month.min,
+        )
+    };
+
+    let new_moon = lunar::time_of_phase_local(&date, &lunar::Phase::New, 0.0).unwrap();
+    assert_eq!(hm(&new_moon), (2, 18, 3, 36));
+
+    let new_moon = lunar::time_of_phase_local(&date, &lunar::Phase::New, 5.5).unwrap();
+    assert_eq!(hm(&new_moon), (2, 18, 9, 6));
+
+    let new_moon = lunar::time_of_phase_local(&date, &lunar::Phase::New, -5.0).unwrap();
+    assert_eq!(hm(&new_moon), (2, 17, 22, 36));
+}
+
+#[test]
+fn next_phase() {
+    // Meeus, example 49.a: the New Moon of 1977 February
+    let JD = lunar::next_phase(2443180.5, &lunar::Phase::New);
+    assert_eq!(util::round_upto_digits(JD, 5), 2443192.65118);
+
+    // Just before and just after that New Moon
+    assert_eq!(lunar::next_phase(JD - 1e-6, &lunar::Phase::New), JD);
+    let next_JD = lunar::next_phase(JD, &lunar::Phase::New);
+    assert!(next_JD - JD > 29.0 && next_JD - JD < 30.0);
+
+    let first_quarter = lunar::next_phase(JD, &lunar::Phase::First);
+    assert!(first_quarter - JD > 6.0 && first_quarter - JD < 9.0);
+}
+
+#[test]
+fn libration() {
+    // Meeus, example 53.a
+    let libration = lunar::libration(2448724.5);
+
+    assert_eq!(util::round_upto_digits(libration.l.to_degrees(), 2), -1.23);
+    assert_eq!(util::round_upto_digits(libration.b.to_degrees(), 2), 4.20);
+    assert_eq!(util::round_upto_digits(libration.pa_axis.to_degrees(), 2), 15.08);
+}
+
+#[test]
+fn apsides() {
+    // Meeus, example 50.a: the apogee of 1988 October 7, at 20h 30m TD,
+    // with a parallax of 3240.679"
+    let (JD, dist) = lunar::apogee(2447430.5);
+    assert_eq!(util::round_upto_digits(JD, 4), 2447442.3543);
+    let parallax = angle::deg_frm_dms(0, 0, 3240.679).to_radians();
+    assert!((dist - 6378.14 / parallax.sin()).abs() < 0.1);
+
+    // The apogee itself is not after itself
+    let (next_JD, _) = lunar::apogee(JD);
+    assert!(next_JD - JD > 25.0);
+
+    // The perigee agrees with the least distance of geocent_ecl_pos()
+    // within Meeus's greatest errors of 31 minutes and 0.124" of
+    // parallax
+    let (perigee_JD, perigee_dist) = lunar::perigee(JD);
+    assert!(perigee_JD > JD && perigee_JD < next_JD);
+    let (least_JD, least_dist) = (0..4000)
+        .map(|i| perigee_JD - 1.0 + (i as f64) * 0.0005)
+        .map(|JD| (JD, lunar::geocent_ecl_pos(JD).1))
+        .fold((0.0, std::f64::MAX), |a, b| if b.1 < a.1 { b } else { a });
+    assert!((perigee_JD - least_JD).abs() * 1440.0 < 31.0);
+    assert!((perigee_dist - least_dist).abs() < 12.0);
+}
+
+#[test]
+fn positions_and_parallax() {
+    let JD = 2448724.5;
+    let positions = lunar::positions_and_parallax(JD);
+
+    let (_, earth_moon_dist) = lunar::geocent_ecl_pos(JD);
+    assert_eq!(positions[1].1, lunar::eq_hz_parllx(earth_moon_dist));
+
+    // The Moon moves eastwards by about 13 degrees a day
+    let daily_motion = positions[1].0.anglr_sepr(&positions[2].0).to_degrees();
+    assert!(daily_motion > 11.0 && daily_motion < 16.0);
+    assert!(
+        positions[0].0.anglr_sepr(&positions[2].0) > positions[0].0.anglr_sepr(&positions[1].0)
+    );
+}
+
+#[test]
+fn node_passage() {
+    // Meeus, example 51.a: the ascending node of 1987 May 23
+    let JD = lunar::node_passage(2446930.5, &lunar::LunarNode::Ascending);
+    assert_eq!(util::round_upto_digits(JD, 5), 2446938.76803);
+
+    // The next passage is through the descending node, half a
+    // draconic month later
+    let descend_JD = lunar::node_passage(JD, &lunar::LunarNode::Descending);
+    assert!((descend_JD - JD - 13.6).abs() < 1.0);
+
+    let next_JD = lunar::node_passage(JD, &lunar::LunarNode::Ascending);
+    assert!((next_JD - JD - 27.2).abs() < 1.0);
+}
@@ -54,3 +54,25 @@ fn ephemeris() {
     assert_eq!((h2, m2), (0, 0));
     assert_eq!(util::round_upto_digits(s2, 2), 1.06);
 }
+
+#[test]
+fn physical_ephemeris() {
+    let ephemeris = planet::mars::physical_ephemeris(2448935.500638);
+
+    assert_eq!(
+        util::round_upto_digits(ephemeris.central_meridian.to_degrees(), 1),
+        111.5
+    );
+    assert_eq!(
+        util::round_upto_digits(angle::limit_to_360(ephemeris.pole_pa.to_degrees()), 2),
+        347.64
+    );
+    assert_eq!(
+        util::round_upto_digits(ephemeris.tilt.to_degrees(), 2),
+        12.44
+    );
+    assert_eq!(
+        util::round_upto_digits(ephemeris.defect_of_illum.to_degrees() * 3600.0, 2),
+        1.06
+    );
+}
@@ -52,3 +52,65 @@ fn nutation_in_eq_coords() {
     assert_eq!(util::round_upto_digits(a.to_degrees(), 7), 0.0044011);
     assert_eq!(util::round_upto_digits(b.to_degrees(), 7), 0.001727);
 }
+
+#[test]
+fn nutation_matrix() {
+    // Theta Persei, mean position of the date (Meeus, example 23.a)
+    let JD = 2462088.69;
+    let eq_point = coords::EqPoint {
+        asc: 41.5472544_f64.to_radians(),
+        dec: 49.3485003_f64.to_radians(),
+    };
+
+    let true_eq_point = nutation::apply_nutation(&eq_point, JD);
+    assert_eq!(
+        util::round_upto_digits((true_eq_point.asc - eq_point.asc).to_degrees() * 3600.0, 2),
+        15.84
+    );
+    assert_eq!(
+        util::round_upto_digits((true_eq_point.dec - eq_point.dec).to_degrees() * 3600.0, 2),
+        6.22
+    );
+
+    // The transpose undoes the nutation
+    let matrix = nutation::nutation_matrix(JD);
+    let transpose = [
+        [matrix[0][0], matrix[1][0], matrix[2][0]],
+        [matrix[0][1], matrix[1][1], matrix[2][1]],
+        [matrix[0][2], matrix[1][2], matrix[2][2]],
+    ];
+    let v = [
+        true_eq_point.dec.cos() * true_eq_point.asc.cos(),
+        true_eq_point.dec.cos() * true_eq_point.asc.sin(),
+        true_eq_point.dec.sin(),
+    ];
+    let v = coords::apply_matrix(&transpose, &v);
+    assert_eq!(
+        util::round_upto_digits(v[1].atan2(v[0]), 12),
+        util::round_upto_digits(eq_point.asc, 12)
+    );
+    assert_eq!(
+        util::round_upto_digits(v[2].asin(), 12),
+        util::round_upto_digits(eq_point.dec, 12)
+    );
+}
+
+#[test]
+fn nutation_2000b() {
+    // Reference values from the SOFA test suite, for MJD 53736.0
+    let (nut_in_long, nut_in_oblq) = nutation::nutation_2000b(2453736.5);
+    assert_eq!(
+        util::round_upto_digits(nut_in_long * 1e6, 12),
+        -9.632552291148
+    );
+    assert_eq!(
+        util::round_upto_digits(nut_in_oblq * 1e5, 12),
+        4.063197106621
+    );
+
+    // Within 10 milliarcseconds of the IAU 1980 series (Meeus, example 22.a)
+    let (nut_in_long, nut_in_oblq) = nutation::nutation_2000b(2446895.5);
+    let (nut_in_long_1980, nut_in_oblq_1980) = nutation::nutation(2446895.5);
+    assert!((nut_in_long - nut_in_long_1980).to_degrees().abs() * 3600.0 < 0.01);
+    assert!((nut_in_oblq - nut_in_oblq_1980).to_degrees().abs() * 3600.0 < 0.01);
+}
@@ -0,0 +1,195 @@
+/*
+Copyright (c) 2015, 2016 Saurav Sachidanand
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+#![allow(non_snake_case)]
+
+extern crate astro;
+use astro::*;
+
+#[test]
+fn solve_kepler() {
+    // Elliptic: Meeus's example 30.a, with M = 5 degrees and e = 0.1
+    let (q, ecc) = (0.9_f64, 0.1);
+    let a = q / (1.0 - ecc);
+    let n = consts::GAUSS_GRAV / a.powf(1.5);
+    let (v, r) = orbit::solve_kepler(5_f64.to_radians() / n, 0.0, q, ecc);
+    let E = 5.554589_f64.to_radians();
+    assert_eq!(
+        util::round_upto_digits(v.to_degrees(), 5),
+        util::round_upto_digits(orbit::elliptic::true_anom(E, ecc).to_degrees(), 5)
+    );
+    assert_eq!(
+        util::round_upto_digits(r, 6),
+        util::round_upto_digits(orbit::elliptic::rad_vec_frm_ecc_anom(E, a, ecc), 6)
+    );
+
+    // Parabolic
+    let (v, r) = orbit::solve_kepler(2446470.5, 2446463.5, 0.5871, 1.0);
+    let (v_p, r_p) = orbit::parabolic::true_anom_and_rad_vec(2446470.5, 2446463.5, 0.5871);
+    assert_eq!((v, r), (v_p, r_p));
+
+    // Near-parabolic, on either side of unity, compared with Meeus's
+    // method in Chapter 35
+    for &ecc in [0.99, 0.999999, 1.000001, 1.01].iter() {
+        for &days in [-300.0, -10.0, 0.5, 10.0, 300.0].iter() {
+            let (v, r) = orbit::solve_kepler(days, 0.0, 0.921326, ecc);
+            let (v_n, r_n) =
+                orbit::near_parabolic::true_anom_and_rad_vec(days, 0.0, ecc, 0.921326, 1e-12)
+                    .unwrap();
+            assert_eq!(
+                util::round_upto_digits(angle::limit_to_two_PI(v).to_degrees(), 6),
+                util::round_upto_digits(v_n.to_degrees(), 6)
+            );
+            assert_eq!(
+                util::round_upto_digits(r, 6),
+                util::round_upto_digits(r_n, 6)
+            );
+        }
+    }
+
+    // Hyperbolic, far from perihelion: the radius vector satisfies
+    // the equation of the orbit
+    let (q, ecc) = (1.2, 3.5);
+    let (v, r) = orbit::solve_kepler(10000.0, 0.0, q, ecc);
+    assert!(v > 0.0 && v < (-1.0 / ecc).acos());
+    assert_eq!(
+        util::round_upto_digits(r, 6),
+        util::round_upto_digits(q * (1.0 + ecc) / (1.0 + ecc * v.cos()), 6)
+    );
+}
+
+#[test]
+fn ephemeris() {
+    // Comet Encke, from Meeus's example 33.b
+    let elements = orbit::OrbitalElements {
+        a_or_q: 2.2091404,
+        e: 0.8502196,
+        i: 11.94524_f64.to_radians(),
+        node: 334.75006_f64.to_radians(),
+        arg_peri: 186.23352_f64.to_radians(),
+        epoch: 2448193.04502,
+        mean_anomaly_or_time: 0.0,
+    };
+    let eq_point = orbit::ephemeris(&elements, 2448170.5);
+
+    assert_eq!(
+        util::round_upto_digits(eq_point.asc.to_degrees(), 3),
+        158.559
+    );
+    assert_eq!(
+        util::round_upto_digits(eq_point.dec.to_degrees(), 3),
+        19.158
+    );
+
+    // The same orbit, given by the mean anomaly at another epoch
+    let mn_motion = consts::GAUSS_GRAV / 2.2091404_f64.powf(1.5);
+    let elements = orbit::OrbitalElements {
+        epoch: 2448000.5,
+        mean_anomaly_or_time: mn_motion * (2448000.5 - 2448193.04502),
+        ..elements
+    };
+    let other_eq_point = orbit::ephemeris(&elements, 2448170.5);
+    assert_eq!(
+        util::round_upto_digits(other_eq_point.asc, 9),
+        util::round_upto_digits(eq_point.asc, 9)
+    );
+    assert_eq!(
+        util::round_upto_digits(other_eq_point.dec, 9),
+        util::round_upto_digits(eq_point.dec, 9)
+    );
+}
+
+#[test]
+fn state_vector() {
+    let mu = consts::GAUSS_GRAV * consts::GAUSS_GRAV;
+    let JD = 2448170.5;
+
+    let orbits = [
+        // Comet Encke
+        (2.2091404, 0.8502196, 11.94524_f64, 334.75006_f64, 186.23352_f64, 0.3),
+        // A hyperbolic orbit, given by the time of perihelion
+        (1.2, 1.5, 120.0, 40.0, 300.0, 2448100.5),
+        // A parabolic orbit, given by the time of perihelion
+        (0.9, 1.0, 70.0, 10.0, 20.0, 2448200.5),
+    ];
+
+    for orbit in orbits.iter() {
+        let elements = orbit::OrbitalElements {
+            a_or_q: orbit.0,
+            e: orbit.1,
+            i: orbit.2.to_radians(),
+            node: orbit.3.to_radians(),
+            arg_peri: orbit.4.to_radians(),
+            epoch: JD,
+            mean_anomaly_or_time: orbit.5,
+        };
+        let (pos, vel) = orbit::elements_to_state(&elements, mu, JD);
+        let result = orbit::state_to_elements(&pos, &vel, mu, JD);
+
+        assert_eq!(
+            util::round_upto_digits(result.a_or_q, 8),
+            util::round_upto_digits(elements.a_or_q, 8)
+        );
+        assert_eq!(
+            util::round_upto_digits(result.e, 8),
+            util::round_upto_digits(elements.e, 8)
+        );
+        for &(x, y) in [
+            (result.i, elements.i),
+            (result.node, elements.node),
+            (result.arg_peri, elements.arg_peri),
+        ]
+        .iter()
+        {
+            assert_eq!(util::round_upto_digits(x, 8), util::round_upto_digits(y, 8));
+        }
+        assert_eq!(
+            util::round_upto_digits(result.mean_anomaly_or_time, 6),
+            util::round_upto_digits(elements.mean_anomaly_or_time, 6)
+        );
+    }
+
+    // A circular orbit in the reference plane, of radius 1 AU, in
+    // which the speed is the Gaussian gravitational constant
+    let elements = orbit::OrbitalElements {
+        a_or_q: 1.0,
+        e: 0.0,
+        i: 0.0,
+        node: 0.0,
+        arg_peri: 0.0,
+        epoch: JD,
+        mean_anomaly_or_time: 1.0,
+    };
+    let (pos, vel) = orbit::elements_to_state(&elements, mu, JD);
+    let speed = (vel[0] * vel[0] + vel[1] * vel[1] + vel[2] * vel[2]).sqrt();
+    assert_eq!(
+        util::round_upto_digits(speed, 10),
+        util::round_upto_digits(consts::GAUSS_GRAV, 10)
+    );
+
+    let result = orbit::state_to_elements(&pos, &vel, mu, JD);
+    assert_eq!(
+        (result.e, result.i, result.node, result.arg_peri),
+        (0.0, 0.0, 0.0, 0.0)
+    );
+    assert_eq!(util::round_upto_digits(result.mean_anomaly_or_time, 8), 1.0);
+}
@@ -82,3 +82,464 @@ fn geocent_apprnt_ecl_coords() {
 
     assert_eq!((L, B, R), (313.08, -2.08, 0.9109));
 }
+
+#[test]
+fn dichotomy_instant() {
+    // Venus's greatest eastern elongation of 2020 was on March 24, and
+    // its phase angle reached 90 degrees on March 27 at about 1h TD.
+    // The reference instant is computed from the Keplerian mean
+    // elements of Venus and the Earth-Moon barycenter given by Standish
+    // (JPL), which are good to some tens of arcseconds.
+    let JD = planet::dichotomy_instant(&planet::Planet::Venus, 2020.0, true).unwrap();
+    assert!((JD - 2458935.533).abs() < 1.0 / 24.0);
+
+    assert!(planet::dichotomy_instant(&planet::Planet::Mars, 2020.0, true).is_err());
+}
+
+#[test]
+fn phase_angle_and_illuminated_fraction() {
+    // Venus on 1992 December 20 (Meeus, example 41.a)
+    let i = planet::phase_angle(&planet::Planet::Venus, 2448976.5);
+    assert_eq!(util::round_upto_digits(i.to_degrees(), 1), 73.0);
+
+    let k = planet::illuminated_fraction(&planet::Planet::Venus, 2448976.5);
+    assert_eq!(util::round_upto_digits(k, 3), 0.647);
+
+    let k = planet::illuminated_fraction(&planet::Planet::Jupiter, 2448976.5);
+    assert!(k > 0.98 && k <= 1.0);
+}
+
+#[test]
+fn apparent_magnitude() {
+    // Venus on 1992 December 20 (Meeus, example 41.a)
+    let m = planet::apparent_magnitude(&planet::Planet::Venus, 2448976.5).unwrap();
+    assert_eq!(util::round_upto_digits(m, 1), -4.2);
+
+    // Saturn on 1992 December 16 (Meeus, example 41.b)
+    let m = planet::apparent_magnitude(&planet::Planet::Saturn, 2448972.5).unwrap();
+    assert!((m - 0.7).abs() < 0.1);
+
+    assert!(planet::apparent_magnitude(&planet::Planet::Earth, 2448976.5).is_err());
+}
+
+#[test]
+fn apparent_diameter() {
+    // Mars at it's closest approach on 2003 August 27
+    let (eq_diameter, pol_diameter) =
+        planet::apparent_diameter(&planet::Planet::Mars, 2452878.9).unwrap();
+    assert_eq!(
+        util::round_upto_digits(eq_diameter.to_degrees() * 3600.0, 1),
+        25.1
+    );
+    assert_eq!(eq_diameter, pol_diameter);
+
+    let (eq_diameter, pol_diameter) =
+        planet::apparent_diameter(&planet::Planet::Saturn, 2448972.5).unwrap();
+    assert!(pol_diameter < eq_diameter);
+
+    assert!(planet::apparent_diameter(&planet::Planet::Earth, 2452878.9).is_err());
+}
+
+#[test]
+fn periods() {
+    let earth = planet::sidereal_period(&planet::Planet::Earth);
+    assert!((earth - 365.256).abs() < 0.01);
+
+    let tropical_year = planet::tropical_period(&planet::Planet::Earth);
+    assert_eq!(util::round_upto_digits(tropical_year, 2), 365.24);
+
+    let mercury = planet::sidereal_period(&planet::Planet::Mercury);
+    assert_eq!(util::round_upto_digits(mercury, 1), 88.0);
+
+    let mars = planet::synodic_period_with_earth(&planet::Planet::Mars).unwrap();
+    assert!((mars - 779.9).abs() < 0.1);
+
+    assert!(planet::synodic_period_with_earth(&planet::Planet::Earth).is_err());
+}
+
+#[test]
+fn stellar_occultation() {
+    let JD = 2457570.5;
+    let (ecl_point, _) = planet::geocent_apprnt_ecl_coords(&planet::Planet::Jupiter, JD);
+    let (nut_in_long, nut_in_oblq) = nutation::nutation(JD);
+    let true_oblq = ecliptic::mn_oblq_laskar(JD) + nut_in_oblq;
+    let (asc, dec) = eq_frm_ecl!(ecl_point.long + nut_in_long, ecl_point.lat, true_oblq);
+
+    // A star 5 arcseconds north of the center of Jupiter's disk
+    let star_eq = coords::EqPoint {
+        asc,
+        dec: dec + 5_f64.to_radians() / 3600.0,
+    };
+    let (JD_flash, sepr) =
+        planet::stellar_occultation(&planet::Planet::Jupiter, &star_eq, JD + 0.3, 1.0).unwrap();
+    assert!((JD_flash - JD).abs() < 0.05);
+    assert!(sepr.to_degrees() * 3600.0 <= 5.0);
+
+    // A star well clear of the disk
+    let star_eq = coords::EqPoint {
+        asc,
+        dec: dec + 1_f64.to_radians() / 60.0,
+    };
+    assert!(planet::stellar_occultation(&planet::Planet::Jupiter, &star_eq, JD, 1.0).is_none());
+}
+
+#[test]
+fn apparent_place_with_options() {
+    // Meeus, example 33.a
+    let JD = 2448976.5;
+    let eq_point = planet::apparent_place(&planet::Planet::Venus, JD);
+
+    let (h, m, s) = angle::hms_frm_deg(eq_point.asc.to_degrees());
+    assert_eq!((h, m, util::round_upto_digits(s, 3)), (21, 4, 41.455));
+    let (d, m, s) = angle::dms_frm_deg(eq_point.dec.to_degrees());
+    assert_eq!((d, m, util::round_upto_digits(s, 2)), (-18, -53, -16.84));
+
+    let all = planet::apparent_place_with_options(
+        &planet::Planet::Venus,
+        JD,
+        &planet::ReductionOptions::all(),
+    );
+    assert_eq!(all.asc, eq_point.asc);
+    assert_eq!(all.dec, eq_point.dec);
+
+    // The geometric position, referred to the mean equinox of the date
+    let (L0, B0, R0) = planet::heliocent_coords(&planet::Planet::Earth, JD);
+    let (L, B, R) = planet::heliocent_coords(&planet::Planet::Venus, JD);
+    let (lambda, beta, _, _) = planet::geocent_geomet_ecl_coords(L0, B0, R0, L, B, R);
+    let mn_oblq = ecliptic::mn_oblq_laskar(JD);
+
+    let none = planet::apparent_place_with_options(
+        &planet::Planet::Venus,
+        JD,
+        &planet::ReductionOptions::none(),
+    );
+    assert_eq!(
+        util::round_upto_digits(none.asc, 12),
+        util::round_upto_digits(
+            angle::limit_to_two_PI(coords::asc_frm_ecl(lambda, beta, mn_oblq)),
+            12
+        )
+    );
+    assert_eq!(
+        util::round_upto_digits(none.dec, 12),
+        util::round_upto_digits(coords::dec_frm_ecl(lambda, beta, mn_oblq), 12)
+    );
+}
+
+#[test]
+fn path() {
+    // Mars through its retrograde loop at the opposition of 2016 May
+    let path = planet::path(
+        &planet::Planet::Mars,
+        2457448.5,
+        2.0,
+        90,
+        &coords::Frame::EqOfDate,
+    )
+    .unwrap();
+    assert_eq!(path.len(), 90);
+
+    let mut reversals = 0;
+    for i in 2..path.len() {
+        let prev_rate = path[i - 1].asc - path[i - 2].asc;
+        let rate = path[i].asc - path[i - 1].asc;
+        assert!(rate.abs() < 0.1);
+        if prev_rate * rate < 0.0 {
+            reversals += 1;
+        }
+    }
+    assert_eq!(reversals, 2);
+
+    // No seam as Neptune crosses 0h of right ascension in 2024
+    let path = planet::path(
+        &planet::Planet::Neptune,
+        2460310.5,
+        30.0,
+        12,
+        &coords::Frame::EqJ2000,
+    )
+    .unwrap();
+    assert!(path[0].asc < angle::TWO_PI && path[6].asc > angle::TWO_PI);
+    for i in 1..path.len() {
+        assert!((path[i].asc - path[i - 1].asc).abs() < 0.1);
+    }
+
+    assert!(planet::path(
+        &planet::Planet::Earth,
+        2457448.5,
+        1.0,
+        1,
+        &coords::Frame::EqOfDate
+    )
+    .is_err());
+}
+
+#[test]
+fn longitude_crossing() {
+    // Jupiter enters Aries in 2022 May, retrogrades back into Pisces
+    // in October, and enters Aries again in December
+    let crossing = |JD_near: f64| {
+        planet::longitude_crossing(&planet::Planet::Jupiter, 0.0, JD_near, 100.0).unwrap()
+    };
+    assert_eq!(util::round_upto_digits(crossing(2459710.0), 1), 2459710.5);
+    assert_eq!(util::round_upto_digits(crossing(2459900.0), 1), 2459880.7);
+    assert_eq!(util::round_upto_digits(crossing(2459930.0), 1), 2459934.1);
+
+    assert!(planet::longitude_crossing(&planet::Planet::Jupiter, 0.0, 2459700.0, 1.0).is_none());
+    assert!(planet::longitude_crossing(&planet::Planet::Earth, 0.0, 2459710.0, 100.0).is_none());
+}
+
+#[test]
+fn all_heliocent_coords_J2000() {
+    let JD = 2448976.5;
+    let all = planet::all_heliocent_coords_J2000(JD);
+
+    for &(ref p, long, lat, rad_vec) in all.iter() {
+        let (L, B, R) = planet::heliocent_coords(p, JD);
+        let (L, B) = precess::precess_ecl_coords(L, B, JD, 2451545.0);
+
+        assert_eq!(
+            util::round_upto_digits(long, 10),
+            util::round_upto_digits(angle::limit_to_two_PI(L), 10)
+        );
+        assert_eq!(
+            util::round_upto_digits(lat, 10),
+            util::round_upto_digits(B, 10)
+        );
+        assert_eq!(rad_vec, R);
+    }
+
+    match all[2].0 {
+        planet::Planet::Earth => (),
+        _ => panic!("Expected the Earth third from the Sun"),
+    }
+}
+
+#[test]
+fn transit_across_sun_local() {
+    let sydney = coords::GeographPoint {
+        long: -151.2093_f64.to_radians(),
+        lat: -33.8688_f64.to_radians(),
+    };
+    let transit =
+        planet::transit_across_sun_local(&planet::Planet::Venus, 2012.4, &sydney, 0.0).unwrap();
+
+    // Contacts on 2012 June 5-6, in TD, within a minute
+    let in_minutes = |JD: f64| util::round_upto_digits((JD - 2456084.0) * 1440.0, 0);
+    assert_eq!(in_minutes(transit.contact1), 618.0);
+    assert_eq!(in_minutes(transit.contact2.unwrap()), 636.0);
+    assert_eq!(in_minutes(transit.greatest), 812.0);
+    assert_eq!(in_minutes(transit.contact3.unwrap()), 987.0);
+    assert_eq!(in_minutes(transit.contact4), 1005.0);
+    assert_eq!(
+        util::round_upto_digits(transit.min_sepr.to_degrees() * 3600.0, 0),
+        574.0
+    );
+
+    // The transit happened during the night in Rio de Janeiro
+    let rio = coords::GeographPoint {
+        long: 43.1729_f64.to_radians(),
+        lat: -22.9068_f64.to_radians(),
+    };
+    assert!(planet::transit_across_sun_local(&planet::Planet::Venus, 2012.4, &rio, 0.0).is_none());
+
+    assert!(
+        planet::transit_across_sun_local(&planet::Planet::Venus, 2014.0, &sydney, 0.0).is_none()
+    );
+    assert!(
+        planet::transit_across_sun_local(&planet::Planet::Mars, 2012.4, &sydney, 0.0).is_none()
+    );
+}
+
+#[test]
+fn retrograde_midpoint() {
+    // Opposition of Jupiter on 2022 September 26, 19h33m UT
+    let opposition = 2459849.31;
+    let JD = planet::retrograde_midpoint(&planet::Planet::Jupiter, opposition, 150.0).unwrap();
+    assert!((JD - opposition).abs() < 1.0);
+
+    // Opposition of Mars on 2020 October 13, 23h26m UT
+    let opposition = 2459136.48;
+    let JD = planet::retrograde_midpoint(&planet::Planet::Mars, opposition, 120.0).unwrap();
+    assert!((JD - opposition).abs() < 2.0);
+
+    assert!(planet::retrograde_midpoint(&planet::Planet::Jupiter, opposition, 30.0).is_none());
+    assert!(planet::retrograde_midpoint(&planet::Planet::Earth, opposition, 150.0).is_none());
+}
+
+#[test]
+fn brightest_date() {
+    // Opposition of Mars on 2020 October 13, 23h26m UT
+    let (JD, mag) = planet::brightest_date(&planet::Planet::Mars, 2020).unwrap();
+    assert!((JD - 2459136.48).abs() < 2.0);
+    assert_eq!(util::round_upto_digits(mag, 1), -2.6);
+
+    // Greatest brilliancy of Venus on 2020 April 28
+    let (JD, mag) = planet::brightest_date(&planet::Planet::Venus, 2020).unwrap();
+    let (_, month, day) = time::date_frm_julian_day(JD).unwrap();
+    assert_eq!((month, day.floor()), (4, 28.0));
+    assert_eq!(util::round_upto_digits(mag, 1), -4.5);
+
+    assert!(planet::brightest_date(&planet::Planet::Earth, 2020).is_err());
+}
+
+#[test]
+fn heliocent_pos() {
+    let helio_coords = planet::heliocent_pos(&planet::Planet::Venus, 2448976.5);
+    let (long, lat, rad_vec) = planet::heliocent_coords(&planet::Planet::Venus, 2448976.5);
+    assert_eq!((helio_coords.long, helio_coords.rad_vec), (long, rad_vec));
+    assert_eq!(helio_coords.lat, lat - 2.0 * std::f64::consts::PI);
+    assert_eq!(
+        util::round_upto_digits(helio_coords.lat.to_degrees(), 3),
+        -2.621
+    );
+
+    // The Earth's distance from the Sun stays between its perihelion
+    // and aphelion distances, of about 0.9833 and 1.0167 AU
+    let mut JD = 2459945.5;
+    let (mut min_rad_vec, mut max_rad_vec) = (2.0_f64, 0.0_f64);
+    while JD < 2459945.5 + 365.25 {
+        let helio_coords = planet::heliocent_pos(&planet::Planet::Earth, JD);
+        min_rad_vec = min_rad_vec.min(helio_coords.rad_vec);
+        max_rad_vec = max_rad_vec.max(helio_coords.rad_vec);
+        assert!(helio_coords.lat.abs() < 1e-5);
+        JD += 1.0;
+    }
+    assert_eq!(util::round_upto_digits(min_rad_vec, 3), 0.983);
+    assert_eq!(util::round_upto_digits(max_rad_vec, 3), 1.017);
+}
+
+#[test]
+fn greatest_elongation() {
+    // Venus was at greatest eastern elongation, 46.1 degrees, on
+    // 2020 March 24 at about 22h UT, and at greatest western
+    // elongation, 45.8 degrees, on 2020 August 13 at about 0h UT
+    let JD_2020 = 2458849.5;
+    let (JD, elong) = planet::greatest_elongation(
+        &planet::InnerPlanet::Venus,
+        JD_2020,
+        &planet::Elongation::East,
+    )
+    .unwrap();
+    assert!((JD - (JD_2020 + 83.0 + 22.0 / 24.0)).abs() < 0.5);
+    assert_eq!(util::round_upto_digits(elong.to_degrees(), 1), 46.1);
+
+    let (JD_west, elong) =
+        planet::greatest_elongation(&planet::InnerPlanet::Venus, JD, &planet::Elongation::West)
+            .unwrap();
+    assert!((JD_west - (JD_2020 + 225.0)).abs() < 0.5);
+    assert_eq!(util::round_upto_digits(elong.to_degrees(), 1), 45.8);
+
+    // The search starts after the given instant
+    let (JD_next, _) =
+        planet::greatest_elongation(&planet::InnerPlanet::Venus, JD, &planet::Elongation::East)
+            .unwrap();
+    assert!((JD_next - JD - 583.9).abs() < 3.0);
+
+    // Mercury's greatest eastern elongation of 2020 February 10, 18.2
+    // degrees
+    let (JD, elong) = planet::greatest_elongation(
+        &planet::InnerPlanet::Mercury,
+        JD_2020,
+        &planet::Elongation::East,
+    )
+    .unwrap();
+    assert!((JD - (JD_2020 + 40.6)).abs() < 0.5);
+    assert_eq!(util::round_upto_digits(elong.to_degrees(), 1), 18.2);
+
+    // and those that follow it, a little more or less than the
+    // synodic period apart
+    let mut JD_prev = JD;
+    for _ in 0..6 {
+        let (JD, elong) = planet::greatest_elongation(
+            &planet::InnerPlanet::Mercury,
+            JD_prev,
+            &planet::Elongation::East,
+        )
+        .unwrap();
+        assert!(JD - JD_prev > 100.0 && JD - JD_prev < 130.0);
+        assert!(elong.to_degrees() > 17.8 && elong.to_degrees() < 28.0);
+        JD_prev = JD;
+    }
+
+    // Meeus, Astronomical Algorithms, example 36.b: the greatest
+    // western elongation of Mercury of 1993 November 22, 19.75 degrees
+    let (JD, elong) = planet::greatest_elongation(
+        &planet::InnerPlanet::Mercury,
+        2449261.5,
+        &planet::Elongation::West,
+    )
+    .unwrap();
+    assert!((JD - 2449314.14).abs() < 0.05);
+    assert_eq!(util::round_upto_digits(elong.to_degrees(), 2), 19.75);
+
+    assert!(planet::greatest_elongation(
+        &planet::InnerPlanet::Mercury,
+        std::f64::NAN,
+        &planet::Elongation::East
+    )
+    .is_err());
+}
+
+#[test]
+fn opposition_and_conjunction() {
+    let JD_2020 = 2458849.5;
+
+    // Jupiter was at opposition on 2020 July 14 at about 8h UT, and
+    // on 2021 August 20 at about 0h UT
+    let JD = planet::opposition(&planet::Planet::Jupiter, JD_2020).unwrap();
+    assert!((JD - 2459044.833).abs() < 0.01);
+    let JD = planet::opposition(&planet::Planet::Jupiter, JD).unwrap();
+    assert!((JD - 2459446.521).abs() < 0.01);
+    assert!(planet::opposition(&planet::Planet::Venus, JD_2020).is_err());
+
+    // Meeus, Astronomical Algorithms, example 36.a: the inferior
+    // conjunction of Mercury of 1993 November 6, at 3h TD
+    let JD = planet::conjunction(&planet::Planet::Mercury, 2449261.5).unwrap();
+    assert!((JD - 2449297.645).abs() < 0.01);
+
+    // The great conjunction of Jupiter and Saturn, of 2020 December 21
+    let JD = planet::mutual_conjunction(&planet::Planet::Jupiter, &planet::Planet::Saturn, JD_2020)
+        .unwrap();
+    assert!((JD - 2459205.265).abs() < 0.01);
+    assert!(
+        planet::mutual_conjunction(&planet::Planet::Mars, &planet::Planet::Mars, JD_2020).is_err()
+    );
+}
+
+#[test]
+fn stationary_point() {
+    // Mars was stationary on 2020 September 9, and again on 2020
+    // November 14, around its opposition of 2020 October 13
+    let JD_2020 = 2458849.5;
+    let JD1 = planet::stationary_point(&planet::Planet::Mars, JD_2020, &planet::Stationary::First)
+        .unwrap();
+    let JD2 =
+        planet::stationary_point(&planet::Planet::Mars, JD1, &planet::Stationary::Second).unwrap();
+    assert_eq!((JD1 - 0.5).floor() + 0.5, 2459101.5);
+    assert_eq!((JD2 - 0.5).floor() + 0.5, 2459167.5);
+
+    let opposition = planet::opposition(&planet::Planet::Mars, JD_2020).unwrap();
+    assert!(JD1 < opposition && opposition < JD2);
+
+    // The next first stationary point is about a synodic period later
+    let JD3 =
+        planet::stationary_point(&planet::Planet::Mars, JD1, &planet::Stationary::First).unwrap();
+    assert!((JD3 - JD1 - 779.9).abs() < 30.0);
+
+    // Mercury was stationary on 2020 February 17 at about 1h UT, and
+    // on 2020 March 10 at about 4h UT
+    let JD1 =
+        planet::stationary_point(&planet::Planet::Mercury, JD_2020, &planet::Stationary::First)
+            .unwrap();
+    let JD2 =
+        planet::stationary_point(&planet::Planet::Mercury, JD1, &planet::Stationary::Second)
+            .unwrap();
+    assert!((JD1 - 2458896.54).abs() < 0.01);
+    assert!((JD2 - 2458918.66).abs() < 0.01);
+
+    assert!(
+        planet::stationary_point(&planet::Planet::Earth, JD_2020, &planet::Stationary::First)
+            .is_err()
+    );
+}
@@ -107,3 +107,110 @@ fn precess_ecl_coords() {
         (118.704, 1.615)
     );
 }
+
+#[test]
+fn precession_matrices() {
+    let (JD1, JD2) = (2451545.0, 2462088.69);
+
+    // Theta Persei (Meeus, example 21.b)
+    let eq_point = coords::EqPoint {
+        asc: 41.054063_f64.to_radians(),
+        dec: 49.227750_f64.to_radians(),
+    };
+    let matrix = precess::precession_matrix_eq(JD1, JD2);
+    let new_eq_point = precess::apply_matrix(&matrix, &eq_point);
+    let alias_eq_point = precess::apply_matrix_eq(&matrix, &eq_point);
+    assert_eq!(
+        (alias_eq_point.asc, alias_eq_point.dec),
+        (new_eq_point.asc, new_eq_point.dec)
+    );
+    let (new_asc, new_dec) = precess::precess_eq_coords(eq_point.asc, eq_point.dec, JD1, JD2);
+    assert_eq!(
+        util::round_upto_digits(new_eq_point.asc, 10),
+        util::round_upto_digits(new_asc, 10)
+    );
+    assert_eq!(
+        util::round_upto_digits(new_eq_point.dec, 10),
+        util::round_upto_digits(new_dec, 10)
+    );
+
+    // Venus at -214 June 30.0 (Meeus, example 21.c)
+    let (long, lat) = (149.48194_f64.to_radians(), 1.76549_f64.to_radians());
+    let vector = [lat.cos() * long.cos(), lat.cos() * long.sin(), lat.sin()];
+    let v = coords::apply_matrix(
+        &precess::precession_matrix_ecl(2451545.0, 1643074.5),
+        &vector,
+    );
+    let (new_long, new_lat) = precess::precess_ecl_coords(long, lat, 2451545.0, 1643074.5);
+    assert_eq!(
+        util::round_upto_digits(angle::limit_to_two_PI(v[1].atan2(v[0])), 10),
+        util::round_upto_digits(angle::limit_to_two_PI(new_long), 10)
+    );
+    assert_eq!(
+        util::round_upto_digits(v[2].asin(), 10),
+        util::round_upto_digits(new_lat, 10)
+    );
+
+    // The matrices are rotations, so their inverse is their transpose
+    for matrix in [
+        precess::precession_matrix_eq(JD1, JD2),
+        precess::precession_matrix_ecl(JD1, JD2),
+    ]
+    .iter()
+    {
+        for i in 0..3 {
+            let product = coords::apply_matrix(matrix, &matrix[i]);
+            for j in 0..3 {
+                let identity = if i == j { 1.0 } else { 0.0 };
+                assert_eq!(util::round_upto_digits(product[j], 12), identity);
+            }
+        }
+    }
+
+    // and precessing back nearly undoes the precession
+    let back = precess::apply_matrix(&precess::precession_matrix_eq(JD2, JD1), &new_eq_point);
+    assert_eq!(
+        util::round_upto_digits(back.asc, 5),
+        util::round_upto_digits(eq_point.asc, 5)
+    );
+    assert_eq!(
+        util::round_upto_digits(back.dec, 5),
+        util::round_upto_digits(eq_point.dec, 5)
+    );
+}
+
+#[test]
+fn precess_eq_coords_2006() {
+    // Theta Persei
+    let (asc, dec) = (41.054063_f64.to_radians(), 49.227750_f64.to_radians());
+    let J2000 = 2451545.0;
+
+    let (asc_2006, dec_2006) = precess::precess_eq_coords_2006(asc, dec, J2000, J2000);
+    assert_eq!(
+        util::round_upto_digits(asc_2006, 12),
+        util::round_upto_digits(asc, 12)
+    );
+    assert_eq!(
+        util::round_upto_digits(dec_2006, 12),
+        util::round_upto_digits(dec, 12)
+    );
+
+    // The two models diverge by about a tenth of an arcsecond in 50 years
+    let JD = J2000 + 50.0 * 365.25;
+    let (asc_1976, dec_1976) = precess::precess_eq_coords(asc, dec, J2000, JD);
+    let (asc_2006, dec_2006) = precess::precess_eq_coords_2006(asc, dec, J2000, JD);
+    let diff_asc = (asc_2006 - asc_1976).to_degrees() * 3600.0 * dec.cos();
+    let diff_dec = (dec_2006 - dec_1976).to_degrees() * 3600.0;
+    assert_eq!(util::round_upto_digits(diff_asc, 2), -0.11);
+    assert_eq!(util::round_upto_digits(diff_dec, 2), -0.05);
+
+    let (back_asc, back_dec) = precess::precess_eq_coords_2006(asc_2006, dec_2006, JD, J2000);
+    assert_eq!(
+        util::round_upto_digits(back_asc, 10),
+        util::round_upto_digits(asc, 10)
+    );
+    assert_eq!(
+        util::round_upto_digits(back_dec, 10),
+        util::round_upto_digits(dec, 10)
+    );
+}
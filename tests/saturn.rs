@@ -76,3 +76,298 @@ fn moons() {
         assert_eq!(util::round_upto_digits(Y, 3), tuple.1);
     }
 }
+
+// Computes Phoebe's apparent distance from Saturn, in equatorial radii
+// of 60268 km, and its geocentric ecliptic longitude minus Saturn's,
+// from a two-body orbit with Phoebe's mean elements referred to the
+// ecliptic of J2000.0: a = 214.6 radii, e = 0.1635, i = 175.245 deg,
+// node = 241.57 deg, argument of pericenter = 342.5 deg, and
+// M = 53.0 + 0.6541771 * (t - J2000.0) deg.
+fn phoebe_two_body(JD: f64) -> (f64, f64) {
+    let (saturn, delta) = planet::geocent_apprnt_ecl_coords(&planet::Planet::Saturn, JD);
+    let (long, lat) = precess::precess_ecl_coords(saturn.long, saturn.lat, JD, 2451545.0);
+    let s = [
+        delta * lat.cos() * long.cos(),
+        delta * lat.cos() * long.sin(),
+        delta * lat.sin(),
+    ];
+
+    let d = JD - 0.0057755183 * delta - 2451545.0;
+    let M = (53.0 + 0.6541771 * d).to_radians();
+    let e: f64 = 0.1635;
+    let mut E = M;
+    for _ in 0..30 {
+        E = M + e * E.sin();
+    }
+    let a = 214.6 * 60268.0 / 149597870.7;
+    let (x0, y0) = (a * (E.cos() - e), a * (1.0 - e * e).sqrt() * E.sin());
+
+    let (node, i, w) = (
+        241.57_f64.to_radians(),
+        175.245_f64.to_radians(),
+        342.5_f64.to_radians(),
+    );
+    let x1 = x0 * w.cos() - y0 * w.sin();
+    let y1 = (x0 * w.sin() + y0 * w.cos()) * i.cos();
+    let z1 = (x0 * w.sin() + y0 * w.cos()) * i.sin();
+    let m = [
+        s[0] + x1 * node.cos() - y1 * node.sin(),
+        s[1] + x1 * node.sin() + y1 * node.cos(),
+        s[2] + z1,
+    ];
+
+    let cross = ((s[1] * m[2] - s[2] * m[1]).powi(2)
+        + (s[2] * m[0] - s[0] * m[2]).powi(2)
+        + (s[0] * m[1] - s[1] * m[0]).powi(2))
+    .sqrt();
+    let dot = s[0] * m[0] + s[1] * m[1] + s[2] * m[2];
+    let radius = 60268.0 / (delta * 149597870.7);
+
+    (
+        cross.atan2(dot) / radius,
+        angle::limit_to_pm_PI(m[1].atan2(m[0]) - long),
+    )
+}
+
+#[test]
+fn phoebe() {
+    for &JD in [2451439.50074, 2451639.5, 2451789.5, 2451939.5].iter() {
+        let (X, Y, _) =
+            planet::saturn::moon::apprnt_rect_coords(JD, &planet::saturn::moon::Moon::Phoebe);
+        let (dist, d_long) = phoebe_two_body(JD);
+
+        // within 0.02 equatorial radii, or about 0.2" of arc
+        assert!(((X * X + Y * Y).sqrt() - dist).abs() < 0.02);
+        // X is positive to the west, where the longitude is smaller
+        assert_eq!(X > 0.0, d_long < 0.0);
+    }
+}
+
+#[test]
+fn moon_coords_and_elements() {
+    let moon = planet::saturn::moon::Moon::Titan;
+    let coords = planet::saturn::moon::apprnt_coords(2451439.50074, &moon);
+    let (X, Y, Z) = planet::saturn::moon::apprnt_rect_coords(2451439.50074, &moon);
+    assert_eq!((coords.x, coords.y, coords.z), (X, Y, Z));
+    assert_eq!(util::round_upto_digits(coords.x, 3), 14.568);
+    assert_eq!(util::round_upto_digits(coords.y, 3), 4.738);
+
+    let elements = planet::saturn::moon::elements(2451439.50074, &moon);
+    assert_eq!(util::round_upto_digits(elements.r, 1), 19.9);
+}
+
+#[test]
+fn moon_phenomenon() {
+    let moon = planet::saturn::moon::Moon::Titan;
+
+    // Near the ring-plane crossing of 2009, Titan and its shadow
+    // crossed Saturn's disk on 2009 February 24; JD 2454887.0 is
+    // February 24 at 12h
+    let phenomenon = planet::saturn::moon::phenomenon(2454887.0, &moon);
+    assert!(phenomenon.transit);
+    assert!(phenomenon.shadow_transit);
+    assert!(!phenomenon.occultation);
+    assert!(!phenomenon.eclipse);
+
+    let phenomenon = planet::saturn::moon::phenomenon(2454884.5, &moon);
+    assert!(!phenomenon.transit);
+    assert!(!phenomenon.shadow_transit);
+}
+
+#[test]
+fn ring_geometry() {
+    let ring_elements = planet::saturn::ring_elements(2448972.50068);
+
+    assert_eq!(
+        util::round_upto_digits(ring_elements.B.to_degrees(), 3),
+        16.442
+    );
+    assert_eq!(
+        util::round_upto_digits(ring_elements.B_prime.to_degrees(), 3),
+        14.679
+    );
+    assert_eq!(
+        util::round_upto_digits(angle::limit_to_360(ring_elements.P.to_degrees()), 3),
+        6.741
+    );
+    assert_eq!(util::round_upto_digits(ring_elements.major_axis, 2), 35.87);
+    assert_eq!(util::round_upto_digits(ring_elements.minor_axis, 2), 10.15);
+}
+
+#[test]
+fn titan_iteration() {
+    let moon = planet::saturn::moon::Moon::Titan;
+    let coords = planet::saturn::moon::apprnt_coords(2451439.50074, &moon);
+
+    let converged = planet::saturn::moon::apprnt_coords_with_titan_iter(
+        2451439.50074,
+        &moon,
+        &planet::saturn::moon::TitanIteration {
+            max_iter: 50,
+            tolerance: 1e-15,
+        },
+    );
+    assert!((coords.x - converged.x).abs() < 1e-12);
+    assert!((coords.y - converged.y).abs() < 1e-12);
+
+    let three_iter = planet::saturn::moon::apprnt_coords_with_titan_iter(
+        2451439.50074,
+        &moon,
+        &planet::saturn::moon::TitanIteration {
+            max_iter: 3,
+            tolerance: 0.0,
+        },
+    );
+    assert_eq!(util::round_upto_digits(three_iter.x, 3), 14.568);
+
+    // Zero iterations are treated as one
+    let titan_coords = |max_iter| {
+        planet::saturn::moon::apprnt_coords_with_titan_iter(
+            2451439.50074,
+            &moon,
+            &planet::saturn::moon::TitanIteration {
+                max_iter,
+                tolerance: 0.0,
+            },
+        )
+    };
+    assert_eq!(titan_coords(0).x, titan_coords(1).x);
+    assert_eq!(titan_coords(0).y, titan_coords(1).y);
+
+    let max_iter = titan_coords(255);
+    assert!((max_iter.x - converged.x).abs() < 1e-12);
+    assert!((max_iter.y - converged.y).abs() < 1e-12);
+}
+
+#[test]
+fn max_elongation_in_year() {
+    let (JD_iapetus, sepr_iapetus, _) =
+        planet::saturn::moon::max_elongation_in_year(2016, &planet::saturn::moon::Moon::Iapetus);
+    let (JD_mimas, sepr_mimas, side_mimas) =
+        planet::saturn::moon::max_elongation_in_year(2016, &planet::saturn::moon::Moon::Mimas);
+
+    assert!(JD_iapetus > 2457388.5 && JD_iapetus < 2457754.5);
+    assert!(JD_mimas > 2457388.5 && JD_mimas < 2457754.5);
+    assert!(sepr_iapetus > 10.0 * sepr_mimas);
+
+    // Mimas's greatest elongation lies near opposition, where Saturn
+    // looks largest
+    assert!(sepr_mimas > 27.0 && sepr_mimas < 30.0);
+
+    let (X, _, _) =
+        planet::saturn::moon::apprnt_rect_coords(JD_mimas, &planet::saturn::moon::Moon::Mimas);
+    match side_mimas {
+        planet::saturn::moon::Side::West => assert!(X > 0.0),
+        planet::saturn::moon::Side::East => assert!(X < 0.0),
+    }
+
+    // The last year that fits in an i16
+    let (JD, _, _) =
+        planet::saturn::moon::max_elongation_in_year(32767, &planet::saturn::moon::Moon::Iapetus);
+    let JD_in_32767 = |month, decimal_day| {
+        time::julian_day(&time::Date {
+            year: 32767,
+            month,
+            decimal_day,
+            cal_type: time::CalType::Gregorian,
+        })
+    };
+    assert!(JD >= JD_in_32767(time::Month::Jan, 1.0) && JD <= JD_in_32767(time::Month::Dec, 32.0));
+}
+
+#[test]
+fn combined_magnitude_with_moons() {
+    // Near the opposition of 2016 June
+    let JD = 2457548.5;
+    let saturn = planet::apparent_magnitude(&planet::Planet::Saturn, JD).unwrap();
+    assert_eq!(
+        util::round_upto_digits(planet::saturn::combined_magnitude_with_moons(JD, &[]), 10),
+        util::round_upto_digits(saturn, 10)
+    );
+
+    let titan = planet::saturn::moon::apparent_magnitude(JD, &planet::saturn::moon::Moon::Titan);
+    assert_eq!(util::round_upto_digits(titan, 1), 8.5);
+
+    let combined =
+        planet::saturn::combined_magnitude_with_moons(JD, &[planet::saturn::moon::Moon::Titan]);
+    assert!(combined < saturn);
+    assert!(saturn - combined < 0.01);
+}
+
+#[test]
+fn ring_ansae() {
+    let JD = 2448972.50068;
+    let (east, west) = planet::saturn::ring_ansae(JD);
+    let centre = planet::apparent_place(&planet::Planet::Saturn, JD);
+    let ring = planet::saturn::ring_elements(JD);
+
+    let arcsec = |x: f64| util::round_upto_digits(x.to_degrees() * 3600.0, 4);
+    let pos_angl = |p: &coords::EqPoint| {
+        let d_asc = p.asc - centre.asc;
+        angle::limit_to_360(
+            (d_asc.sin())
+                .atan2(centre.dec.cos() * p.dec.tan() - centre.dec.sin() * d_asc.cos())
+                .to_degrees(),
+        )
+    };
+
+    // Both ansae lie half the major axis away from the centre, on
+    // opposite sides of it along the major axis
+    assert_eq!(
+        arcsec(east.anglr_sepr(&centre)),
+        util::round_upto_digits(ring.major_axis / 2.0, 4)
+    );
+    assert_eq!(
+        arcsec(west.anglr_sepr(&centre)),
+        util::round_upto_digits(ring.major_axis / 2.0, 4)
+    );
+    assert_eq!(
+        arcsec(east.anglr_sepr(&west)),
+        util::round_upto_digits(ring.major_axis, 4)
+    );
+
+    let P = angle::limit_to_360(ring.P.to_degrees());
+    assert_eq!(
+        util::round_upto_digits(pos_angl(&east), 6),
+        util::round_upto_digits(P + 90.0, 6)
+    );
+    assert_eq!(
+        util::round_upto_digits(pos_angl(&west), 6),
+        util::round_upto_digits(angle::limit_to_360(P - 90.0), 6)
+    );
+}
+
+#[test]
+fn moon_velocity() {
+    let JD = 2451439.50074;
+    let span = 0.2;
+    let steps = 100;
+
+    for moon in &[
+        planet::saturn::moon::Moon::Mimas,
+        planet::saturn::moon::Moon::Titan,
+        planet::saturn::moon::Moon::Iapetus,
+    ] {
+        let ((X, Y, _), _) = planet::saturn::moon::apprnt_rect_coords_and_velocity(JD, moon);
+        let (X1, Y1, _) = planet::saturn::moon::apprnt_rect_coords(JD, moon);
+        assert_eq!((X, Y), (X1, Y1));
+
+        // Integrate the velocity with the trapezoidal rule
+        let h = span / (steps as f64);
+        let (mut dX, mut dY) = (0.0, 0.0);
+        for i in 0..steps {
+            let (_, (X_dot1, Y_dot1, _)) =
+                planet::saturn::moon::apprnt_rect_coords_and_velocity(JD + (i as f64) * h, moon);
+            let (_, (X_dot2, Y_dot2, _)) = planet::saturn::moon::apprnt_rect_coords_and_velocity(
+                JD + ((i + 1) as f64) * h,
+                moon,
+            );
+            dX += (X_dot1 + X_dot2) * h / 2.0;
+            dY += (Y_dot1 + Y_dot2) * h / 2.0;
+        }
+
+        let (X2, Y2, _) = planet::saturn::moon::apprnt_rect_coords(JD + span, moon);
+        assert!((dX - (X2 - X)).abs() < 1e-4);
+        assert!((dY - (Y2 - Y)).abs() < 1e-4);
+    }
+}
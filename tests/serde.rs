@@ -0,0 +1,69 @@
+/*
+Copyright (c) 2015, 2016 Saurav Sachidanand
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+#![cfg(feature = "serde")]
+#![allow(non_snake_case)]
+
+extern crate astro;
+extern crate serde_json;
+
+use astro::*;
+
+#[test]
+fn round_trip() {
+    let eq_point = coords::EqPoint {
+        asc: 2.1,
+        dec: -0.3,
+    };
+    let json = serde_json::to_string(&eq_point).unwrap();
+    assert_eq!(json, r#"{"asc":2.1,"dec":-0.3}"#);
+    let eq_point: coords::EqPoint = serde_json::from_str(&json).unwrap();
+    assert_eq!((eq_point.asc, eq_point.dec), (2.1, -0.3));
+
+    let geograph_point = coords::GeographPoint {
+        long: 1.2,
+        lat: 0.4,
+    };
+    let json = serde_json::to_string(&geograph_point).unwrap();
+    let geograph_point: coords::GeographPoint = serde_json::from_str(&json).unwrap();
+    assert_eq!((geograph_point.long, geograph_point.lat), (1.2, 0.4));
+
+    let date = time::Date {
+        year: 1957,
+        month: time::Month::Oct,
+        decimal_day: 4.81,
+        cal_type: time::CalType::Gregorian,
+    };
+    let json = serde_json::to_string(&date).unwrap();
+    assert_eq!(
+        json,
+        r#"{"year":1957,"month":"Oct","decimal_day":4.81,"cal_type":"Gregorian"}"#
+    );
+    let date: time::Date = serde_json::from_str(&json).unwrap();
+    assert_eq!(time::julian_day(&date), 2436116.31);
+
+    let cal_type: time::CalType = serde_json::from_str(r#""Julian""#).unwrap();
+    match cal_type {
+        time::CalType::Julian => (),
+        time::CalType::Gregorian => panic!("CalType::Gregorian was deserialized"),
+    }
+}
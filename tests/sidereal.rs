@@ -0,0 +1,75 @@
+/*
+Copyright (c) 2015, 2016 Saurav Sachidanand
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in
+all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+THE SOFTWARE.
+*/
+
+#![allow(non_snake_case)]
+
+extern crate astro;
+
+use astro::*;
+
+#[test]
+fn greenwich_and_local_sidereal() {
+    // Meeus, Astronomical Algorithms, examples 12.a and 12.b
+    let hms = |x: f64| {
+        let (h, m, s) = angle::hms_frm_deg(x.to_degrees());
+        (h, m, util::round_upto_digits(s, 4))
+    };
+    assert_eq!(
+        hms(sidereal::mean_sidereal_greenwich(2446895.5)),
+        (13, 10, 46.3668)
+    );
+    assert_eq!(
+        hms(sidereal::apparent_sidereal_greenwich(2446895.5)),
+        (13, 10, 46.1351)
+    );
+    assert_eq!(
+        hms(sidereal::mean_sidereal_greenwich(2446896.30625)),
+        (8, 34, 57.0896)
+    );
+
+    // The equation of the equinoxes, Δψ cos ε
+    let (nut_in_long, nut_in_oblq) = nutation::nutation(2446895.5);
+    let true_oblq = ecliptic::mn_oblq_laskar(2446895.5) + nut_in_oblq;
+    let eqn_of_equinoxes = sidereal::apparent_sidereal_greenwich(2446895.5)
+        - sidereal::mean_sidereal_greenwich(2446895.5);
+    assert_eq!(
+        util::round_upto_digits(eqn_of_equinoxes, 12),
+        util::round_upto_digits(nut_in_long * true_oblq.cos(), 12)
+    );
+
+    // At the US Naval Observatory, 77°03'56" west of Greenwich
+    let long = angle::deg_frm_dms(77, 3, 56.0).to_radians();
+    let greenw_sidr = sidereal::mean_sidereal_greenwich(2446895.5);
+    assert_eq!(
+        hms(sidereal::local_sidereal(greenw_sidr, long)),
+        (8, 2, 30.6335)
+    );
+    assert_eq!(sidereal::local_sidereal(greenw_sidr, 0.0), greenw_sidr);
+
+    // and the result is within a day, on either side of Greenwich
+    let loc_sidr = sidereal::local_sidereal(0.1, 0.2);
+    assert!(loc_sidr > 0.0 && loc_sidr < 2.0 * std::f64::consts::PI);
+    assert_eq!(
+        util::round_upto_digits(loc_sidr, 12),
+        util::round_upto_digits(2.0 * std::f64::consts::PI - 0.1, 12)
+    );
+}
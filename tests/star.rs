@@ -24,6 +24,69 @@ extern crate astro;
 
 use astro::*;
 
+#[test]
+fn magnitudes() {
+    // Meeus's example 56.a, Castor
+    assert_eq!(
+        util::round_upto_digits(star::combined_mag(1.96, 2.89), 2),
+        1.58
+    );
+    assert_eq!(
+        util::round_upto_digits(star::combined_mag_of_many(&[1.96, 2.89]), 2),
+        1.58
+    );
+
+    // Meeus's example 56.b, a triple star
+    assert_eq!(
+        util::round_upto_digits(star::combined_mag_of_many(&[4.73, 5.22, 5.60]), 2),
+        3.93
+    );
+    assert_eq!(star::combined_mag_of_many(&[4.73]), 4.73);
+    assert_eq!(star::combined_mag_of_many(&[]), f64::INFINITY);
+
+    // Meeus's example 56.c, Rigel and Polaris
+    assert_eq!(
+        util::round_upto_digits(star::brightness_ratio(0.14, 2.12), 2),
+        6.19
+    );
+
+    // Meeus's example 56.d
+    assert_eq!(util::round_upto_digits(star::mag_diff(500.0), 2), 6.75);
+}
+
+#[test]
+fn abs_mag_and_dist() {
+    // Sirius, with a parallax of 0.37921" and V = -1.46
+    let par = (0.37921 / 3600.0_f64).to_radians();
+    let am = -1.46;
+
+    let d = star::dist_frm_parallax(par).unwrap();
+    assert_eq!(util::round_upto_digits(d, 4), 2.6371);
+
+    let abs_mag = star::abs_mag_frm_parallax(par, am);
+    assert_eq!(util::round_upto_digits(abs_mag, 2), 1.43);
+    assert_eq!(star::absolute_magnitude(am, par).unwrap(), abs_mag);
+    assert_eq!(
+        util::round_upto_digits(star::abs_mag_frm_dist(d, am), 10),
+        util::round_upto_digits(abs_mag, 10)
+    );
+
+    let dist_mod = star::dist_modulus(am, abs_mag);
+    assert_eq!(util::round_upto_digits(dist_mod, 2), -2.89);
+    assert_eq!(
+        util::round_upto_digits(star::dist_frm_dist_modulus(dist_mod), 10),
+        util::round_upto_digits(d, 10)
+    );
+
+    assert!(star::dist_frm_parallax(0.0).is_err());
+    assert!(star::dist_frm_parallax(-par).is_err());
+    assert!(star::dist_frm_parallax(f64::NAN).is_err());
+
+    assert!(star::absolute_magnitude(am, 0.0).is_err());
+    assert!(star::absolute_magnitude(am, -par).is_err());
+    assert!(star::absolute_magnitude(am, f64::NAN).is_err());
+}
+
 #[test]
 fn eq_coords_frm_motion() {
     let (asc, dec) = star::eq_coords_frm_motion(
@@ -42,3 +105,56 @@ fn eq_coords_frm_motion() {
     let (d2, m2, s2) = angle::dms_frm_deg(dec.to_degrees());
     assert_eq!((d2, m2, util::round_upto_digits(s2, 1)), (-16, -22, -56.0));
 }
+
+#[test]
+fn apply_space_motion() {
+    let sirius = coords::EqPoint {
+        asc: 101.286962_f64.to_radians(),
+        dec: -16.716108_f64.to_radians(),
+    };
+    let pm_asc = (-0.03847 * 15.0 / 3600.0_f64).to_radians();
+    let pm_dec = (-1.20530 / 3600.0_f64).to_radians();
+    let parallax = (0.37921 / 3600.0_f64).to_radians();
+
+    let eq_point = star::apply_space_motion(
+        &sirius,
+        pm_asc,
+        pm_dec,
+        parallax,
+        -7.6,
+        2451545.0,
+        2451545.0 - 2000.0 * 365.25,
+    );
+
+    let (h1, m1, s1) = angle::hms_frm_deg(eq_point.asc.to_degrees());
+    assert_eq!((h1, m1, util::round_upto_digits(s1, 2)), (6, 46, 25.09));
+
+    let (d2, m2, s2) = angle::dms_frm_deg(eq_point.dec.to_degrees());
+    assert_eq!((d2, m2, util::round_upto_digits(s2, 1)), (-16, -3, -0.8));
+
+    // Barnard's Star, whose proper motion in declination grows by about
+    // 1.3 milliarcseconds a year due to its approach
+    let barnard = coords::EqPoint {
+        asc: 269.45402305_f64.to_radians(),
+        dec: 4.66828815_f64.to_radians(),
+    };
+    let pm_asc = (-0.79784 / 3600.0 / barnard.dec.cos()).to_radians();
+    let pm_dec = (10.32693 / 3600.0_f64).to_radians();
+    let epoch = 2448349.0625;
+    let after_century = epoch + 100.0 * 365.25;
+
+    let rigorous = star::apply_space_motion(
+        &barnard,
+        pm_asc,
+        pm_dec,
+        (0.54901 / 3600.0_f64).to_radians(),
+        -110.51,
+        epoch,
+        after_century,
+    );
+    let linear =
+        star::apply_space_motion(&barnard, pm_asc, pm_dec, 0.0, -110.51, epoch, after_century);
+
+    let diff_dec = (rigorous.dec - linear.dec).to_degrees() * 3600.0;
+    assert_eq!(util::round_upto_digits(diff_dec, 1), 6.4);
+}
@@ -80,3 +80,376 @@ fn approx_synd_rot() {
         2444480.72
     );
 }
+
+#[test]
+fn sunrise_sunset_azimuths() {
+    let geograph_point = coords::GeographPoint {
+        long: 0.0,
+        lat: 51.4769_f64.to_radians(),
+    };
+
+    let asymmetry = |month: time::Month, day: f64| {
+        let date = time::Date {
+            year: 2016,
+            month: month,
+            decimal_day: day,
+            cal_type: time::CalType::Gregorian,
+        };
+        let (rise, set) = sun::sunrise_sunset_azimuths(&date, &geograph_point);
+
+        (rise.unwrap() - (2.0 * std::f64::consts::PI - set.unwrap())).abs()
+    };
+
+    let near_equinox = asymmetry(time::Month::Mar, 20.0);
+    let near_solstice = asymmetry(time::Month::June, 20.0);
+    assert!(near_equinox > 10.0 * near_solstice);
+    assert!(near_equinox.to_degrees() > 0.2);
+
+    let arctic_point = coords::GeographPoint {
+        long: 0.0,
+        lat: 80_f64.to_radians(),
+    };
+    let date = time::Date {
+        year: 2016,
+        month: time::Month::June,
+        decimal_day: 20.0,
+        cal_type: time::CalType::Gregorian,
+    };
+    assert_eq!(
+        sun::sunrise_sunset_azimuths(&date, &arctic_point),
+        (None, None)
+    );
+}
+
+#[test]
+fn apparent_diameter() {
+    // Near perihelion and aphelion in 2016
+    let perihelion = sun::apparent_diameter(2457391.5).to_degrees() * 60.0;
+    let aphelion = sun::apparent_diameter(2457574.5).to_degrees() * 60.0;
+
+    assert_eq!(util::round_upto_digits(perihelion, 1), 32.5);
+    assert_eq!(util::round_upto_digits(aphelion, 1), 31.5);
+}
+
+#[test]
+fn equinox_solstice() {
+    // Meeus, example 27.a: 1962 June 21, 21h 24m 42s TD
+    let JD = sun::equinox_solstice(1962, &sun::EquinoxSolstice::JuneSolstice);
+    assert!((JD - 2437837.39215).abs() * 1440.0 < 1.0);
+
+    // The events of 2000, to the minute in UT, with a ΔT of 64 seconds
+    let delta_t = 64.0 / 86400.0;
+    let data = [
+        (sun::EquinoxSolstice::MarchEquinox, 2451623.815972),
+        (sun::EquinoxSolstice::JuneSolstice, 2451716.575),
+        (sun::EquinoxSolstice::SeptemberEquinox, 2451810.227083),
+        (sun::EquinoxSolstice::DecemberSolstice, 2451900.067361),
+    ];
+    for &(ref event, JD_UT) in data.iter() {
+        let JD = sun::equinox_solstice(2000, event);
+        assert!((JD - JD_UT - delta_t).abs() * 1440.0 < 1.0);
+    }
+
+    // Before 1000, table 27.A gives the mean instant, and beyond 3000
+    // table 27.B is extrapolated; the March equinox is still found
+    // within the year, with the Sun on the equator
+    for &year in [-500, 500, 3500].iter() {
+        let JD = sun::equinox_solstice(year, &sun::EquinoxSolstice::MarchEquinox);
+        let (sun_eq_point, _) = sun::apprnt_eq_pos(JD);
+        assert!(sun_eq_point.dec.abs() < angle::deg_frm_dms(0, 0, 1.0).to_radians());
+        assert!(angle::limit_to_pm_PI(sun_eq_point.asc).abs() < 1e-5);
+        let years = (JD - 2451623.80984) / 365.2422;
+        assert!((years - (year as f64 - 2000.0)).abs() < 0.1);
+    }
+}
+
+#[test]
+fn equation_of_time() {
+    // Meeus, example 28.a
+    let E = sun::equation_of_time(2448908.5);
+    assert_eq!(util::round_upto_digits(E.to_degrees(), 3), 3.427);
+
+    // The extreme values, near 2016 November 3 and 2016 February 11
+    let minutes = |JD: f64| sun::equation_of_time(JD).to_degrees() * 4.0;
+    assert_eq!(util::round_upto_digits(minutes(2457695.5), 1), 16.4);
+    assert_eq!(util::round_upto_digits(minutes(2457429.5), 1), -14.2);
+}
+
+#[test]
+fn eq_coords_rate() {
+    let solstice = sun::equinox_solstice(2016, &sun::EquinoxSolstice::JuneSolstice);
+    let (solstice_asc_rate, solstice_dec_rate) = sun::eq_coords_rate(solstice);
+    assert!(solstice_dec_rate.to_degrees().abs() < 1e-4);
+
+    // Near the equinox, the Sun moves fastest in declination, by about
+    // 0.4 degrees a day, and slowest in right ascension
+    let equinox = sun::equinox_solstice(2016, &sun::EquinoxSolstice::MarchEquinox);
+    let (equinox_asc_rate, equinox_dec_rate) = sun::eq_coords_rate(equinox);
+    assert_eq!(util::round_upto_digits(equinox_dec_rate.to_degrees(), 1), 0.4);
+    assert!(solstice_asc_rate > equinox_asc_rate);
+
+    // Across 0h of right ascension
+    assert!(equinox_asc_rate > 0.0);
+}
+
+#[test]
+fn geocent_rect_coords() {
+    let JD = 2448908.5;
+    let (_, sun_earth_dist) = sun::geocent_ecl_pos(JD);
+
+    // Meeus, example 26.a, with the abridged VSOP87 theory of the
+    // crate good to about 1e-5 AU
+    let (x, y, z) = sun::geocent_rect_coords_of_date(JD);
+    assert!((x - -0.9379952).abs() < 1e-5);
+    assert!((y - -0.3116544).abs() < 1e-5);
+    assert!((z - -0.1351215).abs() < 1e-5);
+    assert_eq!(
+        util::round_upto_digits(x * x + y * y + z * z, 10),
+        util::round_upto_digits(sun_earth_dist * sun_earth_dist, 10)
+    );
+
+    // and referred to the equinox of J2000.0 (Meeus, chapter 26)
+    let (x, y, z) = sun::geocent_rect_coords_J2000(JD);
+    assert!((x - -0.93740485).abs() < 1e-5);
+    assert!((y - -0.31316758).abs() < 1e-5);
+    assert!((z - -0.13577924).abs() < 1e-5);
+    assert_eq!(
+        util::round_upto_digits(x * x + y * y + z * z, 10),
+        util::round_upto_digits(sun_earth_dist * sun_earth_dist, 10)
+    );
+}
+
+#[test]
+fn sky_state() {
+    // Stockholm, where the Sun at midnight in midsummer is about 7
+    // degrees below the horizon
+    let geograph_point = coords::GeographPoint {
+        long: -18.07_f64.to_radians(),
+        lat: 59.33_f64.to_radians(),
+    };
+
+    // Local midnight, near 22h 50m UT
+    match sun::sky_state(2457560.45, &geograph_point) {
+        sun::SkyState::NauticalTwilight => (),
+        _ => panic!("Expected nautical twilight at midnight in midsummer"),
+    }
+    match sun::sky_state(2457560.95, &geograph_point) {
+        sun::SkyState::Day => (),
+        _ => panic!("Expected day at noon in midsummer"),
+    }
+    match sun::sky_state(2457743.45, &geograph_point) {
+        sun::SkyState::Night => (),
+        _ => panic!("Expected night at midnight in midwinter"),
+    }
+}
+
+#[test]
+fn antisolar_horiz() {
+    let geograph_point = coords::GeographPoint {
+        long: 0.0,
+        lat: 51.4769_f64.to_radians(),
+    };
+    let JD = 2457560.5;
+
+    // The local sidereal time at which the Sun sets
+    let sun_eq_point = ephem::position(&ephem::Body::Sun, JD, &coords::Frame::EqOfDate).unwrap();
+    let h0 = -0.8333_f64.to_radians();
+    let cos_H0 = (h0.sin() - geograph_point.lat.sin() * sun_eq_point.dec.sin())
+        / (geograph_point.lat.cos() * sun_eq_point.dec.cos());
+    let local_sidr = sun_eq_point.asc + cos_H0.acos();
+
+    let hour_angle = coords::hr_angl_frm_loc_sidr(local_sidr, sun_eq_point.asc);
+    let sun_az = coords::az_frm_eq(hour_angle, sun_eq_point.dec, geograph_point.lat);
+
+    let horiz_point = sun::antisolar_horiz(JD, &geograph_point, local_sidr);
+    assert_eq!(
+        util::round_upto_digits(horiz_point.alt.to_degrees(), 3),
+        0.833
+    );
+
+    // Opposite the Sun, in the south-east, the Sun having set in the
+    // north-west
+    assert_eq!(
+        util::round_upto_digits(
+            angle::limit_to_two_PI(horiz_point.az - sun_az).to_degrees(),
+            3
+        ),
+        180.0
+    );
+    let az = angle::limit_to_two_PI(horiz_point.az).to_degrees();
+    assert!(az > 270.0 && az < 360.0);
+}
+
+#[test]
+fn rise_set_with_definition() {
+    let geograph_point = coords::GeographPoint {
+        long: 0.0,
+        lat: 51.4769_f64.to_radians(),
+    };
+    let date = time::Date {
+        year: 2016,
+        month: time::Month::June,
+        decimal_day: 21.0,
+        cal_type: time::CalType::Gregorian,
+    };
+
+    let (rise_limb, set_limb) =
+        sun::rise_set_with_definition(&date, &geograph_point, &sun::SunDisk::UpperLimb);
+    let (rise_center, set_center) =
+        sun::rise_set_with_definition(&date, &geograph_point, &sun::SunDisk::Center);
+    let (rise_limb, set_limb) = (rise_limb.unwrap(), set_limb.unwrap());
+    let (rise_center, set_center) = (rise_center.unwrap(), set_center.unwrap());
+
+    // Sunrise at 3h 42m UT, and sunset at 20h 20m UT, at Greenwhich
+    let (h, m, _) = angle::hms_frm_deg(angle::limit_to_360((rise_limb - 0.5).fract() * 360.0));
+    assert_eq!((h, m), (3, 42));
+    let (h, m, _) = angle::hms_frm_deg(angle::limit_to_360((set_limb - 0.5).fract() * 360.0));
+    assert_eq!((h, m), (20, 20));
+
+    // The center rises a couple of minutes after the upper limb
+    let rise_delay = (rise_center - rise_limb) * 1440.0;
+    let set_advance = (set_limb - set_center) * 1440.0;
+    assert!(rise_delay > 2.0 && rise_delay < 3.0);
+    assert!(set_advance > 2.0 && set_advance < 3.0);
+
+    let arctic_point = coords::GeographPoint {
+        long: 0.0,
+        lat: 70_f64.to_radians(),
+    };
+    let (rise, set) = sun::rise_set_with_definition(&date, &arctic_point, &sun::SunDisk::UpperLimb);
+    assert!(rise.is_none() && set.is_none());
+}
+
+#[test]
+fn rise_set_local() {
+    let hm = |date: &time::Date| {
+        let day_of_month = date.day_of_month();
+        (
+            date.month as u8,
+            day_of_month.day,
+            day_of_month.hr,
+            day_of_month.min,
+        )
+    };
+
+    // Sunrise at Greenwhich on 2016 June 21 at 3h 42m UT is at 9h 12m
+    // in Indian Standard Time
+    let greenwhich = coords::GeographPoint {
+        long: 0.0,
+        lat: 51.4769_f64.to_radians(),
+    };
+    let date = time::Date {
+        year: 2016,
+        month: time::Month::June,
+        decimal_day: 21.0,
+        cal_type: time::CalType::Gregorian,
+    };
+    let (rise, set) = sun::rise_set_local(&date, &greenwhich, &sun::SunDisk::UpperLimb, 5.5);
+    assert_eq!(hm(&rise.unwrap()), (6, 21, 9, 12));
+    assert_eq!(hm(&set.unwrap()), (6, 22, 1, 50));
+
+    // Sunrise at Sydney on the morning of 2016 June 21 is at 7h 00m
+    // local time (UTC+10), which is 21h 00m UT on June 20, and 2h 30m
+    // on June 21 in Indian Standard Time
+    let sydney = coords::GeographPoint {
+        long: -151.2093_f64.to_radians(),
+        lat: -33.8688_f64.to_radians(),
+    };
+    let (rise, _) = sun::rise_set_local(&date, &sydney, &sun::SunDisk::UpperLimb, 10.0);
+    assert_eq!(hm(&rise.unwrap()), (6, 21, 7, 0));
+    let (rise, _) = sun::rise_set_local(&date, &sydney, &sun::SunDisk::UpperLimb, 0.0);
+    assert_eq!(hm(&rise.unwrap()), (6, 20, 21, 0));
+    let (rise, _) = sun::rise_set_local(&date, &sydney, &sun::SunDisk::UpperLimb, 5.5);
+    assert_eq!(hm(&rise.unwrap()), (6, 21, 2, 30));
+
+    let arctic_point = coords::GeographPoint {
+        long: 0.0,
+        lat: 70_f64.to_radians(),
+    };
+    let (rise, set) = sun::rise_set_local(&date, &arctic_point, &sun::SunDisk::UpperLimb, 5.5);
+    assert!(rise.is_none() && set.is_none());
+}
+
+#[test]
+fn axis_position_angle() {
+    let P = sun::axis_position_angle(2448908.50068).to_degrees();
+    assert_eq!(util::round_upto_digits(P, 2), 26.27);
+
+    let april = sun::axis_position_angle(2461139.5).to_degrees();
+    let october = sun::axis_position_angle(2461322.5).to_degrees();
+    assert!(april < -26.0 && april > -26.5);
+    assert!(october > 26.0 && october < 26.5);
+}
+
+#[test]
+fn carrington_rotation() {
+    let JD = 2448908.50068;
+    let (B0, L0, P) = sun::heliographic_disk_center(JD);
+
+    assert_eq!(util::round_upto_digits(B0.to_degrees(), 2), 5.99);
+    assert_eq!(util::round_upto_digits(L0.to_degrees(), 2), 238.63);
+    assert_eq!(util::round_upto_digits(P.to_degrees(), 2), 26.27);
+    assert_eq!(P, sun::axis_position_angle(JD));
+
+    let rot = sun::carrington_rotation(JD);
+    assert_eq!(rot.floor(), 1861.0);
+    assert_eq!(
+        util::round_upto_digits(rot.fract(), 4),
+        util::round_upto_digits(1.0 - 238.63 / 360.0, 4)
+    );
+
+    // A new rotation begins as L0 wraps through 0°
+    let JD_start = sun::synodic_rot(2300);
+    let before = sun::carrington_rotation(JD_start - 0.01);
+    let after = sun::carrington_rotation(JD_start + 0.01);
+    assert_eq!(before.floor(), 2299.0);
+    assert_eq!(after.floor(), 2300.0);
+    assert!((after - before - 0.02 / 27.2753).abs() < 1e-4);
+
+    let (_, L0_before, _) = sun::heliographic_disk_center(JD_start - 0.01);
+    let (_, L0_after, _) = sun::heliographic_disk_center(JD_start + 0.01);
+    assert!(L0_before.to_degrees() < 1.0 && L0_after.to_degrees() > 359.0);
+}
+
+#[test]
+fn sunspot_heliographic() {
+    let JD = 2448908.50068;
+    let (B0, L0, P) = sun::heliographic_disk_center(JD);
+    let semidia = sun::apparent_diameter(JD) / 2.0;
+
+    // The center of the disk
+    let (lat, long) = sun::sunspot_heliographic(JD, 0.0, 0.0);
+    assert_eq!(
+        util::round_upto_digits(lat.to_degrees(), 9),
+        util::round_upto_digits(B0.to_degrees(), 9)
+    );
+    assert_eq!(
+        util::round_upto_digits(long.to_degrees(), 9),
+        util::round_upto_digits(L0.to_degrees(), 9)
+    );
+
+    // Sunspots projected onto the disk, and back
+    for &(lat, long) in &[
+        (20.0_f64, 200.0_f64),
+        (-15.0, 270.0),
+        (35.0, 300.0),
+        (-5.0, 160.0),
+    ] {
+        let (lat, long) = (lat.to_radians(), long.to_radians());
+        let x = lat.cos() * (long - L0).sin();
+        let y = lat.sin() * B0.cos() - lat.cos() * B0.sin() * (long - L0).cos();
+        let rho = x.hypot(y).asin();
+        let measured_rho = (rho.sin() / (1.0 / semidia.sin() - rho.cos())).atan();
+        let measured_pa = P - x.atan2(y);
+
+        let (lat1, long1) = sun::sunspot_heliographic(JD, measured_rho, measured_pa);
+        assert!((lat1 - lat).abs().to_degrees() < 1e-6);
+        assert!((long1 - long).abs().to_degrees() < 1e-6);
+    }
+
+    // Beyond the limb is taken as on the limb
+    assert_eq!(
+        sun::sunspot_heliographic(JD, 1.01 * semidia, 1.0),
+        sun::sunspot_heliographic(JD, semidia, 1.0)
+    );
+}
@@ -209,3 +209,489 @@ fn date_frm_julian_day() {
         assert_eq!(util::round_upto_digits(day, 2), data.2);
     }
 }
+
+#[test]
+fn to_local() {
+    let date = time::Date {
+        year: 2016,
+        month: time::Month::June,
+        decimal_day: 21.0,
+        cal_type: time::CalType::Gregorian,
+    };
+    let geograph_point = coords::GeographPoint {
+        long: 0.0,
+        lat: 51.4769_f64.to_radians(),
+    };
+    let (rise, set) =
+        sun::rise_set_with_definition(&date, &geograph_point, &sun::SunDisk::UpperLimb);
+
+    // Sunrise at Greenwhich at 3h 42m UT is at 9h 12m in IST
+    let (year, month, decimal_day) = time::date_frm_julian_day(rise.unwrap()).unwrap();
+    assert_eq!((year, month), (2016, 6));
+    let utc = time::Date {
+        year,
+        month: time::Month::June,
+        decimal_day,
+        cal_type: time::CalType::Gregorian,
+    };
+    let local = utc.to_local(5.5).unwrap();
+    let (h, m, _) = angle::hms_frm_deg(local.decimal_day.fract() * 360.0);
+    assert_eq!(
+        (
+            local.year,
+            local.month as u8,
+            local.decimal_day.floor(),
+            h,
+            m
+        ),
+        (2016, 6, 21.0, 9, 12)
+    );
+
+    // and sunset at 20h 20m UT is at 1h 50m on the next day
+    let (_, _, decimal_day) = time::date_frm_julian_day(set.unwrap()).unwrap();
+    let utc = time::Date {
+        year,
+        month: time::Month::June,
+        decimal_day,
+        cal_type: time::CalType::Gregorian,
+    };
+    let local = utc.to_local(5.5).unwrap();
+    let (h, m, _) = angle::hms_frm_deg(local.decimal_day.fract() * 360.0);
+    assert_eq!(
+        (
+            local.year,
+            local.month as u8,
+            local.decimal_day.floor(),
+            h,
+            m
+        ),
+        (2016, 6, 22.0, 1, 50)
+    );
+
+    let new_year = time::Date {
+        year: 2016,
+        month: time::Month::Dec,
+        decimal_day: 31.9,
+        cal_type: time::CalType::Gregorian,
+    }
+    .to_local(5.5)
+    .unwrap();
+    assert_eq!((new_year.year, new_year.month as u8), (2017, 1));
+    assert_eq!(util::round_upto_digits(new_year.decimal_day, 6), 1.129167);
+
+    let leap_day = time::Date {
+        year: 2016,
+        month: time::Month::Mar,
+        decimal_day: 1.1,
+        cal_type: time::CalType::Gregorian,
+    }
+    .to_local(-8.0)
+    .unwrap();
+    assert_eq!((leap_day.year, leap_day.month as u8), (2016, 2));
+    assert_eq!(util::round_upto_digits(leap_day.decimal_day, 6), 29.766667);
+
+    let last_year = time::Date {
+        year: 32767,
+        month: time::Month::Dec,
+        decimal_day: 31.9,
+        cal_type: time::CalType::Gregorian,
+    };
+    assert!(last_year.to_local(5.5).is_err());
+    assert!(last_year.to_local(-5.5).is_ok());
+
+    let first_year = time::Date {
+        year: -32768,
+        month: time::Month::Jan,
+        decimal_day: 1.1,
+        cal_type: time::CalType::Julian,
+    };
+    assert!(first_year.to_local(-5.5).is_err());
+    assert!(first_year.to_local(5.5).is_ok());
+}
+
+#[test]
+fn iso_8601() {
+    for s in [
+        "2024-03-20T12:34:56Z",
+        "2024-03-20T12:34:56.250Z",
+        "2000-01-01T12:00:00Z",
+        "1957-10-04T19:28:34Z",
+        "1582-10-04T23:59:59.999Z",
+        "-0584-05-28T00:00:00Z",
+    ]
+    .iter()
+    {
+        let date: time::Date = s.parse().unwrap();
+        assert_eq!(&date.to_string(), s);
+    }
+
+    // The Julian Day of Meeus's example 7.a
+    let date: time::Date = "1957-10-04T19:26:24Z".parse().unwrap();
+    assert_eq!(
+        util::round_upto_digits(time::julian_day(&date), 5),
+        2436116.31
+    );
+
+    // Dates before 1582 October 15 are in the Julian calendar
+    let date: time::Date = "1582-10-04".parse().unwrap();
+    assert!(matches!(date.cal_type, time::CalType::Julian));
+    assert_eq!(time::julian_day(&date), 2299159.5);
+    let date: time::Date = "1582-10-15".parse().unwrap();
+    assert!(matches!(date.cal_type, time::CalType::Gregorian));
+    assert_eq!(time::julian_day(&date), 2299160.5);
+
+    let date: time::Date = "2024-03-20T12:34".parse().unwrap();
+    assert_eq!(date.to_string(), "2024-03-20T12:34:00Z");
+
+    // Time zones are converted to UTC
+    let date: time::Date = "2024-01-01T02:30:00+05:30".parse().unwrap();
+    assert_eq!(date.to_string(), "2023-12-31T21:00:00Z");
+    let date: time::Date = "2024-02-28T20:00:00-08:00".parse().unwrap();
+    assert_eq!(date.to_string(), "2024-02-29T04:00:00Z");
+
+    let err = |s: &str| s.parse::<time::Date>().unwrap_err();
+    assert_eq!(err("2024/03/20"), time::ParseDateError::Format);
+    assert_eq!(err("2024-03-20T12"), time::ParseDateError::Format);
+    assert_eq!(err("2024-3-20"), time::ParseDateError::Format);
+    assert_eq!(err("40000-01-01"), time::ParseDateError::Format);
+    assert_eq!(err("2024-13-20"), time::ParseDateError::Month);
+    assert_eq!(err("2023-02-29"), time::ParseDateError::Day);
+    assert_eq!(err("2024-03-20T24:00:00Z"), time::ParseDateError::Time);
+    assert_eq!(err("2024-03-20T12:34:60Z"), time::ParseDateError::Time);
+    assert_eq!(
+        err("32767-12-31T23:00:00-05:00"),
+        time::ParseDateError::Time
+    );
+}
+
+#[test]
+fn unix_timestamp() {
+    let epoch = time::Date::from_unix_timestamp(0).unwrap();
+    assert_eq!(epoch.to_string(), "1970-01-01T00:00:00Z");
+    assert_eq!(time::julian_day(&epoch), 2440587.5);
+    assert_eq!(epoch.to_unix_timestamp(), 0);
+
+    let date = time::Date::from_unix_timestamp(1710938096).unwrap();
+    assert_eq!(date.to_string(), "2024-03-20T12:34:56Z");
+    let date: time::Date = "2024-03-20T12:34:56Z".parse().unwrap();
+    assert_eq!(date.to_unix_timestamp(), 1710938096);
+
+    // Before 1970, the launch of Sputnik 1
+    let date = time::Date::from_unix_timestamp(-386310686).unwrap();
+    assert_eq!(date.to_string(), "1957-10-04T19:28:34Z");
+    assert_eq!(date.to_unix_timestamp(), -386310686);
+
+    let date = time::Date::from_unix_timestamp(-1).unwrap();
+    assert_eq!(date.to_string(), "1969-12-31T23:59:59Z");
+
+    for &secs in [-12219292801_i64, -12219292800, 4102444799].iter() {
+        let date = time::Date::from_unix_timestamp(secs).unwrap();
+        assert_eq!(date.to_unix_timestamp(), secs);
+    }
+    assert!(time::Date::from_unix_timestamp(-300000000000).is_err());
+
+    // The last second of the year 32767, the largest that fits in an i16
+    let new_year_32768 = time::julian_day(&time::Date {
+        year: 32767,
+        month: time::Month::Dec,
+        decimal_day: 32.0,
+        cal_type: time::CalType::Gregorian,
+    });
+    let last_sec = ((new_year_32768 - 2440587.5) * 86400.0) as i64 - 1;
+    let date = time::Date::from_unix_timestamp(last_sec).unwrap();
+    assert_eq!(date.to_string(), "32767-12-31T23:59:59Z");
+    assert!(time::Date::from_unix_timestamp(last_sec + 1).is_err());
+    assert!(time::Date::from_unix_timestamp(1_100_000_000_000).is_err());
+}
+
+#[test]
+fn modified_julian_day() {
+    let date = time::date_from_mjd(51544.5).unwrap();
+    assert_eq!(date.to_string(), "2000-01-01T12:00:00Z");
+    assert_eq!(time::modified_julian_day(&date), 51544.5);
+    assert_eq!(time::julian_day(&date), 2451545.0);
+
+    let date = time::date_from_mjd(51544.0).unwrap();
+    assert_eq!(date.to_string(), "2000-01-01T00:00:00Z");
+    assert_eq!(
+        time::date_from_mjd(0.0).unwrap().to_string(),
+        "1858-11-17T00:00:00Z"
+    );
+
+    assert_eq!(time::julian_day_frm_mjd(51544.5), 2451545.0);
+    assert_eq!(time::mjd_frm_julian_day(2451545.0), 51544.5);
+}
+
+#[test]
+fn day_of_week_and_year() {
+    // Meeus, Astronomical Algorithms, examples 7.e, 7.f and 7.g
+    let date: time::Date = "1954-06-30".parse().unwrap();
+    assert!(matches!(time::day_of_week(&date), time::Weekday::Wednesday));
+
+    let date: time::Date = "1978-11-14".parse().unwrap();
+    assert_eq!(time::day_of_year(&date), 318);
+    let date: time::Date = "1988-04-22T18:00".parse().unwrap();
+    assert_eq!(time::day_of_year(&date), 113);
+    let date: time::Date = "2023-12-31".parse().unwrap();
+    assert_eq!(time::day_of_year(&date), 365);
+    let date: time::Date = "2024-12-31".parse().unwrap();
+    assert_eq!(time::day_of_year(&date), 366);
+
+    // The Julian calendar's 1582 October 4 was followed by the
+    // Gregorian calendar's October 15
+    let date: time::Date = "1582-10-04".parse().unwrap();
+    assert!(matches!(time::day_of_week(&date), time::Weekday::Thursday));
+    let date: time::Date = "1582-10-15".parse().unwrap();
+    assert!(matches!(time::day_of_week(&date), time::Weekday::Friday));
+    let date: time::Date = "1700-03-01".parse().unwrap();
+    assert_eq!(time::day_of_year(&date), 60);
+}
+
+#[test]
+fn date_from_julian_day() {
+    // Meeus, Astronomical Algorithms, example 7.c
+    let date = time::Date::from_julian_day(2436116.31).unwrap();
+    assert_eq!((date.year, date.month as u8), (1957, 10));
+    let day = date.day_of_month();
+    assert_eq!((day.day, day.hr, day.min), (4, 19, 26));
+    assert_eq!(util::round_upto_digits(day.sec, 0), 24.0);
+
+    for s in [
+        "2000-01-01T12:00:00Z",
+        "1987-06-19T12:00:00Z",
+        "1600-12-31T00:00:00Z",
+        "1582-10-15T00:00:00Z",
+        "1582-10-04T18:00:00Z",
+        "1066-12-25T06:30:00Z",
+        "0837-04-10T07:12:00Z",
+        "0000-01-01T00:00:00Z",
+        "-1000-02-29T03:00:00Z",
+        "-4712-01-01T12:00:00Z",
+    ]
+    .iter()
+    {
+        let date: time::Date = s.parse().unwrap();
+        let JD = time::julian_day(&date);
+        let round_trip = time::Date::from_julian_day(JD).unwrap();
+        assert_eq!(&round_trip.to_string(), s);
+        assert_eq!(time::julian_day(&round_trip), JD);
+    }
+
+    // The day after 1582 October 4 (Julian) is 1582 October 15 (Gregorian)
+    let date = time::Date::from_julian_day(2299160.5).unwrap();
+    assert!(matches!(date.cal_type, time::CalType::Gregorian));
+    assert_eq!(date.to_string(), "1582-10-15T00:00:00Z");
+    let date = time::Date::from_julian_day(2299160.49).unwrap();
+    assert!(matches!(date.cal_type, time::CalType::Julian));
+    assert_eq!(date.day_of_month().day, 4);
+
+    assert!(time::Date::from_julian_day(-1.0).is_err());
+
+    // The last day of the year 32767, the largest that fits in an i16
+    let last_JD = time::julian_day(&time::Date {
+        year: 32767,
+        month: time::Month::Dec,
+        decimal_day: 31.5,
+        cal_type: time::CalType::Gregorian,
+    });
+    let date = time::Date::from_julian_day(last_JD).unwrap();
+    assert_eq!((date.year, date.month as u8), (32767, 12));
+    assert!(time::Date::from_julian_day(last_JD + 1.0).is_err());
+    assert!(time::Date::from_julian_day(2440587.5 + 2e7).is_err());
+}
+
+#[test]
+fn easter() {
+    // Meeus, Astronomical Algorithms, chapter 8
+    for &(year, month, day) in [
+        (1991_i16, 3_u8, 31),
+        (1992, 4, 19),
+        (1993, 4, 11),
+        (1954, 4, 18),
+        (2000, 4, 23),
+        (1818, 3, 22),
+        (2285, 3, 22),
+        (1583, 4, 10),
+        (179, 4, 12),
+        (711, 4, 12),
+        (1243, 4, 12),
+    ]
+    .iter()
+    {
+        let date = time::easter(year);
+        assert_eq!(
+            (date.year, date.month as u8, date.decimal_day),
+            (year, month, day as f64)
+        );
+        assert!(matches!(time::day_of_week(&date), time::Weekday::Sunday));
+    }
+
+    assert!(matches!(time::easter(1582).cal_type, time::CalType::Julian));
+    assert!(matches!(
+        time::easter(1583).cal_type,
+        time::CalType::Gregorian
+    ));
+}
+
+#[test]
+fn islamic_calendar() {
+    // Meeus, Astronomical Algorithms, example 9.b
+    let date: time::Date = "1991-08-13".parse().unwrap();
+    let islamic_date = time::gregorian_to_islamic(&date);
+    assert_eq!(
+        islamic_date,
+        time::IslamicDate {
+            year: 1412,
+            month: time::IslamicMonth::Safar,
+            day: 2,
+        }
+    );
+
+    // Meeus, Astronomical Algorithms, example 9.a
+    let islamic_date = time::IslamicDate {
+        year: 1421,
+        month: time::IslamicMonth::Muharram,
+        day: 1,
+    };
+    let date = time::islamic_to_gregorian(&islamic_date).unwrap();
+    assert_eq!(date.to_string(), "2000-04-06T00:00:00Z");
+
+    // The epoch of the Islamic calendar, in the Julian calendar
+    let date = time::islamic_to_gregorian(&time::IslamicDate {
+        year: 1,
+        month: time::IslamicMonth::Muharram,
+        day: 1,
+    })
+    .unwrap();
+    assert!(matches!(date.cal_type, time::CalType::Julian));
+    assert_eq!(date.to_string(), "0622-07-16T00:00:00Z");
+
+    // Every day of a 30 year cycle converts there and back
+    let start: time::Date = "2000-01-01".parse().unwrap();
+    let mut JD = time::julian_day(&start);
+    let mut last_day = time::gregorian_to_islamic(&start).day - 1;
+    while JD < 2451544.5 + 10631.0 {
+        let date = time::Date::from_julian_day(JD).unwrap();
+        let islamic_date = time::gregorian_to_islamic(&date);
+        assert!(islamic_date.day == last_day + 1 || islamic_date.day == 1);
+        assert_eq!(
+            time::julian_day(&time::islamic_to_gregorian(&islamic_date).unwrap()),
+            JD
+        );
+        last_day = islamic_date.day;
+        JD += 1.0;
+    }
+}
+
+#[test]
+fn epochs() {
+    assert_eq!(
+        util::round_upto_digits(time::julian_day_frm_besselian_epoch(1950.0), 4),
+        2433282.4235
+    );
+    assert_eq!(time::julian_day_frm_besselian_epoch(1900.0), 2415020.31352);
+    assert_eq!(
+        util::round_upto_digits(time::besselian_epoch_frm_julian_day(2433282.4235), 6),
+        1950.0
+    );
+    assert_eq!(
+        util::round_upto_digits(time::besselian_epoch_frm_julian_day(2451545.0), 6),
+        2000.001278
+    );
+
+    assert_eq!(time::julian_day_frm_julian_epoch(2000.0), 2451545.0);
+    assert_eq!(time::julian_day_frm_julian_epoch(2050.5), 2469990.125);
+    assert_eq!(time::julian_epoch_frm_julian_day(2469990.125), 2050.5);
+    assert_eq!(
+        util::round_upto_digits(time::julian_epoch_frm_julian_day(2433282.4235), 6),
+        1949.999791
+    );
+
+    for epoch in &[-1000.5, 1875.0, 2024.25] {
+        let JD = time::julian_day_frm_besselian_epoch(*epoch);
+        assert_eq!(
+            util::round_upto_digits(time::besselian_epoch_frm_julian_day(JD), 9),
+            *epoch
+        );
+        let JD = time::julian_day_frm_julian_epoch(*epoch);
+        assert_eq!(
+            util::round_upto_digits(time::julian_epoch_frm_julian_day(JD), 9),
+            *epoch
+        );
+    }
+}
+
+#[test]
+fn time_scales() {
+    let JD = 2457754.5;
+
+    let JD_TAI = time::scales::tai_frm_tt(JD);
+    assert_eq!(util::round_upto_digits((JD - JD_TAI) * 86400.0, 3), 32.184);
+    assert_eq!(time::scales::tt_frm_tai(JD_TAI), JD);
+
+    // TT - UTC is 69.184 s from 2017, with 37 leap seconds
+    let JD_TT = time::scales::tt_frm_tai(time::scales::tai_frm_utc(JD, 37.0));
+    assert_eq!(util::round_upto_digits((JD_TT - JD) * 86400.0, 3), 69.184);
+    let JD_UTC = time::scales::utc_frm_tai(time::scales::tai_frm_tt(JD_TT), 37.0);
+    assert_eq!(util::round_upto_digits(JD_UTC, 9), JD);
+
+    let delta_t = time::delta_t(2017, 1);
+    let JD_TT = time::scales::tt_frm_utc(JD, delta_t);
+    assert_eq!(JD_TT, time::julian_ephemeris_day(JD, delta_t));
+    assert_eq!(
+        util::round_upto_digits(time::scales::utc_frm_tt(JD_TT, delta_t), 9),
+        JD
+    );
+    assert_eq!(time::scales::tt_frm_ut1(JD, delta_t), JD_TT);
+    assert_eq!(
+        util::round_upto_digits(time::scales::ut1_frm_tt(JD_TT, delta_t), 9),
+        JD
+    );
+
+    let JD_UT1 = time::scales::ut1_frm_utc(JD, 0.4);
+    assert_eq!(util::round_upto_digits((JD_UT1 - JD) * 86400.0, 3), 0.4);
+    assert_eq!(
+        util::round_upto_digits(time::scales::utc_frm_ut1(JD_UT1, 0.4), 9),
+        JD
+    );
+}
+
+#[test]
+fn delta_t_frm_decimal_year() {
+    assert_eq!(
+        util::round_upto_digits(time::delta_t_frm_decimal_year(1900.0), 2),
+        -2.79
+    );
+    assert_eq!(
+        util::round_upto_digits(time::delta_t_frm_decimal_year(2000.0), 2),
+        63.86
+    );
+    assert_eq!(
+        util::round_upto_digits(time::delta_t_frm_decimal_year(2050.0), 1),
+        93.0
+    );
+    assert_eq!(
+        time::delta_t(1988, 3),
+        time::delta_t_frm_decimal_year(1988.0 + 2.5 / 12.0)
+    );
+
+    // The parabolic extrapolation beyond the polynomials
+    assert_eq!(
+        util::round_upto_digits(time::delta_t_frm_decimal_year(-1180.0), 1),
+        28780.0
+    );
+    assert_eq!(
+        util::round_upto_digits(time::delta_t_frm_decimal_year(2820.0), 1),
+        3180.0
+    );
+
+    // and continuity at the ends of every polynomial
+    for y in &[
+        -500.0, 500.0, 1600.0, 1700.0, 1800.0, 1860.0, 1900.0, 1920.0, 1941.0, 1961.0, 1986.0,
+        2005.0, 2050.0, 2150.0,
+    ] {
+        let jump =
+            time::delta_t_frm_decimal_year(y + 1e-6) - time::delta_t_frm_decimal_year(y - 1e-6);
+        assert!(jump.abs() < 1.0);
+    }
+}
@@ -92,3 +92,216 @@ fn time() {
 
     assert_eq!((h_set, m_set), (2, 54));
 }
+
+#[test]
+fn time_local() {
+    // Venus at Boston on 1988 March 20 (Meeus, example 15.a)
+    let eq_point1 = coords::EqPoint {
+        asc: 40.68021_f64.to_radians(),
+        dec: 18.04761_f64.to_radians(),
+    };
+    let eq_point2 = coords::EqPoint {
+        asc: 41.73129_f64.to_radians(),
+        dec: 18.44092_f64.to_radians(),
+    };
+    let eq_point3 = coords::EqPoint {
+        asc: 42.78204_f64.to_radians(),
+        dec: 18.82742_f64.to_radians(),
+    };
+    let geograph_point = coords::GeographPoint {
+        long: 71.0833_f64.to_radians(),
+        lat: 42.3333_f64.to_radians(),
+    };
+    let Theta0 = 177.74208_f64.to_radians();
+    let deltaT = time::delta_t(1988, 3);
+
+    let time_local = |transit_type: &transit::TransitType, utc_offset_hours: f64| {
+        let (day_shift, h, m, _) = transit::time_local(
+            transit_type,
+            &transit::TransitBody::StarOrPlanet,
+            &geograph_point,
+            &eq_point1,
+            &eq_point2,
+            &eq_point3,
+            Theta0,
+            deltaT,
+            0.0,
+            utc_offset_hours,
+        );
+        (day_shift, h, m)
+    };
+
+    // Rise at 12h 25m UT is at 17h 55m in Indian Standard Time
+    assert_eq!(time_local(&transit::TransitType::Rise, 5.5), (0, 17, 55));
+
+    // Transit at 19h 40m UT is at 1h 10m on the next day
+    assert_eq!(time_local(&transit::TransitType::Transit, 5.5), (1, 1, 10));
+
+    // Set at 2h 54m UT is at 21h 54m on the day before in Boston
+    // (UTC-5)
+    assert_eq!(time_local(&transit::TransitType::Set, -5.0), (-1, 21, 54));
+    assert_eq!(time_local(&transit::TransitType::Set, 0.0), (0, 2, 54));
+}
+
+#[test]
+fn rise_set_azimuth() {
+    let geograph_point = coords::GeographPoint {
+        long: 0.0,
+        lat: 40_f64.to_radians(),
+    };
+    let summer_sun = coords::EqPoint {
+        asc: 90_f64.to_radians(),
+        dec: 23.44_f64.to_radians(),
+    };
+    let winter_sun = coords::EqPoint {
+        asc: 270_f64.to_radians(),
+        dec: -23.44_f64.to_radians(),
+    };
+
+    let (rise, set) = transit::rise_set_azimuth(
+        &transit::TransitBody::Sun,
+        &geograph_point,
+        &summer_sun,
+        0.0,
+    )
+    .unwrap();
+    assert_eq!(util::round_upto_digits(rise.to_degrees(), 2), 57.89);
+    assert_eq!(util::round_upto_digits(set.to_degrees(), 2), 302.11);
+
+    let (rise, set) = transit::rise_set_azimuth(
+        &transit::TransitBody::Sun,
+        &geograph_point,
+        &winter_sun,
+        0.0,
+    )
+    .unwrap();
+    assert_eq!(util::round_upto_digits(rise.to_degrees(), 2), 120.47);
+    assert_eq!(util::round_upto_digits(set.to_degrees(), 2), 239.53);
+
+    let arctic_point = coords::GeographPoint {
+        long: 0.0,
+        lat: 70_f64.to_radians(),
+    };
+    assert!(
+        transit::rise_set_azimuth(&transit::TransitBody::Sun, &arctic_point, &summer_sun, 0.0)
+            .is_err()
+    );
+}
+
+#[test]
+fn series() {
+    let geograph_point = coords::GeographPoint {
+        long: 0.0,
+        lat: 51.4769_f64.to_radians(),
+    };
+
+    let sun_eq_point = |JD: f64| {
+        let (sun_ecl_point, _) = sun::geocent_ecl_pos(JD);
+        let oblq = ecliptic::mn_oblq_laskar(JD);
+        coords::EqPoint {
+            asc: coords::asc_frm_ecl(sun_ecl_point.long, sun_ecl_point.lat, oblq),
+            dec: coords::dec_frm_ecl(sun_ecl_point.long, sun_ecl_point.lat, oblq),
+        }
+    };
+
+    // A week of sunrises at Greenwhich, from 2016 June 18
+    let sunrises = transit::series(
+        &transit::TransitType::Rise,
+        &transit::TransitBody::Sun,
+        &geograph_point,
+        &sun_eq_point,
+        2457557.5,
+        7,
+        &|_| time::delta_t(2016, 6),
+    );
+
+    assert_eq!(sunrises.len(), 7);
+    for sunrise in sunrises {
+        let (h, m, _) = sunrise.unwrap();
+        assert_eq!(h, 3);
+        assert!(m >= 42 && m <= 45);
+    }
+}
+
+#[test]
+fn time_at_hour_angle() {
+    let eq_point = coords::EqPoint {
+        asc: 41.73129_f64.to_radians(),
+        dec: 18.44092_f64.to_radians(),
+    };
+    let geograph_point = coords::GeographPoint {
+        long: 71.0833_f64.to_radians(),
+        lat: 42.3333_f64.to_radians(),
+    };
+    let Theta0 = 177.74208_f64.to_radians();
+
+    let (h, m, s) = transit::time(
+        &transit::TransitType::Transit,
+        &transit::TransitBody::StarOrPlanet,
+        &geograph_point,
+        &eq_point,
+        &eq_point,
+        &eq_point,
+        Theta0,
+        0.0,
+        0.0,
+    );
+    let transit_time = (h as f64) + (m as f64) / 60.0 + s / 3600.0;
+
+    let time = transit::time_at_hour_angle(&eq_point, &geograph_point, Theta0, 0.0);
+    assert_eq!(
+        util::round_upto_digits(time, 3),
+        util::round_upto_digits(transit_time, 3)
+    );
+
+    // Six sidereal hours before transit
+    let time_before =
+        transit::time_at_hour_angle(&eq_point, &geograph_point, Theta0, -90_f64.to_radians());
+    assert_eq!(util::round_upto_digits(time - time_before, 3), 5.984);
+}
+
+#[test]
+fn moon_topocentric_transit() {
+    let geograph_point = coords::GeographPoint {
+        long: 71.0833_f64.to_radians(),
+        lat: 42.3333_f64.to_radians(),
+    };
+    let date = time::Date {
+        year: 2016,
+        month: time::Month::June,
+        decimal_day: 18.0,
+        cal_type: time::CalType::Gregorian,
+    };
+    let JD = time::julian_day(&date);
+    let delta_t = time::delta_t(2016, 6);
+
+    let moon_eq_point = |JD: f64| {
+        let (moon_ecl_point, _) = lunar::geocent_ecl_pos(JD);
+        let (nut_in_long, nut_in_oblq) = nutation::nutation(JD);
+        let true_oblq = ecliptic::mn_oblq_laskar(JD) + nut_in_oblq;
+        let (asc, dec) = eq_frm_ecl!(
+            moon_ecl_point.long + nut_in_long,
+            moon_ecl_point.lat,
+            true_oblq
+        );
+        coords::EqPoint { asc, dec }
+    };
+    let JDE = JD + delta_t / 86400.0;
+    let (geo_h, geo_m, geo_s) = transit::time(
+        &transit::TransitType::Transit,
+        &transit::TransitBody::Moon,
+        &geograph_point,
+        &moon_eq_point(JDE - 1.0),
+        &moon_eq_point(JDE),
+        &moon_eq_point(JDE + 1.0),
+        apprnt_sidr!(JD),
+        delta_t,
+        0.0,
+    );
+    let (topo_h, topo_m, topo_s) = transit::moon_topocentric_transit(&date, &geograph_point, 0.0);
+
+    let geo_time = (geo_h as f64) * 3600.0 + (geo_m as f64) * 60.0 + geo_s;
+    let topo_time = (topo_h as f64) * 3600.0 + (topo_m as f64) * 60.0 + topo_s;
+    assert_eq!((topo_h, topo_m), (2, 55));
+    assert!((topo_time - geo_time).abs() < 60.0);
+}